@@ -37,6 +37,21 @@ fn bench_parse_memory(c: &mut Criterion) {
                     });
                 },
             );
+
+            // Same input through the borrowed, zero-copy path
+            group.bench_with_input(
+                BenchmarkId::new("parse_borrowed_and_hold", name),
+                &content,
+                |b, content| {
+                    b.iter(|| {
+                        let data = GedcomBuilder::new()
+                            .build_borrowed(black_box(content))
+                            .unwrap();
+                        black_box(&data);
+                        data
+                    });
+                },
+            );
         }
     }
 
@@ -352,7 +367,7 @@ fn bench_struct_sizes(c: &mut Criterion) {
                 if ind.xref.is_some() {
                     total += 1;
                 }
-                if ind.name.is_some() {
+                if !ind.names.is_empty() {
                     total += 1;
                 }
                 if ind.sex.is_some() {
@@ -0,0 +1,194 @@
+//! Asynchronous streaming parser for GEDCOM data.
+//!
+//! This module provides [`AsyncGedcomStreamParser`], the non-blocking counterpart of
+//! [`GedcomStreamParser`](crate::GedcomStreamParser). It reads from any tokio
+//! [`AsyncBufRead`] and yields records as a [`Stream`], so uploads can be parsed as they
+//! arrive without tying up an executor thread.
+//!
+//! Requires the `async` feature to be enabled.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "async")]
+//! # mod example {
+//! use futures::StreamExt;
+//! use ged_io::async_stream::AsyncGedcomStreamParser;
+//! use ged_io::{GedcomError, GedcomRecord};
+//! use tokio::io::AsyncBufRead;
+//!
+//! async fn count_individuals<R: AsyncBufRead + Unpin>(upload: R) -> Result<usize, GedcomError> {
+//!     let mut records = AsyncGedcomStreamParser::new(upload);
+//!     let mut count = 0;
+//!     while let Some(record) = records.next().await {
+//!         if let GedcomRecord::Individual(_) = record? {
+//!             count += 1;
+//!         }
+//!     }
+//!     Ok(count)
+//! }
+//! # }
+//! ```
+//!
+//! # Encodings
+//!
+//! Like the blocking streaming parser, the asynchronous parser detects the encoding from
+//! the byte order mark or the header `CHAR` line and decodes the input incrementally.
+
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_core::Stream;
+use tokio::io::AsyncBufRead;
+
+use crate::{
+    builder::ParserConfig,
+    stream::{GedcomRecord, NextRecord, RecordParser, RecordReader},
+    GedcomEncoding, GedcomError,
+};
+
+/// A [`Stream`] of GEDCOM records read from an [`AsyncBufRead`].
+///
+/// Records are buffered and parsed exactly as [`GedcomStreamParser`](crate::GedcomStreamParser)
+/// does: the text of one level-0 record is held in memory at a time. The stream ends at the
+/// `TRLR` record or at the end of input, and stops after the first error.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "async")]
+/// # fn main() {
+/// use futures::StreamExt;
+/// use ged_io::async_stream::AsyncGedcomStreamParser;
+///
+/// let gedcom = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 TRLR\n";
+///
+/// let records = futures::executor::block_on(async {
+///     AsyncGedcomStreamParser::new(gedcom.as_bytes())
+///         .collect::<Vec<_>>()
+///         .await
+/// });
+///
+/// assert_eq!(records.len(), 2);
+/// assert!(records[1].as_ref().unwrap().is_individual());
+/// # }
+/// # #[cfg(not(feature = "async"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct AsyncGedcomStreamParser<R> {
+    reader: R,
+    /// Decodes input bytes and groups them into level-0 records
+    records: RecordReader,
+    /// Parses record text with the configuration and the file's GEDCOM version
+    parser: RecordParser,
+    /// Whether we've finished parsing
+    finished: bool,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncGedcomStreamParser<R> {
+    /// Creates a new asynchronous streaming parser from a buffered reader.
+    ///
+    /// The encoding is detected from the start of the input when the stream is first
+    /// polled, so encoding and I/O errors are reported as stream items.
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self::with_config(reader, ParserConfig::default())
+    }
+
+    /// Creates a new asynchronous streaming parser that parses records with the given
+    /// [`ParserConfig`].
+    ///
+    /// Strict-mode xref validation follows the GEDCOM version declared in the header
+    /// once the header record has been read.
+    #[must_use]
+    pub fn with_config(reader: R, config: ParserConfig) -> Self {
+        Self {
+            reader,
+            records: RecordReader::new(),
+            parser: RecordParser::new(config),
+            finished: false,
+        }
+    }
+
+    /// Creates a new asynchronous streaming parser that decodes its input with the given
+    /// encoding, skipping detection.
+    #[must_use]
+    pub fn with_encoding(reader: R, encoding: GedcomEncoding) -> Self {
+        Self {
+            reader,
+            records: RecordReader::with_encoding(encoding),
+            parser: RecordParser::new(ParserConfig::default()),
+            finished: false,
+        }
+    }
+
+    /// Returns the encoding used to decode the input, once it is known.
+    #[must_use]
+    pub fn encoding(&self) -> Option<GedcomEncoding> {
+        self.records.encoding()
+    }
+
+    /// Consumes the parser, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Polls for the next complete record text and the line it starts on.
+    fn poll_next_record(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<(String, u32)>, GedcomError>> {
+        loop {
+            match self.records.next_record() {
+                NextRecord::Record(text, line) => return Poll::Ready(Ok(Some((text, line)))),
+                NextRecord::Done => return Poll::Ready(Ok(None)),
+                NextRecord::NeedInput => {}
+            }
+
+            let buffer = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))
+                .map_err(|e| GedcomError::IoError(e.to_string()))?;
+            if buffer.is_empty() {
+                self.records.finish_input()?;
+                continue;
+            }
+            let length = buffer.len();
+            self.records.feed(buffer)?;
+            Pin::new(&mut self.reader).consume(length);
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for AsyncGedcomStreamParser<R> {
+    type Item = Result<GedcomRecord, GedcomError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match ready!(this.poll_next_record(cx)) {
+                Ok(Some((text, line))) => match this.parser.parse(&text, line) {
+                    Ok(Some(record)) => return Poll::Ready(Some(Ok(record))),
+                    Ok(None) => {}
+                    Err(e) => {
+                        this.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                },
+                Ok(None) => {
+                    this.finished = true;
+                    return Poll::Ready(None);
+                }
+                Err(e) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
@@ -298,33 +298,38 @@ fn run() -> Result<RunOutcome, CliError> {
             .map(|s| s.to_lowercase());
 
         for individual in &data.individuals {
-            let display_name = individual
-                .name
-                .as_ref()
-                .map(|n| n.to_string())
-                .unwrap_or_else(|| "(Unknown)".to_string());
-
-            let (first, last) = extract_first_last_name(&display_name);
-
-            let first_lower = first.as_deref().map(|s| s.to_lowercase());
-            let last_lower = last.as_deref().map(|s| s.to_lowercase());
-
-            let matches_last = filter_last
-                .as_ref()
-                .map(|f| last_lower.as_ref().map(|l| l.contains(f)).unwrap_or(false))
-                .unwrap_or(true);
-
-            let matches_first = filter_first
-                .as_ref()
-                .map(|f| {
-                    first_lower
-                        .as_ref()
-                        .map(|fi| fi.contains(f))
-                        .unwrap_or(false)
-                })
-                .unwrap_or(true);
-
-            if matches_last && matches_first {
+            // An individual matches when any of its names does
+            let display_names: Vec<String> = if individual.names.is_empty() {
+                vec!["(Unknown)".to_string()]
+            } else {
+                individual.names.iter().map(|n| n.to_string()).collect()
+            };
+
+            let matches = display_names.iter().any(|display_name| {
+                let (first, last) = extract_first_last_name(display_name);
+
+                let first_lower = first.as_deref().map(|s| s.to_lowercase());
+                let last_lower = last.as_deref().map(|s| s.to_lowercase());
+
+                let matches_last = filter_last
+                    .as_ref()
+                    .map(|f| last_lower.as_ref().map(|l| l.contains(f)).unwrap_or(false))
+                    .unwrap_or(true);
+
+                let matches_first = filter_first
+                    .as_ref()
+                    .map(|f| {
+                        first_lower
+                            .as_ref()
+                            .map(|fi| fi.contains(f))
+                            .unwrap_or(false)
+                    })
+                    .unwrap_or(true);
+
+                matches_last && matches_first
+            });
+
+            if matches {
                 println!("{individual}");
             }
         }
@@ -0,0 +1,999 @@
+//! Zero-copy parsing of GEDCOM data borrowed from the input string.
+//!
+//! The regular [`Tokenizer`](crate::tokenizer::Tokenizer) allocates a `String` for every tag
+//! and value it reads, and the typed records in [`GedcomData`] own all of their text. When
+//! scanning very large exports, that allocation dominates. This module offers a borrowed
+//! alternative in which every tag, xref and value is a `&'a str` slice of the input:
+//!
+//! - [`LinesRef`] splits the input into [`LineRef`]s (level, xref, tag and value).
+//! - [`BorrowedTokenizer`] exposes the same lines as [`BorrowedToken`]s and implements
+//!   [`TokenizerTrait`], so code written against the trait works unchanged.
+//! - [`GedcomDataRef`] groups the lines into [`NodeRef`] trees per level-0 record, with
+//!   typed views such as [`IndividualRef`] and [`FamilyRef`] for the most common lookups.
+//!
+//! Any record can be turned into its owned, fully typed counterpart on demand, since each
+//! node remembers the slice of input it was read from.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ged_io::borrowed::GedcomDataRef;
+//!
+//! # fn main() -> Result<(), ged_io::GedcomError> {
+//! let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n1 BIRT\n2 DATE 1 JAN 1900\n0 TRLR";
+//! let data = GedcomDataRef::parse(source)?;
+//!
+//! let john = &data.individuals[0];
+//! assert_eq!(john.xref(), Some("@I1@"));
+//! assert_eq!(john.name(), Some("John /Doe/"));
+//! assert_eq!(john.birth_date(), Some("1 JAN 1900"));
+//!
+//! // Fully typed record, parsed on demand
+//! let individual = john.to_individual()?;
+//! assert_eq!(individual.xref.as_deref(), Some("@I1@"));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{borrow::Cow, cell::OnceCell, sync::Arc};
+
+use crate::{
+    builder::ParserConfig,
+    tokenizer::{Token, Tokenizer, TokenizerTrait},
+    types::{family::Family, individual::Individual, GedcomData, Xref},
+    GedcomError, GedcomVersion,
+};
+
+/// A single GEDCOM line whose parts borrow from the input.
+///
+/// `gedcom_line: level + delim + [optional_xref_ID] + tag + [optional_line_value] + terminator`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineRef<'a> {
+    /// The level, denoting the depth within the tree.
+    pub level: u8,
+    /// The optional xref identifier, including its `@` delimiters.
+    pub xref: Option<&'a str>,
+    /// The tag.
+    pub tag: &'a str,
+    /// The optional line value.
+    pub value: Option<&'a str>,
+    /// The 1-based line number.
+    pub line: u32,
+    /// The byte offset where the line starts.
+    pub offset: usize,
+}
+
+/// An iterator over the [`LineRef`]s of a GEDCOM string.
+///
+/// Accepts the same real-world quirks as the owned tokenizer: a leading BOM, CRLF or CR-only
+/// line endings, leading whitespace and blank lines.
+#[derive(Clone, Debug)]
+pub struct LinesRef<'a> {
+    input: &'a str,
+    pos: usize,
+    line: u32,
+}
+
+impl<'a> LinesRef<'a> {
+    /// Creates a line iterator over the given GEDCOM content.
+    #[must_use]
+    pub fn new(input: &'a str) -> Self {
+        let pos = if input.starts_with('\u{FEFF}') {
+            '\u{FEFF}'.len_utf8()
+        } else {
+            0
+        };
+        LinesRef {
+            input,
+            pos,
+            line: 0,
+        }
+    }
+
+    /// Returns the byte offset of the next unread line.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    /// Reads the next raw line, returning its start offset and text without terminator.
+    fn next_raw_line(&mut self) -> Option<(usize, &'a str)> {
+        if self.pos >= self.input.len() {
+            return None;
+        }
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let (text, terminator) = match rest.find(['\n', '\r']) {
+            Some(end) => {
+                let terminator = if rest[end..].starts_with("\r\n") {
+                    2
+                } else {
+                    1
+                };
+                (&rest[..end], end + terminator)
+            }
+            None => (rest, rest.len()),
+        };
+        self.pos += terminator;
+        self.line += 1;
+        Some((start, text))
+    }
+
+    fn parse_line(&self, offset: usize, text: &'a str) -> Result<LineRef<'a>, GedcomError> {
+        let line = self.line;
+        let error = |message: &str| GedcomError::ParseError {
+            line,
+            message: message.to_string(),
+        };
+
+        let text = text.trim_start_matches(is_delimiter);
+        let digits = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        if digits == 0 {
+            return Err(error("Expected digit for level number"));
+        }
+        let level = text[..digits]
+            .parse::<u8>()
+            .map_err(|_| error(&format!("Level number too large: {}", &text[..digits])))?;
+
+        let (mut word, mut rest) = split_word(&text[digits..]);
+        let mut xref = None;
+        if word.starts_with('@') {
+            xref = Some(word);
+            (word, rest) = split_word(rest);
+        }
+        if word.is_empty() {
+            return Err(error("Expected tag"));
+        }
+
+        Ok(LineRef {
+            level,
+            xref,
+            tag: word,
+            value: (!rest.is_empty()).then_some(rest),
+            line,
+            offset,
+        })
+    }
+}
+
+impl<'a> Iterator for LinesRef<'a> {
+    type Item = Result<LineRef<'a>, GedcomError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (offset, text) = self.next_raw_line()?;
+            if text.trim_matches(is_delimiter).is_empty() {
+                continue;
+            }
+            return Some(self.parse_line(offset, text));
+        }
+    }
+}
+
+/// Returns true for the non-newline whitespace that separates the parts of a line.
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || c == '\u{FEFF}'
+}
+
+/// Splits off the first whitespace-delimited word, returning it and the remainder with
+/// leading whitespace removed.
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start_matches(is_delimiter);
+    let end = text.find(is_delimiter).unwrap_or(text.len());
+    (&text[..end], text[end..].trim_start_matches(is_delimiter))
+}
+
+/// A token borrowing its text from the input; the borrowed counterpart of [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BorrowedToken<'a> {
+    /// The `level`, denoting the depth within the tree
+    Level(u8),
+    /// The `tag`, a four character code that distinguishes datatypes
+    Tag(&'a str),
+    /// The value of the data: `optional_line_value`
+    LineValue(&'a str),
+    /// The `optional_xref_ID` used throughout the file to refer to a particular face
+    Pointer(&'a str),
+    /// A user-defined tag, always begins with an underscore
+    CustomTag(&'a str),
+    /// End-of-file indicator
+    EOF,
+    /// The initial token value, indicating nothing
+    None,
+}
+
+impl BorrowedToken<'_> {
+    /// Converts the token into an owned [`Token`].
+    #[must_use]
+    pub fn to_token(&self) -> Token {
+        match *self {
+            BorrowedToken::Level(level) => Token::Level(level),
+            BorrowedToken::Tag(tag) => Token::Tag(tag.into()),
+            BorrowedToken::LineValue(value) => Token::LineValue(value.into()),
+            BorrowedToken::Pointer(xref) => Token::Pointer(xref.into()),
+            BorrowedToken::CustomTag(tag) => Token::CustomTag(tag.into()),
+            BorrowedToken::EOF => Token::EOF,
+            BorrowedToken::None => Token::None,
+        }
+    }
+}
+
+/// Which part of the current line a [`BorrowedTokenizer`] is positioned on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    Level,
+    Pointer,
+    Tag,
+    Value,
+}
+
+/// A tokenizer whose tokens are slices of the input string.
+///
+/// Use [`current`](Self::current) and [`take_value`](Self::take_value) to read tokens
+/// without allocating. The [`TokenizerTrait`] implementation is provided for compatibility
+/// with code written against the owned tokenizers; it materializes an owned [`Token`] only
+/// when [`TokenizerTrait::current_token`] is called.
+///
+/// # Example
+///
+/// ```rust
+/// use ged_io::borrowed::{BorrowedToken, BorrowedTokenizer};
+///
+/// # fn main() -> Result<(), ged_io::GedcomError> {
+/// let mut tokenizer = BorrowedTokenizer::new("0 @I1@ INDI\n1 NAME John /Doe/");
+/// tokenizer.advance()?;
+/// let mut tags = Vec::new();
+/// while tokenizer.current() != BorrowedToken::EOF {
+///     if let BorrowedToken::Tag(tag) = tokenizer.current() {
+///         tags.push(tag);
+///     }
+///     tokenizer.advance()?;
+/// }
+/// assert_eq!(tags, ["INDI", "NAME"]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct BorrowedTokenizer<'a> {
+    lines: LinesRef<'a>,
+    current_line: Option<LineRef<'a>>,
+    part: Part,
+    token: BorrowedToken<'a>,
+    owned_token: OnceCell<Token>,
+    config: ParserConfig,
+}
+
+impl<'a> BorrowedTokenizer<'a> {
+    /// Creates a new borrowed tokenizer over GEDCOM content.
+    #[must_use]
+    pub fn new(input: &'a str) -> Self {
+        Self::with_config(input, ParserConfig::default())
+    }
+
+    /// Creates a new borrowed tokenizer that carries a [`ParserConfig`].
+    #[must_use]
+    pub fn with_config(input: &'a str, config: ParserConfig) -> Self {
+        BorrowedTokenizer {
+            lines: LinesRef::new(input),
+            current_line: None,
+            part: Part::Level,
+            token: BorrowedToken::None,
+            owned_token: OnceCell::new(),
+            config,
+        }
+    }
+
+    /// Returns the current token.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> BorrowedToken<'a> {
+        self.token
+    }
+
+    /// Returns the line the tokenizer is positioned on, if any.
+    #[inline]
+    #[must_use]
+    pub fn current_line(&self) -> Option<&LineRef<'a>> {
+        self.current_line.as_ref()
+    }
+
+    /// Advances to the next token.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a line is malformed.
+    pub fn advance(&mut self) -> Result<(), GedcomError> {
+        self.owned_token = OnceCell::new();
+
+        if let Some(line) = self.current_line {
+            let next = match self.part {
+                Part::Level if line.xref.is_some() => Some(Part::Pointer),
+                Part::Level | Part::Pointer => Some(Part::Tag),
+                Part::Tag if line.value.is_some() => Some(Part::Value),
+                Part::Tag | Part::Value => None,
+            };
+            if let Some(part) = next {
+                self.part = part;
+                self.token = match part {
+                    Part::Level => BorrowedToken::Level(line.level),
+                    Part::Pointer => BorrowedToken::Pointer(line.xref.unwrap_or_default()),
+                    Part::Tag if line.tag.starts_with('_') => BorrowedToken::CustomTag(line.tag),
+                    Part::Tag => BorrowedToken::Tag(line.tag),
+                    Part::Value => BorrowedToken::LineValue(line.value.unwrap_or_default()),
+                };
+                return Ok(());
+            }
+        }
+
+        self.current_line = self.lines.next().transpose()?;
+        self.part = Part::Level;
+        self.token = self
+            .current_line
+            .map_or(BorrowedToken::EOF, |line| BorrowedToken::Level(line.level));
+        Ok(())
+    }
+
+    /// Returns the value of the current line and advances past it, without allocating.
+    ///
+    /// The tokenizer must be positioned on the line's tag. Returns an empty string for lines
+    /// without a value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a following line is malformed.
+    pub fn take_value(&mut self) -> Result<&'a str, GedcomError> {
+        let value = match self.current_line {
+            Some(line) if self.part == Part::Tag => line.value.unwrap_or_default(),
+            _ => "",
+        };
+        self.advance()?;
+        if self.part == Part::Value {
+            self.advance()?;
+        }
+        Ok(value)
+    }
+
+    /// Takes a potentially multi-line text value, joining `CONT` and `CONC` lines.
+    ///
+    /// Borrows from the input when there are no continuation lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a following line is malformed.
+    pub fn take_text(&mut self, level: u8) -> Result<Cow<'a, str>, GedcomError> {
+        let mut text = Cow::Borrowed(self.take_value()?);
+        while let Some(line) = self.current_line {
+            if line.level <= level {
+                break;
+            }
+            match line.tag {
+                "CONT" => {
+                    self.advance()?;
+                    let value = self.take_value()?;
+                    let text = text.to_mut();
+                    text.push('\n');
+                    text.push_str(value);
+                }
+                "CONC" => {
+                    self.advance()?;
+                    let value = self.take_value()?;
+                    text.to_mut().push_str(value);
+                }
+                _ => break,
+            }
+        }
+        Ok(text)
+    }
+}
+
+impl TokenizerTrait for BorrowedTokenizer<'_> {
+    fn current_token(&self) -> &Token {
+        self.owned_token.get_or_init(|| self.token.to_token())
+    }
+
+    #[inline]
+    fn line(&self) -> u32 {
+        self.current_line.map_or(self.lines.line, |line| line.line)
+    }
+
+    #[inline]
+    fn done(&self) -> bool {
+        self.token == BorrowedToken::EOF
+    }
+
+    fn next_token(&mut self) -> Result<(), GedcomError> {
+        self.advance()
+    }
+
+    fn take_token(&mut self) -> Result<Token, GedcomError> {
+        let token = self.token.to_token();
+        self.advance()?;
+        Ok(token)
+    }
+
+    fn take_line_value(&mut self) -> Result<String, GedcomError> {
+        self.take_value().map(str::to_string)
+    }
+
+    fn take_continued_text(&mut self, level: u8) -> Result<String, GedcomError> {
+        self.take_text(level).map(Cow::into_owned)
+    }
+
+    fn debug(&self) -> String {
+        format!("line {}:", self.line())
+    }
+
+    fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+}
+
+/// A GEDCOM structure and its substructures, borrowing from the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeRef<'a> {
+    /// The line the structure starts on.
+    pub line: LineRef<'a>,
+    /// The substructures, in input order.
+    pub children: Vec<NodeRef<'a>>,
+    /// The input text of the structure, including substructures and line terminators.
+    pub source: &'a str,
+    /// The configuration and file version the structure is converted to typed records with.
+    context: Arc<RecordContext>,
+}
+
+/// The parser configuration and GEDCOM version of a borrowed parse, shared by its nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct RecordContext {
+    config: ParserConfig,
+    version: GedcomVersion,
+}
+
+impl<'a> NodeRef<'a> {
+    /// Returns the tag.
+    #[must_use]
+    pub fn tag(&self) -> &'a str {
+        self.line.tag
+    }
+
+    /// Returns the xref identifier, if any.
+    #[must_use]
+    pub fn xref(&self) -> Option<&'a str> {
+        self.line.xref
+    }
+
+    /// Returns the line value, if any.
+    #[must_use]
+    pub fn value(&self) -> Option<&'a str> {
+        self.line.value
+    }
+
+    /// Returns the first substructure with the given tag.
+    #[must_use]
+    pub fn child(&self, tag: &str) -> Option<&NodeRef<'a>> {
+        self.children.iter().find(|child| child.tag() == tag)
+    }
+
+    /// Returns all substructures with the given tag.
+    pub fn children_with_tag<'s>(&'s self, tag: &'s str) -> impl Iterator<Item = &'s NodeRef<'a>> {
+        self.children.iter().filter(move |child| child.tag() == tag)
+    }
+
+    /// Returns the value of the first substructure with the given tag.
+    #[must_use]
+    pub fn child_value(&self, tag: &str) -> Option<&'a str> {
+        self.child(tag).and_then(NodeRef::value)
+    }
+
+    /// Returns the value joined with its `CONT` and `CONC` substructures.
+    ///
+    /// Borrows from the input when there are no continuation lines.
+    #[must_use]
+    pub fn text(&self) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(self.value().unwrap_or_default());
+        for child in &self.children {
+            match child.tag() {
+                "CONT" => {
+                    let text = text.to_mut();
+                    text.push('\n');
+                    text.push_str(child.value().unwrap_or_default());
+                }
+                "CONC" => text.to_mut().push_str(child.value().unwrap_or_default()),
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Returns an owned tokenizer positioned on this structure's tag, for handing it to the
+    /// typed parsers with the configuration the input was parsed with.
+    fn tokenizer(&self) -> Result<Tokenizer<'a>, GedcomError> {
+        let mut tokenizer =
+            Tokenizer::with_config(self.source.chars(), self.context.config.clone());
+        tokenizer.set_version(self.context.version.clone());
+        tokenizer.next_token()?;
+        tokenizer.next_token()?;
+        if matches!(tokenizer.current_token, Token::Pointer(_)) {
+            tokenizer.next_token()?;
+        }
+        Ok(tokenizer)
+    }
+}
+
+/// A borrowed view of an individual record (tag: INDI).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndividualRef<'a>(pub NodeRef<'a>);
+
+impl<'a> IndividualRef<'a> {
+    /// Returns the underlying record.
+    #[must_use]
+    pub fn node(&self) -> &NodeRef<'a> {
+        &self.0
+    }
+
+    /// Returns the xref identifier, if any.
+    #[must_use]
+    pub fn xref(&self) -> Option<&'a str> {
+        self.0.xref()
+    }
+
+    /// Returns the first name, e.g. `John /Doe/`.
+    #[must_use]
+    pub fn name(&self) -> Option<&'a str> {
+        self.0.child_value("NAME")
+    }
+
+    /// Returns all names in input order.
+    pub fn names(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.children_with_tag("NAME").filter_map(NodeRef::value)
+    }
+
+    /// Returns the value of the SEX structure.
+    #[must_use]
+    pub fn sex(&self) -> Option<&'a str> {
+        self.0.child_value("SEX")
+    }
+
+    /// Returns the date of the first birth event.
+    #[must_use]
+    pub fn birth_date(&self) -> Option<&'a str> {
+        self.0
+            .child("BIRT")
+            .and_then(|birth| birth.child_value("DATE"))
+    }
+
+    /// Returns the place of the first birth event.
+    #[must_use]
+    pub fn birth_place(&self) -> Option<&'a str> {
+        self.0
+            .child("BIRT")
+            .and_then(|birth| birth.child_value("PLAC"))
+    }
+
+    /// Returns the date of the first death event.
+    #[must_use]
+    pub fn death_date(&self) -> Option<&'a str> {
+        self.0
+            .child("DEAT")
+            .and_then(|death| death.child_value("DATE"))
+    }
+
+    /// Returns the place of the first death event.
+    #[must_use]
+    pub fn death_place(&self) -> Option<&'a str> {
+        self.0
+            .child("DEAT")
+            .and_then(|death| death.child_value("PLAC"))
+    }
+
+    /// Returns the xrefs of the families this individual is a spouse in (tag: FAMS).
+    pub fn spouse_families(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.children_with_tag("FAMS").filter_map(NodeRef::value)
+    }
+
+    /// Returns the xrefs of the families this individual is a child in (tag: FAMC).
+    pub fn child_families(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.children_with_tag("FAMC").filter_map(NodeRef::value)
+    }
+
+    /// Parses the record into an owned, fully typed [`Individual`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the record is malformed.
+    pub fn to_individual(&self) -> Result<Individual, GedcomError> {
+        let mut tokenizer = self.0.tokenizer()?;
+        Individual::new(&mut tokenizer, 0, self.xref().map(Xref::from))
+    }
+}
+
+/// A borrowed view of a family record (tag: FAM).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FamilyRef<'a>(pub NodeRef<'a>);
+
+impl<'a> FamilyRef<'a> {
+    /// Returns the underlying record.
+    #[must_use]
+    pub fn node(&self) -> &NodeRef<'a> {
+        &self.0
+    }
+
+    /// Returns the xref identifier, if any.
+    #[must_use]
+    pub fn xref(&self) -> Option<&'a str> {
+        self.0.xref()
+    }
+
+    /// Returns the xref of the first partner (tag: HUSB).
+    #[must_use]
+    pub fn husband(&self) -> Option<&'a str> {
+        self.0.child_value("HUSB")
+    }
+
+    /// Returns the xref of the second partner (tag: WIFE).
+    #[must_use]
+    pub fn wife(&self) -> Option<&'a str> {
+        self.0.child_value("WIFE")
+    }
+
+    /// Returns the xrefs of the children (tag: CHIL).
+    pub fn children(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.0.children_with_tag("CHIL").filter_map(NodeRef::value)
+    }
+
+    /// Returns the date of the first marriage event.
+    #[must_use]
+    pub fn marriage_date(&self) -> Option<&'a str> {
+        self.0
+            .child("MARR")
+            .and_then(|marriage| marriage.child_value("DATE"))
+    }
+
+    /// Parses the record into an owned, fully typed [`Family`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the record is malformed.
+    pub fn to_family(&self) -> Result<Family, GedcomError> {
+        let mut tokenizer = self.0.tokenizer()?;
+        Family::new(&mut tokenizer, 0, self.xref().map(Xref::from))
+    }
+}
+
+/// The records of a GEDCOM file, borrowing all of their text from the input.
+///
+/// This is the borrowed counterpart of [`GedcomData`]. Records are kept as [`NodeRef`] trees;
+/// individuals and families get typed views for the most common lookups.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GedcomDataRef<'a> {
+    /// The header (tag: HEAD)
+    pub header: Option<NodeRef<'a>>,
+    /// Submitter records (tag: SUBM)
+    pub submitters: Vec<NodeRef<'a>>,
+    /// Submission records (tag: SUBN, GEDCOM 5.5.1 only)
+    pub submissions: Vec<NodeRef<'a>>,
+    /// Individual records (tag: INDI)
+    pub individuals: Vec<IndividualRef<'a>>,
+    /// Family records (tag: FAM)
+    pub families: Vec<FamilyRef<'a>>,
+    /// Repository records (tag: REPO)
+    pub repositories: Vec<NodeRef<'a>>,
+    /// Source records (tag: SOUR)
+    pub sources: Vec<NodeRef<'a>>,
+    /// Multimedia records (tag: OBJE)
+    pub multimedia: Vec<NodeRef<'a>>,
+    /// Shared note records (tag: SNOTE, or NOTE in GEDCOM 5.5.1)
+    pub shared_notes: Vec<NodeRef<'a>>,
+    /// User-defined level-0 records (tags with a leading underscore)
+    pub custom_data: Vec<NodeRef<'a>>,
+    /// The complete input
+    pub source: &'a str,
+    /// The configuration and file version the input was parsed with.
+    context: Arc<RecordContext>,
+}
+
+impl<'a> GedcomDataRef<'a> {
+    /// Parses GEDCOM content without copying any of its text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a line is malformed or an unknown level-0 record is found.
+    pub fn parse(input: &'a str) -> Result<Self, GedcomError> {
+        Self::parse_with_config(input, &ParserConfig::default())
+    }
+
+    /// Parses GEDCOM content without copying any of its text, honoring
+    /// [`ParserConfig::ignore_unknown_tags`] for unknown level-0 records.
+    ///
+    /// The configuration is kept for converting records to typed records, e.g. with
+    /// [`IndividualRef::to_individual`] or [`GedcomDataRef::to_gedcom_data`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a line is malformed or an unknown level-0 record is found.
+    pub fn parse_with_config(input: &'a str, config: &ParserConfig) -> Result<Self, GedcomError> {
+        let mut context = Arc::new(RecordContext {
+            config: config.clone(),
+            version: GedcomVersion::default(),
+        });
+        let mut data = GedcomDataRef {
+            source: input,
+            context: Arc::clone(&context),
+            ..Default::default()
+        };
+
+        let mut lines = LinesRef::new(input);
+        // Structures still collecting substructures, outermost first
+        let mut open: Vec<NodeRef<'a>> = Vec::new();
+
+        loop {
+            let line = lines.next().transpose()?;
+            let end = line.map_or(input.len(), |line| line.offset);
+            let level = line.map_or(0, |line| line.level);
+
+            while let Some(mut node) = open.pop() {
+                if node.line.level < level {
+                    open.push(node);
+                    break;
+                }
+                node.source = &input[node.line.offset..end];
+                if let Some(parent) = open.last_mut() {
+                    parent.children.push(node);
+                    continue;
+                }
+                let header = node.tag() == "HEAD";
+                data.add_record(node, config)?;
+                // Records after the header are converted by its version's rules
+                if let Some(version) = data.version().filter(|_| header) {
+                    Arc::make_mut(&mut context).version = GedcomVersion::from_version_str(version);
+                    data.context = Arc::clone(&context);
+                }
+            }
+
+            match line {
+                Some(line) if line.level == 0 && line.tag == "TRLR" => break,
+                Some(line) => open.push(NodeRef {
+                    line,
+                    children: Vec::new(),
+                    source: "",
+                    context: Arc::clone(&context),
+                }),
+                None => break,
+            }
+        }
+
+        Ok(data)
+    }
+
+    fn add_record(&mut self, node: NodeRef<'a>, config: &ParserConfig) -> Result<(), GedcomError> {
+        match node.tag() {
+            "HEAD" => self.header = Some(node),
+            "INDI" => self.individuals.push(IndividualRef(node)),
+            "FAM" => self.families.push(FamilyRef(node)),
+            "REPO" => self.repositories.push(node),
+            "SOUR" => self.sources.push(node),
+            "SUBN" => self.submissions.push(node),
+            "SUBM" => self.submitters.push(node),
+            "OBJE" => self.multimedia.push(node),
+            "NOTE" | "SNOTE" => self.shared_notes.push(node),
+            tag if tag.starts_with('_') => {
+                if !config.ignore_unknown_tags {
+                    self.custom_data.push(node);
+                }
+            }
+            tag => {
+                if !config.ignore_unknown_tags {
+                    return Err(GedcomError::ParseError {
+                        line: node.line.line,
+                        message: format!("Unhandled tag {tag}"),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds an individual by xref.
+    #[must_use]
+    pub fn find_individual(&self, xref: &str) -> Option<&IndividualRef<'a>> {
+        self.individuals
+            .iter()
+            .find(|individual| individual.xref() == Some(xref))
+    }
+
+    /// Finds a family by xref.
+    #[must_use]
+    pub fn find_family(&self, xref: &str) -> Option<&FamilyRef<'a>> {
+        self.families
+            .iter()
+            .find(|family| family.xref() == Some(xref))
+    }
+
+    /// Returns the GEDCOM version declared in the header (HEAD.GEDC.VERS).
+    #[must_use]
+    pub fn version(&self) -> Option<&'a str> {
+        self.header
+            .as_ref()
+            .and_then(|header| header.child("GEDC"))
+            .and_then(|gedc| gedc.child_value("VERS"))
+    }
+
+    /// Parses the complete input into an owned [`GedcomData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the input is malformed.
+    pub fn to_gedcom_data(&self) -> Result<GedcomData, GedcomError> {
+        let mut tokenizer =
+            Tokenizer::with_config(self.source.chars(), self.context.config.clone());
+        tokenizer.next_token()?;
+        GedcomData::new(&mut tokenizer, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\u{FEFF}0 HEAD\r\n1 GEDC\r\n2 VERS 5.5.1\r\n\r\n0 @I1@ INDI\r\n1 NAME John /Doe/\r\n1 SEX M\r\n1 BIRT\r\n2 DATE 1 JAN 1900\r\n2 PLAC Boston\r\n1 FAMS @F1@\r\n1 NOTE First line\r\n2 CONT second line\r\n2 CONC , continued\r\n0 @F1@ FAM\r\n1 HUSB @I1@\r\n1 CHIL @I2@\r\n1 CHIL @I3@\r\n0 @X1@ _CUSTOM data\r\n0 TRLR\r\n";
+
+    #[test]
+    fn test_lines_ref() {
+        let lines: Vec<_> = LinesRef::new("0 HEAD\n1   NOTE   spaced  value \n0 @I1@ INDI")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].level, 1);
+        assert_eq!(lines[1].tag, "NOTE");
+        assert_eq!(lines[1].value, Some("spaced  value "));
+        assert_eq!(lines[2].xref, Some("@I1@"));
+        assert_eq!(lines[2].value, None);
+        assert_eq!(lines[2].line, 3);
+        assert_eq!(lines[2].offset, 33);
+    }
+
+    #[test]
+    fn test_lines_ref_errors() {
+        let result: Result<Vec<_>, _> = LinesRef::new("0 HEAD\nfoo bar").collect();
+        assert!(matches!(
+            result,
+            Err(GedcomError::ParseError { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_borrowed_tokenizer_matches_owned() {
+        let source = "\u{FEFF}0 HEAD\r\n1 GEDC\r\n2 VERS 5.5.1\r\n0 @I1@ INDI\r\n1 NAME John /Doe/\r\n1 _CUSTOM x\r\n1 BIRT\r\n0 TRLR";
+
+        let mut borrowed = BorrowedTokenizer::new(source);
+        borrowed.next_token().unwrap();
+        let mut tokenizer = Tokenizer::new(source.chars());
+        tokenizer.next_token().unwrap();
+
+        while !tokenizer.done() {
+            assert_eq!(*borrowed.current_token(), tokenizer.current_token);
+            assert_eq!(TokenizerTrait::line(&borrowed), tokenizer.line);
+            tokenizer.next_token().unwrap();
+            borrowed.next_token().unwrap();
+        }
+        assert!(borrowed.done());
+    }
+
+    #[test]
+    fn test_borrowed_tokenizer_text() {
+        let mut tokenizer =
+            BorrowedTokenizer::new("1 NOTE First\n2 CONT second\n2 CONC  third\n1 SEX M");
+        tokenizer.advance().unwrap();
+        tokenizer.advance().unwrap();
+        assert_eq!(tokenizer.current(), BorrowedToken::Tag("NOTE"));
+        assert_eq!(tokenizer.take_text(1).unwrap(), "First\nsecondthird");
+        assert_eq!(tokenizer.current(), BorrowedToken::Level(1));
+
+        let mut tokenizer = BorrowedTokenizer::new("1 NOTE Only\n1 SEX M");
+        tokenizer.advance().unwrap();
+        tokenizer.advance().unwrap();
+        assert!(matches!(
+            tokenizer.take_text(1).unwrap(),
+            Cow::Borrowed("Only")
+        ));
+    }
+
+    #[test]
+    fn test_gedcom_data_ref() {
+        let data = GedcomDataRef::parse(SAMPLE).unwrap();
+
+        assert_eq!(data.version(), Some("5.5.1"));
+        assert_eq!(data.individuals.len(), 1);
+        assert_eq!(data.families.len(), 1);
+        assert_eq!(data.custom_data.len(), 1);
+
+        let john = data.find_individual("@I1@").unwrap();
+        assert_eq!(john.name(), Some("John /Doe/"));
+        assert_eq!(john.sex(), Some("M"));
+        assert_eq!(john.birth_date(), Some("1 JAN 1900"));
+        assert_eq!(john.birth_place(), Some("Boston"));
+        assert_eq!(john.spouse_families().collect::<Vec<_>>(), ["@F1@"]);
+        assert_eq!(
+            john.node().child("NOTE").unwrap().text(),
+            "First line\nsecond line, continued"
+        );
+        assert!(john.node().source.starts_with("0 @I1@ INDI\r\n"));
+        assert!(john.node().source.ends_with("2 CONC , continued\r\n"));
+
+        let family = data.find_family("@F1@").unwrap();
+        assert_eq!(family.husband(), Some("@I1@"));
+        assert_eq!(family.children().collect::<Vec<_>>(), ["@I2@", "@I3@"]);
+    }
+
+    #[test]
+    fn test_gedcom_data_ref_to_owned() {
+        let data = GedcomDataRef::parse(SAMPLE).unwrap();
+
+        let individual = data.individuals[0].to_individual().unwrap();
+        assert_eq!(individual.xref.as_deref(), Some("@I1@"));
+        assert_eq!(
+            individual.primary_name().unwrap().value.as_deref(),
+            Some("John /Doe/")
+        );
+
+        let family = data.families[0].to_family().unwrap();
+        assert_eq!(family.children.len(), 2);
+    }
+
+    #[test]
+    fn test_gedcom_data_ref_unknown_record() {
+        let source = "0 HEAD\n0 @X1@ BOGUS\n0 TRLR";
+        assert!(GedcomDataRef::parse(source).is_err());
+
+        let config = ParserConfig {
+            ignore_unknown_tags: true,
+            ..ParserConfig::default()
+        };
+        assert!(GedcomDataRef::parse_with_config(source, &config).is_ok());
+    }
+
+    #[test]
+    fn test_gedcom_data_ref_conversion_uses_config() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I_WITH_A_VERY_LONG_XREF@ INDI\n\
+                      1 NAME John /Doe/\n1 BOGUS value\n0 TRLR";
+
+        let data = GedcomDataRef::parse(source).unwrap();
+        assert_eq!(
+            data.individuals[0]
+                .to_individual()
+                .unwrap()
+                .custom_data
+                .len(),
+            1
+        );
+
+        let config = ParserConfig {
+            ignore_unknown_tags: true,
+            ..ParserConfig::default()
+        };
+        let data = GedcomDataRef::parse_with_config(source, &config).unwrap();
+        assert!(data.individuals[0]
+            .to_individual()
+            .unwrap()
+            .custom_data
+            .is_empty());
+        assert!(data.to_gedcom_data().unwrap().individuals[0]
+            .custom_data
+            .is_empty());
+
+        // Strict mode rejects the unknown tag, but checks the xref by GEDCOM 7.0 rules
+        let config = ParserConfig {
+            strict_mode: true,
+            ..ParserConfig::default()
+        };
+        let data = GedcomDataRef::parse_with_config(source, &config).unwrap();
+        assert!(matches!(
+            data.individuals[0].to_individual(),
+            Err(GedcomError::InvalidTag { .. })
+        ));
+        assert!(data.to_gedcom_data().is_err());
+        let valid = source.replace("1 BOGUS value\n", "");
+        let data = GedcomDataRef::parse_with_config(&valid, &config).unwrap();
+        assert!(data.individuals[0].to_individual().is_ok());
+    }
+}
@@ -22,37 +22,60 @@
 //! ```
 
 use crate::{
-    encoding::{decode_gedcom_bytes, GedcomEncoding},
+    borrowed::GedcomDataRef,
+    encoding::{decode_gedcom_bytes, detect_declared_encoding, GedcomEncoding},
+    error::{Diagnostic, Severity},
     tokenizer::Tokenizer,
-    types::GedcomData,
+    types::{span::Span, GedcomData, Xref},
     GedcomError,
 };
 use std::str::Chars;
 
+/// How the parser reacts to errors in the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ErrorMode {
+    /// Stop at the first error and return it.
+    #[default]
+    FailFast,
+    /// Record each error as a [`Diagnostic`], skip to the next level-0 record and keep going.
+    ///
+    /// Use [`GedcomBuilder::build_with_diagnostics`] to receive every diagnostic; the other
+    /// build methods return the first error once the whole input has been read.
+    Deferred,
+}
+
 /// Configuration options for GEDCOM parsing.
 ///
 /// This struct holds all configuration settings that affect how the parser
 /// processes GEDCOM data. It is used internally by `GedcomBuilder`.
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParserConfig {
-    /// When true, the parser will fail on any non-standard or unknown tags.
-    /// When false, unknown tags are skipped or stored as custom data.
+    /// When true, the parser fails on standard tags that are not valid in their context, on
+    /// malformed DATE payloads and on record identifiers that break the rules of the file's
+    /// GEDCOM version. User-defined (underscore) tags are still accepted.
+    /// When false, unknown tags are skipped together with their substructures.
     pub strict_mode: bool,
 
     /// When true, the parser validates that all cross-references (xrefs)
     /// point to existing records.
     pub validate_references: bool,
 
-    /// When true, unknown/unrecognized tags are silently ignored.
-    /// When false, they may be stored as custom data or cause errors (depending on `strict_mode`).
+    /// When true, unknown/unrecognized tags are silently ignored: user-defined tags and
+    /// unrecognized standard substructures are not stored as custom data, unknown level-0
+    /// records are skipped, and `strict_mode` does not reject unknown tags. When false, both
+    /// are stored as custom data and unknown tags are handled according to `strict_mode`.
     pub ignore_unknown_tags: bool,
 
-    /// When true, the parser attempts to auto-detect the character encoding.
-    /// When false, UTF-8 is assumed.
+    /// When true, an encoding declared by the data itself (a BOM or the header `CHAR` line)
+    /// takes precedence over the encoding passed to
+    /// [`GedcomBuilder::build_from_bytes_with_encoding`], which then acts as a fallback.
+    /// When false, the passed encoding is always used.
+    /// [`GedcomBuilder::build_from_bytes`] always auto-detects.
     pub encoding_detection: bool,
 
-    /// When true, dates are validated for proper GEDCOM format.
+    /// When true, DATE payloads are validated against the GEDCOM date grammar and malformed
+    /// dates cause an error. Strict mode implies date validation.
     /// When false, dates are stored as-is without validation.
     pub date_validation: bool,
 
@@ -61,8 +84,17 @@ pub struct ParserConfig {
     pub max_file_size: Option<usize>,
 
     /// When true, original spacing and formatting in text values is preserved.
-    /// When false, text may be normalized.
+    /// When false, runs of whitespace in line values are collapsed to a single space and
+    /// trailing whitespace is removed.
     pub preserve_formatting: bool,
+
+    /// When true, records and events remember the lines and byte offsets they were parsed
+    /// from in their `span` field.
+    pub record_spans: bool,
+
+    /// Whether parsing stops at the first error or collects diagnostics and recovers at the
+    /// next level-0 record.
+    pub error_mode: ErrorMode,
 }
 
 impl Default for ParserConfig {
@@ -75,6 +107,8 @@ impl Default for ParserConfig {
             date_validation: false,
             max_file_size: None,
             preserve_formatting: true,
+            record_spans: false,
+            error_mode: ErrorMode::FailFast,
         }
     }
 }
@@ -121,6 +155,8 @@ impl GedcomBuilder {
     /// - `date_validation`: false
     /// - `max_file_size`: None (unlimited)
     /// - `preserve_formatting`: true
+    /// - `record_spans`: false
+    /// - `error_mode`: [`ErrorMode::FailFast`]
     ///
     /// # Example
     ///
@@ -138,9 +174,11 @@ impl GedcomBuilder {
 
     /// Enables or disables strict parsing mode.
     ///
-    /// When strict mode is enabled, the parser will fail on any non-standard
-    /// tags or structural issues. When disabled (default), the parser is more
-    /// lenient and will attempt to continue parsing despite minor issues.
+    /// When strict mode is enabled, the parser will fail on standard tags that
+    /// are not valid in their context, on malformed DATE payloads and on record
+    /// identifiers that are not valid for the file's GEDCOM version. When
+    /// disabled (default), the parser is more lenient and skips unknown tags
+    /// together with their substructures.
     ///
     /// # Arguments
     ///
@@ -187,8 +225,10 @@ impl GedcomBuilder {
     /// Enables or disables ignoring unknown tags.
     ///
     /// When enabled, unknown or unrecognized GEDCOM tags will be silently
-    /// ignored during parsing. When disabled, unknown tags may be stored
-    /// as custom data or cause errors (depending on `strict_mode` setting).
+    /// ignored during parsing: user-defined tags and unrecognized standard
+    /// substructures are not kept as custom data and unknown level-0 records
+    /// are skipped. When disabled, both are stored as custom data and unknown
+    /// tags may cause errors (depending on `strict_mode` setting).
     ///
     /// # Arguments
     ///
@@ -210,9 +250,10 @@ impl GedcomBuilder {
 
     /// Enables or disables automatic encoding detection.
     ///
-    /// When enabled, the parser will attempt to auto-detect the character
-    /// encoding of the GEDCOM file from the header or BOM. When disabled,
-    /// UTF-8 encoding is assumed.
+    /// When enabled, [`build_from_bytes_with_encoding`](Self::build_from_bytes_with_encoding)
+    /// first looks for an encoding declared by the file itself (BOM or header
+    /// `CHAR` line) and only falls back to the given encoding when there is
+    /// none. When disabled, the given encoding is always used.
     ///
     /// # Arguments
     ///
@@ -284,8 +325,8 @@ impl GedcomBuilder {
     /// Enables or disables preservation of original formatting.
     ///
     /// When enabled (default), original spacing and formatting in text
-    /// values is preserved. When disabled, text may be normalized
-    /// (e.g., collapsing multiple spaces).
+    /// values is preserved. When disabled, line values are normalized by
+    /// collapsing runs of whitespace and trimming trailing whitespace.
     ///
     /// # Arguments
     ///
@@ -305,6 +346,63 @@ impl GedcomBuilder {
         self
     }
 
+    /// Enables or disables recording of source spans.
+    ///
+    /// When enabled, level-0 records, events, attributes and source citations
+    /// store the start and end line and byte offset they were parsed from in
+    /// their `span` field, and reference validation errors name the lines of
+    /// the offending record. Disabled by default to keep parsed data compact.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to record spans
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::GedcomBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+    /// let data = GedcomBuilder::new()
+    ///     .record_spans(true)
+    ///     .build_from_str(source)?;
+    ///
+    /// let span = data.individuals[0].span.unwrap();
+    /// assert_eq!((span.start_line, span.end_line), (4, 5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn record_spans(mut self, enabled: bool) -> Self {
+        self.config.record_spans = enabled;
+        self
+    }
+
+    /// Sets how the parser reacts to errors in the input.
+    ///
+    /// With [`ErrorMode::Deferred`], a record that fails to parse is dropped, a
+    /// [`Diagnostic`] is recorded and parsing resumes at the next level-0
+    /// record, so all problems in a file are found in one pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The error handling mode
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::builder::{ErrorMode, GedcomBuilder};
+    ///
+    /// let builder = GedcomBuilder::new()
+    ///     .error_mode(ErrorMode::Deferred);
+    /// ```
+    #[must_use]
+    pub fn error_mode(mut self, mode: ErrorMode) -> Self {
+        self.config.error_mode = mode;
+        self
+    }
+
     /// Returns a reference to the current parser configuration.
     ///
     /// This can be used to inspect the configuration before building.
@@ -351,17 +449,105 @@ impl GedcomBuilder {
     /// # }
     /// ```
     pub fn build(self, chars: Chars<'_>) -> Result<GedcomData, GedcomError> {
-        let mut tokenizer = Tokenizer::new(chars);
-        tokenizer.next_token()?;
+        let (data, diagnostics) = self.build_with_diagnostics(chars)?;
+
+        // In deferred mode, report the first error once the whole input has been read
+        if let Some(diagnostic) = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            return Err(diagnostic.error);
+        }
 
-        let data = GedcomData::new(&mut tokenizer, 0)?;
+        Ok(data)
+    }
+
+    /// Builds the parser and parses the GEDCOM data from a character iterator,
+    /// returning the data together with every problem found.
+    ///
+    /// With [`ErrorMode::Deferred`], records that fail to parse are dropped and
+    /// reported as [`Diagnostic`]s carrying the line, column, severity and
+    /// record xref; reference validation problems are reported the same way.
+    /// With [`ErrorMode::FailFast`], the first error is returned as `Err` and
+    /// the diagnostics are always empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - A character iterator over the GEDCOM content
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` in [`ErrorMode::FailFast`] if the GEDCOM data is
+    /// malformed or validation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::builder::{ErrorMode, GedcomBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 @I2@ FOO\n0 TRLR";
+    /// let (data, diagnostics) = GedcomBuilder::new()
+    ///     .error_mode(ErrorMode::Deferred)
+    ///     .build_with_diagnostics(source.chars())?;
+    ///
+    /// assert_eq!(data.individuals.len(), 1);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].xref.as_deref(), Some("@I2@"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_with_diagnostics(
+        self,
+        chars: Chars<'_>,
+    ) -> Result<(GedcomData, Vec<Diagnostic>), GedcomError> {
+        let mut tokenizer = Tokenizer::with_config(chars, self.config.clone());
+
+        if self.config.error_mode == ErrorMode::FailFast {
+            tokenizer.next_token()?;
+            let data = GedcomData::new(&mut tokenizer, 0)?;
+
+            // Post-parse validation if enabled
+            if self.config.validate_references {
+                if let Some(problem) = self.reference_problems(&data).into_iter().next() {
+                    return Err(problem.error);
+                }
+            }
+
+            return Ok((data, Vec::new()));
+        }
+
+        let (data, mut diagnostics) = GedcomData::parse_with_diagnostics(&mut tokenizer);
 
-        // Post-parse validation if enabled
         if self.config.validate_references {
-            self.validate_references_internal(&data)?;
+            diagnostics.extend(self.reference_diagnostics(&data));
         }
 
-        Ok(data)
+        Ok((data, diagnostics))
+    }
+
+    /// Builds the parser and parses the GEDCOM data from a string, returning
+    /// the data together with every problem found.
+    ///
+    /// See [`build_with_diagnostics`](Self::build_with_diagnostics).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the content exceeds the configured maximum
+    /// file size, or in [`ErrorMode::FailFast`] if parsing or validation fails.
+    pub fn build_from_str_with_diagnostics(
+        self,
+        content: &str,
+    ) -> Result<(GedcomData, Vec<Diagnostic>), GedcomError> {
+        // Check file size limit if configured
+        if let Some(max_size) = self.config.max_file_size {
+            let size = content.len();
+            if size > max_size {
+                return Err(GedcomError::FileSizeLimitExceeded { size, max_size });
+            }
+        }
+
+        self.build_with_diagnostics(content.chars())
     }
 
     /// Builds the parser and parses the GEDCOM data from raw bytes.
@@ -455,6 +641,13 @@ impl GedcomBuilder {
             }
         }
 
+        // Prefer the file's own declaration when encoding detection is enabled
+        let encoding = if self.config.encoding_detection {
+            detect_declared_encoding(bytes).unwrap_or(encoding)
+        } else {
+            encoding
+        };
+
         // Decode bytes with specified encoding
         let (content, _) = crate::encoding::decode_with_encoding(bytes, encoding)?;
 
@@ -499,6 +692,230 @@ impl GedcomBuilder {
         self.build(content.chars())
     }
 
+    /// Parses the GEDCOM data from a string on a thread pool.
+    ///
+    /// Level-0 records are independent of each other, so the content is split
+    /// at each `0` line and the records are parsed in parallel with
+    /// [rayon](https://docs.rs/rayon). The results are merged into
+    /// `GedcomData` in their original order, yielding the same data as
+    /// [`build_from_str`](Self::build_from_str). With [`ErrorMode::Deferred`],
+    /// the first error is reported once every record has been parsed.
+    ///
+    /// Requires the `rayon` feature to be enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The decoded GEDCOM content as a string slice
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if:
+    /// - The content exceeds the configured maximum file size
+    /// - A record is malformed (the first failing record in file order is reported)
+    /// - Validation fails (when strict mode or validation options are enabled)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rayon")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ged_io::GedcomBuilder;
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_parallel(source)?;
+    /// assert_eq!(data.individuals.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "rayon"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn build_parallel(self, content: &str) -> Result<GedcomData, GedcomError> {
+        let (data, diagnostics) = self.build_parallel_with_diagnostics(content)?;
+
+        // In deferred mode, report the first error once the whole input has been read
+        if let Some(diagnostic) = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            return Err(diagnostic.error);
+        }
+
+        Ok(data)
+    }
+
+    /// Parses the GEDCOM data from a string on a thread pool, returning the
+    /// data together with every problem found.
+    ///
+    /// This is the parallel counterpart of
+    /// [`build_from_str_with_diagnostics`](Self::build_from_str_with_diagnostics):
+    /// with [`ErrorMode::Deferred`], records that fail to parse are dropped and
+    /// reported as [`Diagnostic`]s in file order, and reference validation
+    /// problems are reported the same way. With [`ErrorMode::FailFast`], the
+    /// first error in file order is returned as `Err` and the diagnostics are
+    /// always empty.
+    ///
+    /// Requires the `rayon` feature to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the content exceeds the configured maximum
+    /// file size, or in [`ErrorMode::FailFast`] if parsing or validation fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rayon")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use ged_io::builder::{ErrorMode, GedcomBuilder};
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 @I2@ FOO\n0 TRLR";
+    /// let (data, diagnostics) = GedcomBuilder::new()
+    ///     .error_mode(ErrorMode::Deferred)
+    ///     .build_parallel_with_diagnostics(source)?;
+    ///
+    /// assert_eq!(data.individuals.len(), 1);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].xref.as_deref(), Some("@I2@"));
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "rayon"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn build_parallel_with_diagnostics(
+        self,
+        content: &str,
+    ) -> Result<(GedcomData, Vec<Diagnostic>), GedcomError> {
+        use crate::{
+            stream::{is_trailer, parse_record, split_records},
+            version::detect_version,
+        };
+        use rayon::prelude::*;
+
+        // Check file size limit if configured
+        if let Some(max_size) = self.config.max_file_size {
+            let size = content.len();
+            if size > max_size {
+                return Err(GedcomError::FileSizeLimitExceeded { size, max_size });
+            }
+        }
+
+        let version = detect_version(content);
+        let chunks = split_records(content);
+        let results: Vec<_> = chunks
+            .par_iter()
+            .map(|chunk| parse_record(chunk.text, chunk.line, chunk.offset, &self.config, &version))
+            .collect();
+
+        if self.config.error_mode == ErrorMode::FailFast {
+            // Collect sequentially so that the first error in file order is reported
+            let records = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+            let data: GedcomData = records.into_iter().flatten().collect();
+
+            // Post-parse validation if enabled
+            if self.config.validate_references {
+                if let Some(problem) = self.reference_problems(&data).into_iter().next() {
+                    return Err(problem.error);
+                }
+            }
+
+            return Ok((data, Vec::new()));
+        }
+
+        let mut records = Vec::with_capacity(results.len());
+        let mut diagnostics = Vec::new();
+        for (chunk, result) in chunks.iter().zip(results) {
+            match result {
+                Ok(record) => records.extend(record),
+                Err(error) => diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    line: error.line().or(Some(chunk.line as usize)),
+                    column: None,
+                    xref: chunk
+                        .text
+                        .split_whitespace()
+                        .nth(1)
+                        .filter(|word| word.starts_with('@'))
+                        .map(Xref::from),
+                    error,
+                }),
+            }
+        }
+
+        // The trailer, if any, is the line right after the last record
+        let end = chunks
+            .last()
+            .map_or(0, |chunk| chunk.offset + chunk.text.len());
+        if !content[end..]
+            .trim_start_matches('\u{FEFF}')
+            .lines()
+            .next()
+            .is_some_and(is_trailer)
+        {
+            let line = content.lines().count();
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                line: Some(line),
+                column: None,
+                xref: None,
+                error: GedcomError::ParseError {
+                    line: u32::try_from(line).unwrap_or(u32::MAX),
+                    message: "Missing TRLR record at end of file".to_string(),
+                },
+            });
+        }
+
+        let data: GedcomData = records.into_iter().collect();
+        if self.config.validate_references {
+            diagnostics.extend(self.reference_diagnostics(&data));
+        }
+
+        Ok((data, diagnostics))
+    }
+
+    /// Parses the GEDCOM data from a string without copying any of its text.
+    ///
+    /// The returned [`GedcomDataRef`] borrows every tag, xref and value from
+    /// `content`, which makes scanning very large files much cheaper than
+    /// [`build_from_str`](Self::build_from_str). Records can be converted to
+    /// their typed, owned counterparts on demand. Only `max_file_size` and
+    /// `ignore_unknown_tags` apply to this path; the remaining options affect
+    /// the typed parsers.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The GEDCOM content as a string slice
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the content exceeds the configured maximum
+    /// file size, a line is malformed or an unknown level-0 record is found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::GedcomBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_borrowed(source)?;
+    /// assert_eq!(data.individuals[0].name(), Some("John /Doe/"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_borrowed(self, content: &str) -> Result<GedcomDataRef<'_>, GedcomError> {
+        // Check file size limit if configured
+        if let Some(max_size) = self.config.max_file_size {
+            let size = content.len();
+            if size > max_size {
+                return Err(GedcomError::FileSizeLimitExceeded { size, max_size });
+            }
+        }
+
+        GedcomDataRef::parse_with_config(content, &self.config)
+    }
+
     /// Builds the parser and parses the GEDCOM data from a GEDZIP archive.
     ///
     /// This method reads a GEDZIP file (ZIP archive containing `gedcom.ged`)
@@ -558,9 +975,24 @@ impl GedcomBuilder {
         self.build_from_bytes(&gedcom_bytes)
     }
 
-    /// Validates that all cross-references point to existing records.
+    /// Reports every cross-reference that does not point to an existing record as an error
+    /// diagnostic.
+    fn reference_diagnostics(&self, data: &GedcomData) -> impl Iterator<Item = Diagnostic> {
+        self.reference_problems(data)
+            .into_iter()
+            .map(|problem| Diagnostic {
+                severity: Severity::Error,
+                line: problem.span.map(|span| span.start_line as usize),
+                column: None,
+                xref: problem.xref,
+                error: problem.error,
+            })
+    }
+
+    /// Collects every cross-reference that does not point to an existing record, together
+    /// with the xref and span of the record it appears in.
     #[allow(clippy::unused_self)]
-    fn validate_references_internal(&self, data: &GedcomData) -> Result<(), GedcomError> {
+    fn reference_problems(&self, data: &GedcomData) -> Vec<ReferenceProblem> {
         use std::collections::HashSet;
 
         // Collect all xrefs
@@ -602,27 +1034,48 @@ impl GedcomBuilder {
             }
         }
 
+        let mut problems = Vec::new();
+        let mut report = |xref: Option<&Xref>, span: Option<Span>, message: String| {
+            let message = match (xref, span) {
+                (Some(xref), Some(span)) => format!("{message} (record {xref}, {span})"),
+                (None, Some(span)) => format!("{message} ({span})"),
+                (_, None) => message,
+            };
+            problems.push(ReferenceProblem {
+                xref: xref.cloned(),
+                span,
+                error: GedcomError::InvalidFormat(message),
+            });
+        };
+
         // Validate family references
         for family in &data.families {
-            if let Some(ref husb) = family.individual1 {
+            if let Some(husb) = family.individual1() {
                 if !xrefs.contains(husb.as_str()) {
-                    return Err(GedcomError::InvalidFormat(format!(
-                        "Family references non-existent individual: {husb}"
-                    )));
+                    report(
+                        family.xref.as_ref(),
+                        family.span,
+                        format!("Family references non-existent individual: {husb}"),
+                    );
                 }
             }
-            if let Some(ref wife) = family.individual2 {
+            if let Some(wife) = family.individual2() {
                 if !xrefs.contains(wife.as_str()) {
-                    return Err(GedcomError::InvalidFormat(format!(
-                        "Family references non-existent individual: {wife}"
-                    )));
+                    report(
+                        family.xref.as_ref(),
+                        family.span,
+                        format!("Family references non-existent individual: {wife}"),
+                    );
                 }
             }
             for child in &family.children {
+                let child = &child.xref;
                 if !xrefs.contains(child.as_str()) {
-                    return Err(GedcomError::InvalidFormat(format!(
-                        "Family references non-existent child: {child}"
-                    )));
+                    report(
+                        family.xref.as_ref(),
+                        family.span,
+                        format!("Family references non-existent child: {child}"),
+                    );
                 }
             }
         }
@@ -631,18 +1084,29 @@ impl GedcomBuilder {
         for individual in &data.individuals {
             for family_link in &individual.families {
                 if !xrefs.contains(family_link.xref.as_str()) {
-                    return Err(GedcomError::InvalidFormat(format!(
-                        "Individual references non-existent family: {}",
-                        family_link.xref
-                    )));
+                    report(
+                        individual.xref.as_ref(),
+                        individual.span,
+                        format!(
+                            "Individual references non-existent family: {}",
+                            family_link.xref
+                        ),
+                    );
                 }
             }
         }
 
-        Ok(())
+        problems
     }
 }
 
+/// A dangling cross-reference found by reference validation.
+struct ReferenceProblem {
+    xref: Option<Xref>,
+    span: Option<Span>,
+    error: GedcomError,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -747,6 +1211,8 @@ mod tests {
             date_validation: true,
             max_file_size: Some(1000),
             preserve_formatting: false,
+            record_spans: true,
+            error_mode: ErrorMode::Deferred,
         };
         let cloned = config.clone();
         assert_eq!(config.strict_mode, cloned.strict_mode);
@@ -781,4 +1247,279 @@ mod tests {
         let cloned = builder.clone();
         assert!(cloned.config().strict_mode);
     }
+
+    #[test]
+    fn test_strict_mode_rejects_unknown_tag() {
+        let sample =
+            "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n1 BOGUS value\n0 TRLR";
+
+        let result = GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(sample);
+        assert!(matches!(result, Err(GedcomError::InvalidTag { ref tag, .. }) if tag == "BOGUS"));
+
+        let data = GedcomBuilder::new().build_from_str(sample).unwrap();
+        assert_eq!(data.individuals.len(), 1);
+    }
+
+    #[test]
+    fn test_lenient_mode_skips_unknown_subtree() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 BOGUS value\n2 NAME Not /Mine/\n1 NAME John /Doe/\n0 TRLR";
+
+        let data = GedcomBuilder::new().build_from_str(sample).unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
+        assert_eq!(name.value.as_deref(), Some("John /Doe/"));
+    }
+
+    #[test]
+    fn test_ignore_unknown_tags() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 _CUSTOM data\n1 BOGUS value\n0 @X1@ _REC\n0 @Z1@ ZZZZ\n1 NAME x\n0 TRLR";
+
+        let data = GedcomBuilder::new()
+            .strict_mode(true)
+            .ignore_unknown_tags(true)
+            .build_from_str(sample)
+            .unwrap();
+        assert_eq!(data.individuals.len(), 1);
+        assert!(data.individuals[0].custom_data.is_empty());
+        assert!(data.custom_data.is_empty());
+
+        let data = GedcomBuilder::new()
+            .build_from_str("0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 _CUSTOM data\n0 TRLR")
+            .unwrap();
+        assert_eq!(data.individuals[0].custom_data.len(), 1);
+    }
+
+    #[test]
+    fn test_strict_mode_validates_xrefs() {
+        let v7 = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @i-1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+        let v5 = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @i-1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+
+        let result = GedcomBuilder::new().strict_mode(true).build_from_str(v7);
+        assert!(matches!(
+            result,
+            Err(GedcomError::InvalidValueFormat { line: 4, ref value, .. }) if value == "@i-1@"
+        ));
+        assert!(GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(v5)
+            .is_ok());
+        assert!(GedcomBuilder::new().build_from_str(v7).is_ok());
+        #[cfg(feature = "rayon")]
+        assert!(GedcomBuilder::new()
+            .strict_mode(true)
+            .build_parallel(v7)
+            .is_err());
+
+        let long = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I123456789012345678901@ INDI\n0 TRLR";
+        assert!(GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(long)
+            .is_err());
+    }
+
+    #[test]
+    fn test_date_validation() {
+        let bad = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 BIRT\n2 DATE 32 FOO 1900\n0 TRLR";
+        let good = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 BIRT\n2 DATE BET 1 JAN 1900 AND 1910\n0 TRLR";
+
+        let result = GedcomBuilder::new()
+            .date_validation(true)
+            .build_from_str(bad);
+        assert!(matches!(
+            result,
+            Err(GedcomError::InvalidValueFormat { line: 6, .. })
+        ));
+        assert!(GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(bad)
+            .is_err());
+        assert!(GedcomBuilder::new().build_from_str(bad).is_ok());
+        assert!(GedcomBuilder::new()
+            .date_validation(true)
+            .build_from_str(good)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_preserve_formatting() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John   /Doe/  \n0 TRLR";
+
+        let data = GedcomBuilder::new().build_from_str(sample).unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
+        assert_eq!(name.value.as_deref(), Some("John   /Doe/  "));
+
+        let data = GedcomBuilder::new()
+            .preserve_formatting(false)
+            .build_from_str(sample)
+            .unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
+        assert_eq!(name.value.as_deref(), Some("John /Doe/"));
+    }
+
+    #[test]
+    fn test_encoding_detection() {
+        // Declared as UTF-8, which differs from the requested ISO-8859-1
+        let bytes =
+            "0 HEAD\n1 GEDC\n2 VERS 5.5\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Jos\u{e9} /Doe/\n0 TRLR"
+                .as_bytes();
+
+        let data = GedcomBuilder::new()
+            .encoding_detection(true)
+            .build_from_bytes_with_encoding(bytes, GedcomEncoding::Iso8859_1)
+            .unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
+        assert_eq!(name.value.as_deref(), Some("Jos\u{e9} /Doe/"));
+
+        let data = GedcomBuilder::new()
+            .build_from_bytes_with_encoding(bytes, GedcomEncoding::Iso8859_1)
+            .unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
+        assert_ne!(name.value.as_deref(), Some("Jos\u{e9} /Doe/"));
+    }
+
+    #[test]
+    fn test_deferred_error_mode_recovers() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 @I2@ INDI\n1 BIRT\n2 DATE 32 FOO 1900\n0 @X1@ BOGUS\nnot a gedcom line\n0 @I3@ INDI\n1 NAME Jane /Doe/\n0 TRLR";
+
+        let (data, diagnostics) = GedcomBuilder::new()
+            .date_validation(true)
+            .error_mode(ErrorMode::Deferred)
+            .build_from_str_with_diagnostics(sample)
+            .unwrap();
+
+        assert_eq!(data.individuals.len(), 2);
+        assert_eq!(data.individuals[1].xref.as_deref(), Some("@I3@"));
+        assert_eq!(diagnostics.len(), 2);
+
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].xref.as_deref(), Some("@I2@"));
+        assert_eq!(diagnostics[0].line, Some(8));
+        assert!(matches!(
+            diagnostics[0].error,
+            GedcomError::InvalidValueFormat { .. }
+        ));
+
+        assert_eq!(diagnostics[1].xref.as_deref(), Some("@X1@"));
+        assert_eq!(diagnostics[1].line, Some(9));
+        assert_eq!(diagnostics[1].column, Some(8));
+    }
+
+    #[test]
+    fn test_deferred_error_mode_build_returns_first_error() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @X1@ BOGUS\n0 @X2@ OTHER\n0 TRLR";
+
+        let result = GedcomBuilder::new()
+            .error_mode(ErrorMode::Deferred)
+            .build_from_str(sample);
+        match result {
+            Err(GedcomError::ParseError { line, message }) => {
+                assert_eq!(line, 4);
+                assert!(message.contains("BOGUS"));
+            }
+            other => panic!("Expected ParseError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deferred_error_mode_reports_references_and_trailer() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @F1@ FAM\n1 HUSB @I9@\n1 CHIL @I8@";
+
+        let (data, diagnostics) = GedcomBuilder::new()
+            .validate_references(true)
+            .error_mode(ErrorMode::Deferred)
+            .build_from_str_with_diagnostics(sample)
+            .unwrap();
+
+        assert_eq!(data.families.len(), 1);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(diagnostics[1..]
+            .iter()
+            .all(|d| d.severity == Severity::Error && d.xref.as_deref() == Some("@F1@")));
+    }
+
+    #[test]
+    fn test_fail_fast_build_with_diagnostics() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @X1@ BOGUS\n0 TRLR";
+        assert!(GedcomBuilder::new()
+            .build_from_str_with_diagnostics(sample)
+            .is_err());
+    }
+
+    #[test]
+    fn test_record_spans() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME Jos\u{e9} /Doe/\n1 BIRT\n2 DATE 1 JAN 1900\n2 SOUR @S1@\n3 PAGE 12\n1 SEX M\n0 @F1@ FAM\n1 HUSB @I1@\n0 TRLR";
+
+        let data = GedcomBuilder::new()
+            .record_spans(true)
+            .build_from_str(sample)
+            .unwrap();
+
+        let individual = &data.individuals[0];
+        let span = individual.span.unwrap();
+        assert_eq!((span.start_line, span.end_line), (4, 10));
+        assert!(sample[span.byte_range()].starts_with("0 @I1@ INDI\n"));
+        assert!(sample[span.byte_range()].ends_with("1 SEX M\n"));
+
+        let birth = individual.events[0].span.unwrap();
+        assert_eq!((birth.start_line, birth.end_line), (6, 9));
+        assert_eq!(
+            &sample[birth.byte_range()],
+            "1 BIRT\n2 DATE 1 JAN 1900\n2 SOUR @S1@\n3 PAGE 12\n"
+        );
+
+        let citation = individual.events[0].citations[0].span.unwrap();
+        assert_eq!((citation.start_line, citation.end_line), (8, 9));
+
+        let family = data.families[0].span.unwrap();
+        assert_eq!((family.start_line, family.end_line), (11, 12));
+        assert_eq!(&sample[family.byte_range()], "0 @F1@ FAM\n1 HUSB @I1@\n");
+
+        let header = data.header.as_ref().unwrap().span.unwrap();
+        assert_eq!((header.start_line, header.end_line), (1, 3));
+        assert_eq!(header.start_offset, 0);
+    }
+
+    #[test]
+    fn test_record_spans_disabled_by_default() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 TRLR";
+        let data = GedcomBuilder::new().build_from_str(sample).unwrap();
+        assert!(data.individuals[0].span.is_none());
+    }
+
+    #[test]
+    fn test_record_spans_crlf_and_eof() {
+        let sample = "0 HEAD\r\n1 GEDC\r\n2 VERS 5.5\r\n0 @I1@ INDI\r\n1 NAME John /Doe/";
+        let data = GedcomBuilder::new()
+            .record_spans(true)
+            .build_from_str(sample)
+            .unwrap();
+
+        let span = data.individuals[0].span.unwrap();
+        assert_eq!((span.start_line, span.end_line), (4, 5));
+        assert_eq!(
+            &sample[span.byte_range()],
+            "0 @I1@ INDI\r\n1 NAME John /Doe/"
+        );
+    }
+
+    #[test]
+    fn test_reference_error_names_record_span() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @F1@ FAM\n1 HUSB @I9@\n0 TRLR";
+
+        let result = GedcomBuilder::new()
+            .validate_references(true)
+            .record_spans(true)
+            .build_from_str(sample);
+        match result {
+            Err(GedcomError::InvalidFormat(message)) => {
+                assert_eq!(
+                    message,
+                    "Family references non-existent individual: @I9@ (record @F1@, lines 4-5)"
+                );
+            }
+            other => panic!("Expected InvalidFormat error, got {other:?}"),
+        }
+    }
 }
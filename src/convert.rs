@@ -0,0 +1,1360 @@
+//! Conversion of GEDCOM data between versions 5.5.1 and 7.0.
+//!
+//! [`GedcomData::convert_to`] rewrites a parsed dataset into the structures of another GEDCOM
+//! version. Every structure it rewrites, cannot fully describe, or has to drop is reported
+//! with a [`ConversionNote`], so a migration can be checked for losses before the old files
+//! are retired.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ged_io::{GedcomBuilder, GedcomVersion, GedcomWriter};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n2 FORM LINEAGE-LINKED\n1 CHAR ANSEL\n\
+//!     1 SUBN @SUB1@\n0 @I1@ INDI\n1 NAME Anna /Berg/\n1 DEAT\n2 AGE INFANT\n\
+//!     1 ASSO @I2@\n2 RELA Godmother\n0 @SUB1@ SUBN\n1 TEMP SLAKE\n0 TRLR";
+//! let data = GedcomBuilder::new().build_from_str(source)?;
+//!
+//! let (converted, notes) = data.convert_to(GedcomVersion::V7_0);
+//! for note in notes.iter().filter(|note| note.is_lossy()) {
+//!     println!("{note}"); // e.g. "lost SUBN in record @SUB1@: submission record removed; ..."
+//! }
+//! assert_eq!(notes.iter().filter(|note| note.is_lossy()).count(), 2);
+//!
+//! let output = GedcomWriter::new().gedcom_version("7.0").write_to_string(&converted)?;
+//! assert!(output.contains("2 AGE < 1y\n3 PHRASE Infant\n"));
+//! assert!(output.contains("2 ROLE GODP\n3 PHRASE Godmother\n"));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+};
+
+use crate::{
+    types::{
+        age::{Age, AgeModifier},
+        custom::UserDefinedTag,
+        date::Date,
+        event::detail::Detail,
+        external_id::ExternalId,
+        family::Family,
+        gedcom7::NonEvent,
+        header::{
+            meta::HeadMeta,
+            schema::{Schema, TagDefinition},
+            Header,
+        },
+        individual::{
+            association::{Association, Role},
+            attribute::detail::AttributeDetail,
+            Individual,
+        },
+        lds::LdsOrdinance,
+        multimedia::{file::Reference, format::Format, Multimedia},
+        note::Note,
+        place::Place,
+        repository::Repository,
+        shared_note::SharedNote,
+        source::{citation::Citation, Source},
+        submitter::Submitter,
+        Xref,
+    },
+    util::{escape_at_signs, is_pointer, unescape_at_signs},
+    GedcomData, GedcomVersion,
+};
+
+/// `EXID.TYPE` for a GEDCOM 5.5.1 ancestral file number (`AFN`).
+const AFN_TYPE: &str = "https://gedcom.io/terms/v7/AFN";
+/// `EXID.TYPE` for a GEDCOM 5.5.1 automated record ID (`RIN`).
+const RIN_TYPE: &str = "https://gedcom.io/terms/v7/RIN";
+
+/// GEDCOM 5.5.1 multimedia format names and their media types.
+///
+/// The first name listed for a media type is used when converting back to 5.5.1.
+const MEDIA_TYPES: &[(&str, &str)] = &[
+    ("bmp", "image/bmp"),
+    ("gif", "image/gif"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("png", "image/png"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("pcx", "image/vnd.zbrush.pcx"),
+    ("wav", "audio/wav"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("avi", "video/x-msvideo"),
+    ("pdf", "application/pdf"),
+    ("txt", "text/plain"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+];
+
+/// The source media enumeration shared by `MEDI` in both versions, spelled in lower case by
+/// GEDCOM 5.5.1 and in upper case by GEDCOM 7.0, which adds `OTHER`.
+const MEDIA: &[&str] = &[
+    "AUDIO",
+    "BOOK",
+    "CARD",
+    "ELECTRONIC",
+    "FICHE",
+    "FILM",
+    "MAGAZINE",
+    "MANUSCRIPT",
+    "MAP",
+    "NEWSPAPER",
+    "PHOTO",
+    "TOMBSTONE",
+    "VIDEO",
+];
+
+/// What happened to a structure during [`GedcomData::convert_to`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConversionKind {
+    /// The structure was rewritten into the target version's form without losing anything.
+    Converted,
+    /// The structure was kept, but the target version cannot fully describe it (e.g. an
+    /// extension tag without a `SCHMA` URI).
+    Unmapped,
+    /// Information was removed because the target version has no place for it.
+    Lost,
+}
+
+impl fmt::Display for ConversionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionKind::Converted => write!(f, "converted"),
+            ConversionKind::Unmapped => write!(f, "unmapped"),
+            ConversionKind::Lost => write!(f, "lost"),
+        }
+    }
+}
+
+/// A structure that was rewritten or dropped while converting GEDCOM data to another version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionNote {
+    /// What happened to the structure.
+    pub kind: ConversionKind,
+    /// The xref of the level-0 record the structure belongs to, if the record has one.
+    pub xref: Option<Xref>,
+    /// The tag of the structure in the source data (e.g. `RELA` or `SUBN`).
+    pub tag: String,
+    /// A description of the change.
+    pub message: String,
+}
+
+impl ConversionNote {
+    /// Returns true if information was removed by the conversion.
+    #[must_use]
+    pub fn is_lossy(&self) -> bool {
+        self.kind == ConversionKind::Lost
+    }
+}
+
+impl fmt::Display for ConversionNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.tag)?;
+        if let Some(xref) = &self.xref {
+            write!(f, " in record {xref}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl GedcomData {
+    /// Converts the data to the structures of another GEDCOM version.
+    ///
+    /// Returns the converted data and a note for every structure that was rewritten, could
+    /// not be fully described, or was removed. Converting between 5.5.1 and 7.0 covers:
+    ///
+    /// - the `GEDC` version and form, and the `CHAR` line, which GEDCOM 7.0 removed
+    /// - shared note records, `NOTE` in 5.5.1 and `SNOTE` in 7.0
+    /// - submission records and the header `SUBN` pointer, which GEDCOM 7.0 removed
+    /// - association `RELA` descriptions and `ROLE` enumerations
+    /// - extension tags, which are declared in `HEAD.SCHMA` for 7.0
+    /// - the `AGE` keywords of 5.5.1 and the week counts and phrases of 7.0
+    /// - multimedia `FORM` names and media types, and the `MEDI` enumeration
+    /// - `RIN` and `AFN`, which become `EXID` structures in 7.0
+    /// - at-sign escapes in note, source and extension text and in email addresses
+    ///
+    /// Converting to 5.5.1 also removes the structures that only exist in 7.0, such as
+    /// `PHRASE`, `EXID`, `SDATE`, `NO`, `CREA` and note translations, with a note for each.
+    ///
+    /// GEDCOM 7.0 declares each extension tag with a URI, which 5.5.1 data does not carry.
+    /// Tags already declared in `header.schema` stay declared; use
+    /// [`convert_to_with`](Self::convert_to_with) to supply URIs for the others. Any extension
+    /// tag left without a URI is reported as [`ConversionKind::Unmapped`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{convert::ConversionKind, GedcomBuilder, GedcomVersion};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @N1@ SNOTE Baptized in the parish\n\
+    ///     1 LANG en\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_from_str(source)?;
+    ///
+    /// let (converted, notes) = data.convert_to(GedcomVersion::V5_5_1);
+    /// assert_eq!(converted.header.unwrap().version(), Some("5.5.1"));
+    /// assert_eq!(notes[0].kind, ConversionKind::Converted);
+    /// assert_eq!(notes[1].to_string(), "lost LANG in record @N1@: language en removed");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn convert_to(&self, version: GedcomVersion) -> (GedcomData, Vec<ConversionNote>) {
+        self.convert_to_with(version, &HashMap::new())
+    }
+
+    /// Converts the data like [`convert_to`](Self::convert_to), declaring extension tags in
+    /// `HEAD.SCHMA` with the URIs in `extension_uris`, keyed by tag (e.g. `_SKYPEID`).
+    ///
+    /// Each supplied URI is declared for a tag that occurs in the data and is not declared
+    /// yet, and reported as [`ConversionKind::Converted`]. The URIs are ignored when
+    /// converting to 5.5.1, which has no schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use ged_io::{GedcomBuilder, GedcomVersion};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 _SKYPEID annaberg\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_from_str(source)?;
+    ///
+    /// let uris = HashMap::from([(
+    ///     "_SKYPEID".to_string(),
+    ///     "http://xmlns.com/foaf/0.1/skypeID".to_string(),
+    /// )]);
+    /// let (converted, _) = data.convert_to_with(GedcomVersion::V7_0, &uris);
+    /// let header = converted.header.unwrap();
+    /// assert_eq!(
+    ///     header.find_extension_uri("_SKYPEID"),
+    ///     Some("http://xmlns.com/foaf/0.1/skypeID")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn convert_to_with(
+        &self,
+        version: GedcomVersion,
+        extension_uris: &HashMap<String, String>,
+    ) -> (GedcomData, Vec<ConversionNote>) {
+        let source = self
+            .header
+            .as_ref()
+            .and_then(Header::version)
+            .map(GedcomVersion::from_version_str);
+        let mut converter = Converter::new(source.as_ref(), version, extension_uris);
+        let mut data = self.clone();
+        converter.data(&mut data);
+        (data, converter.notes)
+    }
+}
+
+/// Walks a dataset, rewriting it for the target version and collecting notes.
+struct Converter<'a> {
+    version: GedcomVersion,
+    to_v7: bool,
+    /// Whether the text payloads follow the at-sign rules of GEDCOM 7.0
+    source_v7: bool,
+    /// The xref of the record being converted
+    xref: Option<Xref>,
+    /// The extension tags used anywhere in the data
+    extension_tags: BTreeSet<String>,
+    /// The URIs to declare extension tags with, keyed by tag
+    extension_uris: &'a HashMap<String, String>,
+    notes: Vec<ConversionNote>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(
+        source: Option<&GedcomVersion>,
+        version: GedcomVersion,
+        extension_uris: &'a HashMap<String, String>,
+    ) -> Self {
+        let to_v7 = version.is_v7();
+        Converter {
+            version,
+            to_v7,
+            source_v7: source.map_or(to_v7, GedcomVersion::is_v7),
+            xref: None,
+            extension_tags: BTreeSet::new(),
+            extension_uris,
+            notes: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, kind: ConversionKind, tag: &str, message: String) {
+        self.notes.push(ConversionNote {
+            kind,
+            xref: self.xref.clone(),
+            tag: tag.to_string(),
+            message,
+        });
+    }
+
+    fn converted(&mut self, tag: &str, message: String) {
+        self.report(ConversionKind::Converted, tag, message);
+    }
+
+    fn lost(&mut self, tag: &str, message: String) {
+        self.report(ConversionKind::Lost, tag, message);
+    }
+
+    fn record(&mut self, xref: Option<&Xref>) {
+        self.xref = xref.cloned();
+    }
+
+    fn data(&mut self, data: &mut GedcomData) {
+        let header = data.header.get_or_insert_with(Header::default);
+        self.header(header);
+
+        if self.to_v7 {
+            for submission in data.submissions.drain(..) {
+                self.record(submission.xref.as_ref());
+                self.lost(
+                    "SUBN",
+                    "submission record removed; GEDCOM 7.0 has no submission records".to_string(),
+                );
+            }
+        }
+        for submission in &mut data.submissions {
+            self.record(submission.xref.as_ref());
+            self.notes(&mut submission.notes);
+            self.custom(&mut submission.custom);
+        }
+        for submitter in &mut data.submitters {
+            self.submitter(submitter);
+        }
+        for individual in &mut data.individuals {
+            self.individual(individual);
+        }
+        for family in &mut data.families {
+            self.family(family);
+        }
+        for repository in &mut data.repositories {
+            self.repository(repository);
+        }
+        for source in &mut data.sources {
+            self.source(source);
+        }
+        for media in &mut data.multimedia {
+            self.record(media.xref.as_ref());
+            self.multimedia(media);
+        }
+        for note in &mut data.shared_notes {
+            self.shared_note(note);
+        }
+
+        self.xref = None;
+        self.custom(&mut data.custom_data);
+        self.schema(header);
+    }
+
+    fn header(&mut self, header: &mut Header) {
+        self.xref = None;
+        let meta = header.gedcom.get_or_insert_with(HeadMeta::default);
+        let same_major = meta.version.as_deref().is_some_and(|version| {
+            GedcomVersion::from_version_str(version).major() == self.version.major()
+        });
+        if !same_major {
+            meta.version = Some(self.version.as_str().to_string());
+        }
+
+        if self.to_v7 {
+            if let Some(form) = meta.form.take() {
+                self.converted(
+                    "FORM",
+                    format!("GEDC.FORM {form} removed; GEDCOM 7.0 is always lineage-linked"),
+                );
+            }
+            if let Some(encoding) = header.encoding.take() {
+                let name = encoding.value.unwrap_or_default();
+                self.converted(
+                    "CHAR",
+                    format!("CHAR {name} removed; GEDCOM 7.0 files are always UTF-8"),
+                );
+            }
+            if let Some(submission) = header.submission_tag.take() {
+                self.lost(
+                    "SUBN",
+                    format!("header pointer to submission {submission} removed"),
+                );
+            }
+            if let Some(filename) = header.filename.take() {
+                self.lost(
+                    "FILE",
+                    format!("file name {filename} removed; GEDCOM 7.0 headers have no FILE"),
+                );
+            }
+        } else if meta.form.is_none() {
+            meta.form = Some("LINEAGE-LINKED".to_string());
+        }
+
+        if let Some(note) = &mut header.note {
+            self.note(note);
+        }
+        if let Some(meta) = &mut header.gedcom {
+            self.custom(&mut meta.custom_data);
+        }
+        if let Some(encoding) = &mut header.encoding {
+            self.custom(&mut encoding.custom_data);
+        }
+        if let Some(place) = &mut header.place {
+            self.custom(&mut place.custom_data);
+        }
+        self.custom(&mut header.custom_data);
+    }
+
+    /// Declares the extension tags for 7.0, or drops the schema for 5.5.1.
+    ///
+    /// Runs last, once every extension tag in the data has been seen.
+    fn schema(&mut self, header: &mut Header) {
+        self.xref = None;
+        if !self.to_v7 {
+            for definition in header
+                .schema
+                .take()
+                .into_iter()
+                .flat_map(|s| s.tag_definitions)
+            {
+                self.lost(
+                    "SCHMA",
+                    format!(
+                        "declaration of {} as {} removed; GEDCOM 5.5.1 has no schema",
+                        definition.tag, definition.uri
+                    ),
+                );
+            }
+            return;
+        }
+
+        let tags = std::mem::take(&mut self.extension_tags);
+        for tag in tags {
+            if header.find_extension_uri(&tag).is_some() {
+                continue;
+            }
+            if let Some(uri) = self.extension_uris.get(&tag) {
+                header
+                    .schema
+                    .get_or_insert_with(Schema::default)
+                    .add_definition(TagDefinition::new(&tag, uri));
+                self.converted(
+                    &tag,
+                    format!("extension tag {tag} declared in HEAD.SCHMA as {uri}"),
+                );
+            } else {
+                self.report(
+                    ConversionKind::Unmapped,
+                    &tag,
+                    format!("extension tag {tag} has no URI to declare in HEAD.SCHMA"),
+                );
+            }
+        }
+    }
+
+    fn submitter(&mut self, submitter: &mut Submitter) {
+        self.record(submitter.xref.as_ref());
+        for email in &mut submitter.email {
+            self.text(email);
+        }
+        for link in &mut submitter.multimedia {
+            if let Some(form) = &mut link.form {
+                self.format(form);
+            }
+            if let Some(file) = &mut link.file {
+                self.file(file);
+            }
+            self.custom(&mut link.custom_data);
+        }
+        self.notes(&mut submitter.notes);
+        self.custom(&mut submitter.custom_data);
+
+        let ids = &mut submitter.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut submitter.automated_record_id, ids);
+        self.external_ids(ids);
+
+        if self.to_v7 {
+            if let Some(rfn) = submitter.registered_refn.take() {
+                self.lost(
+                    "RFN",
+                    format!("registered RFN {rfn} removed; GEDCOM 7.0 has no RFN"),
+                );
+            }
+        }
+    }
+
+    fn individual(&mut self, individual: &mut Individual) {
+        self.record(individual.xref.as_ref());
+        for name in &mut individual.names {
+            self.notes(&mut name.notes);
+            self.citations(&mut name.source);
+            self.custom(&mut name.custom_data);
+        }
+        for event in &mut individual.events {
+            self.event(event);
+        }
+        for attribute in &mut individual.attributes {
+            self.attribute(attribute);
+        }
+        for link in &mut individual.families {
+            self.notes(&mut link.notes);
+            self.custom(&mut link.custom_data);
+        }
+        for association in &mut individual.associations {
+            self.association(association);
+        }
+        self.citations(&mut individual.source);
+        self.multimedia_links(&mut individual.multimedia);
+        self.notes(&mut individual.notes);
+        self.custom(&mut individual.custom_data);
+
+        let ids = &mut individual.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut individual.automated_record_id, ids);
+        self.identifier("AFN", AFN_TYPE, &mut individual.ancestral_file_number, ids);
+        self.external_ids(ids);
+        self.non_events(&mut individual.non_events);
+        self.lds_ordinances(&mut individual.lds_ordinances);
+    }
+
+    fn family(&mut self, family: &mut Family) {
+        self.record(family.xref.as_ref());
+        let individuals1 = family
+            .individual1
+            .iter_mut()
+            .chain(&mut family.additional_individual1);
+        let individuals2 = family
+            .individual2
+            .iter_mut()
+            .chain(&mut family.additional_individual2);
+        let members = individuals1
+            .map(|member| ("HUSB", member))
+            .chain(individuals2.map(|member| ("WIFE", member)))
+            .chain(family.children.iter_mut().map(|member| ("CHIL", member)));
+        for (tag, member) in members {
+            if !self.to_v7 {
+                if let Some(phrase) = member.phrase.take() {
+                    self.lost("PHRASE", format!("{tag} phrase {phrase} removed"));
+                }
+            }
+            self.custom(&mut member.custom_data);
+        }
+        for event in family.events.iter_mut().chain(&mut family.family_event) {
+            self.event(event);
+        }
+        self.citations(&mut family.sources);
+        self.multimedia_links(&mut family.multimedia);
+        self.notes(&mut family.notes);
+        self.custom(&mut family.custom_data);
+
+        let ids = &mut family.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut family.automated_record_id, ids);
+        self.external_ids(ids);
+        self.non_events(&mut family.non_events);
+        self.lds_ordinances(&mut family.lds_ordinances);
+    }
+
+    fn repository(&mut self, repository: &mut Repository) {
+        self.record(repository.xref.as_ref());
+        for email in &mut repository.email {
+            self.text(email);
+        }
+        self.notes(&mut repository.notes);
+        self.custom(&mut repository.custom_data);
+
+        let ids = &mut repository.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut repository.automated_record_id, ids);
+        self.external_ids(ids);
+    }
+
+    fn source(&mut self, source: &mut Source) {
+        self.record(source.xref.as_ref());
+        for text in [
+            &mut source.title,
+            &mut source.author,
+            &mut source.publication_facts,
+            &mut source.citation_from_source,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            self.text(text);
+        }
+        self.multimedia_links(&mut source.multimedia);
+        self.notes(&mut source.notes);
+        for citation in &mut source.repo_citations {
+            self.notes(&mut citation.notes);
+            self.custom(&mut citation.custom_data);
+        }
+        self.custom(&mut source.custom_data);
+
+        let ids = &mut source.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut source.automated_record_id, ids);
+        self.external_ids(ids);
+    }
+
+    fn shared_note(&mut self, note: &mut SharedNote) {
+        self.record(note.xref.as_ref());
+        let (from, to) = if self.to_v7 {
+            ("NOTE", "SNOTE")
+        } else {
+            ("SNOTE", "NOTE")
+        };
+        if note
+            .version
+            .as_ref()
+            .is_some_and(|version| version.is_v7() != self.to_v7)
+        {
+            self.converted(from, format!("{from} record written as {to} record"));
+        }
+        note.version = Some(self.version.clone());
+
+        self.text(&mut note.text);
+        self.citations(&mut note.source_citations);
+        self.custom(&mut note.custom_data);
+
+        let ids = &mut note.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut note.automated_record_id, ids);
+        self.external_ids(ids);
+        if self.to_v7 {
+            for translation in &mut note.translations {
+                self.text(&mut translation.text);
+            }
+            return;
+        }
+
+        self.note_format(note.mime.take(), note.language.take());
+        for translation in note.translations.drain(..) {
+            self.lost("TRAN", format!("translation {} removed", translation.text));
+        }
+        if note.creation_date.take().is_some() {
+            self.lost("CREA", "creation date removed".to_string());
+        }
+    }
+
+    fn event(&mut self, event: &mut Detail) {
+        if let Some(place) = &mut event.place {
+            self.place(place);
+        }
+        self.date(event.date.as_mut());
+        self.citations(&mut event.citations);
+        self.notes(&mut event.notes);
+        self.multimedia_links(&mut event.multimedia);
+        if let Some(age) = &mut event.age {
+            self.age(age);
+        }
+        for detail in &mut event.family_event_details {
+            if let Some(age) = &mut detail.age {
+                self.age(age);
+            }
+            self.custom(&mut detail.custom_data);
+        }
+        self.custom(&mut event.custom_data);
+
+        if self.to_v7 {
+            for association in &mut event.associations {
+                self.association(association);
+            }
+            return;
+        }
+        if let Some(sort_date) = event.sort_date.take() {
+            let value = sort_date.value.unwrap_or_default();
+            self.lost("SDATE", format!("sort date {value} removed"));
+        }
+        for association in event.associations.drain(..) {
+            self.lost(
+                "ASSO",
+                format!(
+                    "association with {} removed; GEDCOM 5.5.1 events have no associations",
+                    association.xref
+                ),
+            );
+        }
+    }
+
+    fn attribute(&mut self, attribute: &mut AttributeDetail) {
+        if let Some(place) = &mut attribute.place {
+            self.place(place);
+        }
+        self.date(attribute.date.as_mut());
+        self.citations(&mut attribute.sources);
+        self.notes(&mut attribute.notes);
+        if let Some(age) = &mut attribute.age {
+            self.age(age);
+        }
+        self.custom(&mut attribute.custom_data);
+    }
+
+    fn place(&mut self, place: &mut Place) {
+        self.notes(&mut place.notes);
+        self.citations(&mut place.citations);
+        self.custom(&mut place.custom_data);
+        self.external_ids(&mut place.external_ids);
+    }
+
+    fn date(&mut self, date: Option<&mut Date>) {
+        let Some(date) = date else {
+            return;
+        };
+        self.custom(&mut date.custom_data);
+        if self.to_v7 {
+            return;
+        }
+        if let Some(phrase) = date.phrase.take() {
+            self.lost("PHRASE", format!("date phrase {phrase} removed"));
+        }
+    }
+
+    fn association(&mut self, association: &mut Association) {
+        if self.to_v7 {
+            if let Some(relationship) = association.relationship.take() {
+                let role = association
+                    .role
+                    .clone()
+                    .unwrap_or_else(|| Role::from_relationship(&relationship));
+                let described = relationship.trim().eq_ignore_ascii_case(role.description());
+                if association.role_phrase.is_none() && !described {
+                    association.role_phrase = Some(relationship.clone());
+                }
+                self.converted(
+                    "RELA",
+                    format!("RELA {relationship} written as ROLE {}", role.as_str()),
+                );
+                association.role = Some(role);
+            }
+            if let Some(association_type) = association.association_type.take() {
+                if association_type != "INDI" {
+                    self.lost(
+                        "TYPE",
+                        format!("association type {association_type} removed; GEDCOM 7.0 associations point to individuals"),
+                    );
+                }
+            }
+        } else {
+            let role = association.role.take();
+            let phrase = association.role_phrase.take();
+            if association.relationship.is_none() {
+                if let Some(role) = role {
+                    let description = phrase.unwrap_or_else(|| role.description().to_string());
+                    self.converted(
+                        "ROLE",
+                        format!("ROLE {} written as RELA {description}", role.as_str()),
+                    );
+                    association.relationship = Some(description);
+                }
+            }
+            if let Some(phrase) = association.phrase.take() {
+                self.lost("PHRASE", format!("association phrase {phrase} removed"));
+            }
+        }
+
+        self.citations(&mut association.source_citations);
+        self.notes(&mut association.notes);
+        self.custom(&mut association.role_custom_data);
+        self.custom(&mut association.custom_data);
+    }
+
+    fn citations(&mut self, citations: &mut [Citation]) {
+        for citation in citations {
+            self.citation(citation);
+        }
+    }
+
+    fn citation(&mut self, citation: &mut Citation) {
+        if let Some(page) = &mut citation.page {
+            self.text(page);
+        }
+        if let Some(data) = &mut citation.data {
+            self.date(data.date.as_mut());
+            for text in &mut data.texts {
+                if let Some(value) = &mut text.value {
+                    self.text(value);
+                }
+                if !self.to_v7 {
+                    self.note_format(text.mime.take(), text.language.take());
+                }
+                self.custom(&mut text.custom_data);
+            }
+            self.custom(&mut data.custom_data);
+        }
+        self.multimedia_links(&mut citation.multimedia);
+        self.notes(&mut citation.notes);
+        self.custom(&mut citation.event_custom_data);
+        self.custom(&mut citation.role_custom_data);
+        self.custom(&mut citation.custom_data);
+
+        if !self.to_v7 {
+            if let Some(phrase) = citation.event_phrase.take() {
+                self.lost("PHRASE", format!("cited event phrase {phrase} removed"));
+            }
+            if let Some(phrase) = citation.role_phrase.take() {
+                self.lost("PHRASE", format!("cited role phrase {phrase} removed"));
+            }
+        }
+    }
+
+    fn multimedia_links(&mut self, links: &mut [Multimedia]) {
+        for media in links {
+            self.multimedia(media);
+        }
+    }
+
+    fn multimedia(&mut self, media: &mut Multimedia) {
+        if self.to_v7 {
+            // GEDCOM 5.5 puts FORM on the record; 7.0 only has it under each FILE
+            if let Some(form) = media.form.take() {
+                let name = form.value.clone().unwrap_or_default();
+                if media.files.is_empty() {
+                    self.lost("FORM", format!("format {name} removed; there is no FILE"));
+                } else {
+                    for file in media.files.iter_mut().filter(|file| file.form.is_none()) {
+                        file.form = Some(form.clone());
+                    }
+                    self.converted("FORM", format!("format {name} moved to FILE.FORM"));
+                }
+            }
+        } else {
+            if let Some(restriction) = media.restriction.take() {
+                self.lost("RESN", format!("restriction {restriction} removed"));
+            }
+            for uid in media.uids.drain(..) {
+                self.lost("UID", format!("unique identifier {uid} removed"));
+            }
+            if media.creation_date.take().is_some() {
+                self.lost("CREA", "creation date removed".to_string());
+            }
+        }
+
+        for file in &mut media.files {
+            self.file(file);
+        }
+        if let Some(form) = &mut media.form {
+            self.format(form);
+        }
+        self.citations(&mut media.source_citations);
+        self.notes(&mut media.notes);
+        self.custom(&mut media.custom_data);
+
+        let ids = &mut media.external_ids;
+        self.identifier("RIN", RIN_TYPE, &mut media.automated_record_id, ids);
+        self.external_ids(ids);
+    }
+
+    fn file(&mut self, file: &mut Reference) {
+        if let Some(form) = &mut file.form {
+            self.format(form);
+        }
+        self.custom(&mut file.custom_data);
+        if self.to_v7 {
+            for translation in &mut file.translations {
+                if let Some(form) = &mut translation.form {
+                    self.format(form);
+                }
+            }
+            return;
+        }
+
+        if file.crop.take().is_some() {
+            self.lost("CROP", "crop region removed".to_string());
+        }
+        for translation in file.translations.drain(..) {
+            let value = translation.value.unwrap_or_default();
+            self.lost("TRAN", format!("alternate file {value} removed"));
+        }
+    }
+
+    /// Converts a 5.5.1 format name to a media type and a 5.5.1 medium to the 7.0
+    /// enumeration, or back.
+    fn format(&mut self, form: &mut Format) {
+        self.custom(&mut form.source_media_custom_data);
+        self.custom(&mut form.custom_data);
+        if let Some(value) = &mut form.value {
+            if self.to_v7 && !value.contains('/') {
+                let name = value.to_ascii_lowercase();
+                match MEDIA_TYPES.iter().find(|(known, _)| *known == name) {
+                    Some((_, media_type)) => {
+                        self.converted("FORM", format!("FORM {value} written as {media_type}"));
+                        *value = (*media_type).to_string();
+                    }
+                    None => self.report(
+                        ConversionKind::Unmapped,
+                        "FORM",
+                        format!("format {value} has no known media type"),
+                    ),
+                }
+            } else if !self.to_v7 {
+                let media_type = value.to_ascii_lowercase();
+                if let Some((name, _)) = MEDIA_TYPES.iter().find(|(_, known)| *known == media_type)
+                {
+                    self.converted("FORM", format!("FORM {value} written as {name}"));
+                    *value = (*name).to_string();
+                }
+            }
+        }
+
+        if let Some(medium) = form.source_media_type.take() {
+            let upper = medium.trim().to_ascii_uppercase();
+            let known = MEDIA.contains(&upper.as_str());
+            form.source_media_type = if self.to_v7 && !known && upper != "OTHER" {
+                self.converted(
+                    "MEDI",
+                    format!("medium {medium} written as OTHER with a PHRASE"),
+                );
+                form.source_media_phrase.get_or_insert(medium);
+                Some("OTHER".to_string())
+            } else if self.to_v7 {
+                Some(upper)
+            } else if known {
+                Some(upper.to_ascii_lowercase())
+            } else {
+                let phrase = form.source_media_phrase.take().unwrap_or_default();
+                self.lost("TYPE", format!("medium {medium} {phrase} removed"));
+                None
+            };
+        }
+        if !self.to_v7 {
+            if let Some(phrase) = form.source_media_phrase.take() {
+                self.lost("PHRASE", format!("medium phrase {phrase} removed"));
+            }
+        }
+    }
+
+    fn notes(&mut self, notes: &mut [Note]) {
+        for note in notes {
+            self.note(note);
+        }
+    }
+
+    fn note(&mut self, note: &mut Note) {
+        if let Some(value) = &mut note.value {
+            self.text(value);
+        }
+        self.custom(&mut note.custom_data);
+        if self.to_v7 {
+            if let Some(value) = note.translation.as_mut().and_then(|t| t.value.as_mut()) {
+                self.text(value);
+            }
+            return;
+        }
+
+        self.note_format(note.mime.take(), note.language.take());
+        if let Some(translation) = note.translation.take() {
+            let value = translation.value.unwrap_or_default();
+            self.lost("TRAN", format!("translation {value} removed"));
+        }
+    }
+
+    /// Reports the 7.0 media type and language of a text, which 5.5.1 cannot record.
+    fn note_format(&mut self, mime: Option<String>, language: Option<String>) {
+        if let Some(mime) = mime.filter(|mime| mime != "text/plain") {
+            self.lost("MIME", format!("media type {mime} removed"));
+        }
+        if let Some(language) = language {
+            self.lost("LANG", format!("language {language} removed"));
+        }
+    }
+
+    fn custom(&mut self, tags: &mut [Box<UserDefinedTag>]) {
+        for tag in tags {
+            if tag.tag.starts_with('_') {
+                self.extension_tags.insert(tag.tag.clone());
+            }
+            if let Some(value) = &mut tag.value {
+                self.text(value);
+            }
+            self.custom(&mut tag.children);
+        }
+    }
+
+    fn age(&mut self, age: &mut Age) {
+        if self.to_v7 {
+            let (years, modifier, phrase) = match age {
+                Age::Child => (8, AgeModifier::LessThan, "Child"),
+                Age::Infant => (1, AgeModifier::LessThan, "Infant"),
+                Age::Stillborn => (0, AgeModifier::Exact, "Stillborn"),
+                Age::Numeric { .. } => return,
+            };
+            let numeric = Age::Numeric {
+                years: Some(years),
+                months: None,
+                weeks: None,
+                days: None,
+                modifier,
+                phrase: Some(phrase.to_string()),
+            };
+            self.converted(
+                "AGE",
+                format!("AGE {age} written as {numeric} with a PHRASE"),
+            );
+            *age = numeric;
+            return;
+        }
+
+        let Age::Numeric {
+            years,
+            months,
+            weeks,
+            days,
+            modifier,
+            phrase,
+        } = age
+        else {
+            return;
+        };
+        // Undo the keyword conversion above
+        let keyword = match (phrase.as_deref(), *years, *months, *weeks, *days, *modifier) {
+            (Some("Child"), Some(8), None, None, None, AgeModifier::LessThan) => Some(Age::Child),
+            (Some("Infant"), Some(1), None, None, None, AgeModifier::LessThan) => Some(Age::Infant),
+            (Some("Stillborn"), Some(0), None, None, None, AgeModifier::Exact) => {
+                Some(Age::Stillborn)
+            }
+            _ => None,
+        };
+        if let Some(keyword) = keyword {
+            self.converted("AGE", format!("AGE {age} written as {keyword}"));
+            *age = keyword;
+            return;
+        }
+
+        if let Some(week_count) = weeks.take() {
+            let total = u16::from(week_count) * 7 + u16::from(days.unwrap_or(0));
+            if let Ok(total) = u8::try_from(total) {
+                *days = Some(total);
+                self.converted("AGE", format!("{week_count} weeks written as days"));
+            } else {
+                self.lost("AGE", format!("{week_count} weeks removed; too many days"));
+            }
+        }
+        if let Some(phrase) = phrase.take() {
+            self.lost("PHRASE", format!("age phrase {phrase} removed"));
+        }
+    }
+
+    /// Moves a 5.5.1 identifier into an `EXID` for 7.0, or back out of one for 5.5.1.
+    fn identifier(
+        &mut self,
+        tag: &str,
+        type_uri: &str,
+        value: &mut Option<String>,
+        external_ids: &mut Vec<ExternalId>,
+    ) {
+        if self.to_v7 {
+            if let Some(id) = value.take() {
+                self.converted(
+                    tag,
+                    format!("{tag} {id} written as EXID with TYPE {type_uri}"),
+                );
+                external_ids.push(ExternalId {
+                    id,
+                    type_uri: Some(type_uri.to_string()),
+                    custom_data: Vec::new(),
+                });
+            }
+        } else if let Some(index) = external_ids
+            .iter()
+            .position(|exid| exid.type_uri.as_deref() == Some(type_uri))
+        {
+            let exid = external_ids.remove(index);
+            self.converted("EXID", format!("EXID {} written as {tag}", exid.id));
+            *value = Some(exid.id);
+        }
+    }
+
+    /// Removes the external identifiers that 5.5.1 cannot record.
+    fn external_ids(&mut self, external_ids: &mut Vec<ExternalId>) {
+        if self.to_v7 {
+            for exid in external_ids {
+                self.custom(&mut exid.custom_data);
+            }
+            return;
+        }
+        for exid in external_ids.drain(..) {
+            self.lost("EXID", format!("external identifier {} removed", exid.id));
+        }
+    }
+
+    fn non_events(&mut self, non_events: &mut Vec<NonEvent>) {
+        if self.to_v7 {
+            for non_event in non_events {
+                self.date(non_event.date.as_mut());
+                self.notes(&mut non_event.notes);
+                self.citations(&mut non_event.source_citations);
+                self.custom(&mut non_event.custom_data);
+            }
+            return;
+        }
+        for non_event in non_events.drain(..) {
+            self.lost("NO", format!("non-event {} removed", non_event.event_type));
+        }
+    }
+
+    fn lds_ordinances(&mut self, ordinances: &mut Vec<LdsOrdinance>) {
+        for ordinance in ordinances.iter_mut() {
+            self.custom(&mut ordinance.custom_data);
+        }
+        if self.to_v7 {
+            return;
+        }
+        ordinances.retain(|ordinance| {
+            let keep = !ordinance.is_gedcom_7_only();
+            if !keep {
+                let description = ordinance
+                    .ordinance_type
+                    .as_ref()
+                    .map_or("ordinance", |kind| kind.description());
+                self.lost("INIL", format!("{description} removed"));
+            }
+            keep
+        });
+    }
+
+    /// Rewrites the at-sign escapes of a text payload for the target version.
+    fn text(&self, text: &mut String) {
+        if self.source_v7 == self.to_v7 || !text.contains('@') || is_pointer(text) {
+            return;
+        }
+        let (escape, rest) = match text.strip_prefix("@#").and_then(|rest| rest.find('@')) {
+            Some(end) => text.split_at(end + 3),
+            None => ("", text.as_str()),
+        };
+        let rest = unescape_at_signs(rest, self.source_v7);
+        *text = format!("{escape}{}", escape_at_signs(&rest, self.to_v7));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GedcomBuilder;
+
+    fn convert(source: &str, version: GedcomVersion) -> (GedcomData, Vec<ConversionNote>) {
+        let data = GedcomBuilder::new().build_from_str(source).unwrap();
+        data.convert_to(version)
+    }
+
+    fn messages(notes: &[ConversionNote]) -> Vec<String> {
+        notes.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_conversion_note_display() {
+        let note = ConversionNote {
+            kind: ConversionKind::Lost,
+            xref: Some("@SUB1@".into()),
+            tag: "SUBN".to_string(),
+            message: "submission record removed".to_string(),
+        };
+        assert_eq!(
+            note.to_string(),
+            "lost SUBN in record @SUB1@: submission record removed"
+        );
+        assert!(note.is_lossy());
+    }
+
+    #[test]
+    fn test_header_converts_to_gedcom_7() {
+        let (data, notes) = convert(
+            "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n2 FORM LINEAGE-LINKED\n1 CHAR ANSEL\n\
+             1 FILE family.ged\n1 SUBN @SUB1@\n0 @SUB1@ SUBN\n0 TRLR",
+            GedcomVersion::V7_0,
+        );
+        let header = data.header.unwrap();
+        assert_eq!(header.version(), Some("7.0"));
+        assert!(header.encoding.is_none());
+        assert!(header.filename.is_none());
+        assert!(header.submission_tag.is_none());
+        assert!(data.submissions.is_empty());
+        assert_eq!(
+            messages(&notes),
+            [
+                "converted FORM: GEDC.FORM LINEAGE-LINKED removed; GEDCOM 7.0 is always lineage-linked",
+                "converted CHAR: CHAR ANSEL removed; GEDCOM 7.0 files are always UTF-8",
+                "lost SUBN: header pointer to submission @SUB1@ removed",
+                "lost FILE: file name family.ged removed; GEDCOM 7.0 headers have no FILE",
+                "lost SUBN in record @SUB1@: submission record removed; GEDCOM 7.0 has no submission records",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ages_convert_both_ways() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 BIRT\n2 AGE STILLBORN\n\
+                      1 DEAT\n2 AGE CHILD\n1 BURI\n2 AGE 3y 2m\n0 TRLR";
+        let (data, _) = convert(source, GedcomVersion::V7_0);
+        let ages: Vec<String> = data.individuals[0]
+            .events
+            .iter()
+            .map(|event| event.age.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(ages, ["0y", "< 8y", "3y 2m"]);
+
+        let (data, notes) = data.convert_to(GedcomVersion::V5_5_1);
+        let ages: Vec<_> = data.individuals[0]
+            .events
+            .iter()
+            .map(|event| event.age.clone().unwrap())
+            .collect();
+        assert_eq!(ages[..2], [Age::Stillborn, Age::Child]);
+        assert!(notes.iter().all(|note| !note.is_lossy()));
+    }
+
+    #[test]
+    fn test_age_weeks_and_phrase_convert_to_gedcom_5() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n1 DEAT\n2 AGE 3w 2d\n\
+                      3 PHRASE About three weeks\n0 TRLR";
+        let (data, notes) = convert(source, GedcomVersion::V5_5_1);
+        assert_eq!(
+            data.individuals[0].events[0]
+                .age
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "23d"
+        );
+        assert_eq!(
+            messages(&notes),
+            [
+                "converted AGE in record @I1@: 3 weeks written as days",
+                "lost PHRASE in record @I1@: age phrase About three weeks removed",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_association_relationship_converts_to_role() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 ASSO @I2@\n2 RELA Witness\n\
+                      1 ASSO @I3@\n2 RELA Godmother\n0 TRLR";
+        let (data, _) = convert(source, GedcomVersion::V7_0);
+        let associations = &data.individuals[0].associations;
+        assert_eq!(associations[0].role, Some(Role::Witness));
+        assert_eq!(associations[0].role_phrase, None);
+        assert_eq!(associations[1].role, Some(Role::Godparent));
+        assert_eq!(associations[1].role_phrase.as_deref(), Some("Godmother"));
+        assert!(associations.iter().all(|a| a.relationship.is_none()));
+
+        let (data, _) = data.convert_to(GedcomVersion::V5_5_1);
+        let relationships: Vec<_> = data.individuals[0]
+            .associations
+            .iter()
+            .map(|a| a.relationship.as_deref().unwrap())
+            .collect();
+        assert_eq!(relationships, ["Witness", "Godmother"]);
+    }
+
+    #[test]
+    fn test_multimedia_format_converts_to_media_type() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @M1@ OBJE\n1 FILE photo.jpg\n2 FORM jpg\n\
+                      3 MEDI photo\n1 FILE scan.xyz\n2 FORM xyz\n3 MEDI slide\n0 TRLR";
+        let (data, notes) = convert(source, GedcomVersion::V7_0);
+        let forms: Vec<_> = data.multimedia[0]
+            .files
+            .iter()
+            .map(|file| file.form.clone().unwrap())
+            .collect();
+        assert_eq!(forms[0].value.as_deref(), Some("image/jpeg"));
+        assert_eq!(forms[0].source_media_type.as_deref(), Some("PHOTO"));
+        assert_eq!(forms[1].value.as_deref(), Some("xyz"));
+        assert_eq!(forms[1].source_media_type.as_deref(), Some("OTHER"));
+        assert_eq!(forms[1].source_media_phrase.as_deref(), Some("slide"));
+        assert!(notes
+            .iter()
+            .any(|note| note.kind == ConversionKind::Unmapped && note.tag == "FORM"));
+
+        let (data, _) = data.convert_to(GedcomVersion::V5_5_1);
+        let form = data.multimedia[0].files[0].form.clone().unwrap();
+        assert_eq!(form.value.as_deref(), Some("jpg"));
+        assert_eq!(form.source_media_type.as_deref(), Some("photo"));
+    }
+
+    #[test]
+    fn test_identifiers_convert_to_external_ids() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 RIN 17\n1 AFN Q1-2\n0 TRLR";
+        let (data, _) = convert(source, GedcomVersion::V7_0);
+        let individual = &data.individuals[0];
+        assert!(individual.automated_record_id.is_none());
+        let types: Vec<_> = individual
+            .external_ids
+            .iter()
+            .map(|exid| exid.type_uri.as_deref().unwrap())
+            .collect();
+        assert_eq!(types, [RIN_TYPE, AFN_TYPE]);
+
+        let (data, notes) = data.convert_to(GedcomVersion::V5_5_1);
+        let individual = &data.individuals[0];
+        assert_eq!(individual.automated_record_id.as_deref(), Some("17"));
+        assert_eq!(individual.ancestral_file_number.as_deref(), Some("Q1-2"));
+        assert!(individual.external_ids.is_empty());
+        assert!(notes.iter().all(|note| !note.is_lossy()));
+    }
+
+    #[test]
+    fn test_extension_tags_are_declared() {
+        let mut data = GedcomBuilder::new()
+            .build_from_str(
+                "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 _SKYPEID annaberg\n\
+                 1 _MILT\n2 _UNIT Fusiliers\n0 TRLR",
+            )
+            .unwrap();
+        data.header.as_mut().unwrap().schema = Some(crate::types::header::schema::Schema {
+            tag_definitions: vec![crate::types::header::schema::TagDefinition::new(
+                "_SKYPEID",
+                "http://xmlns.com/foaf/0.1/skypeID",
+            )],
+            custom_data: Vec::new(),
+        });
+
+        let (converted, notes) = data.convert_to(GedcomVersion::V7_0);
+        assert_eq!(
+            messages(&notes),
+            [
+                "unmapped _MILT: extension tag _MILT has no URI to declare in HEAD.SCHMA",
+                "unmapped _UNIT: extension tag _UNIT has no URI to declare in HEAD.SCHMA",
+            ]
+        );
+
+        let (_, notes) = converted.convert_to(GedcomVersion::V5_5_1);
+        assert_eq!(
+            messages(&notes),
+            ["lost SCHMA: declaration of _SKYPEID as http://xmlns.com/foaf/0.1/skypeID removed; GEDCOM 5.5.1 has no schema"]
+        );
+    }
+
+    #[test]
+    fn test_at_signs_are_escaped_for_the_target_version() {
+        let source =
+            "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NOTE Write to anna@@example.com\n\
+                      1 _MAIL @@home\n0 TRLR";
+        let (data, _) = convert(source, GedcomVersion::V7_0);
+        let individual = &data.individuals[0];
+        assert_eq!(
+            individual.notes[0].value.as_deref(),
+            Some("Write to anna@example.com")
+        );
+        assert_eq!(individual.custom_data[0].value.as_deref(), Some("@@home"));
+
+        let (data, _) = data.convert_to(GedcomVersion::V5_5_1);
+        assert_eq!(
+            data.individuals[0].notes[0].value.as_deref(),
+            Some("Write to anna@@example.com")
+        );
+    }
+
+    #[test]
+    fn test_converting_to_the_same_version_reports_nothing() {
+        let (data, notes) = convert(
+            "0 HEAD\n1 GEDC\n2 VERS 7.0.14\n0 @I1@ INDI\n1 DEAT\n2 AGE 3w\n0 TRLR",
+            GedcomVersion::V7_0,
+        );
+        assert!(notes.is_empty(), "{notes:?}");
+        assert_eq!(data.header.unwrap().version(), Some("7.0.14"));
+    }
+}
@@ -0,0 +1,736 @@
+//! Lossless concrete syntax tree for GEDCOM files.
+//!
+//! The typed model in [`types`](crate::types) normalizes what it reads: line order within a
+//! record, `CONC` split points, spacing, line endings and unknown structures are not kept,
+//! so writing it back with [`GedcomWriter`] rarely reproduces the original file. This
+//! module provides a tree of [`GedcomNode`]s that keeps every line exactly as it appeared,
+//! including its line ending, so an unmodified [`GedcomDocument`] re-serializes byte for
+//! byte.
+//!
+//! Typed records are built from the nodes with [`GedcomNode::to_record`], and
+//! [`GedcomDocument::apply`] maps edits to the typed model back onto the tree: records
+//! whose typed form is unchanged keep their original text, and only changed, added or
+//! removed records are touched. This keeps diffs of edited files small.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ged_io::cst::GedcomDocument;
+//! use ged_io::GedcomWriter;
+//!
+//! let source = "0 HEAD\r\n1 GEDC\r\n2 VERS 5.5.1\r\n0 @I1@ INDI\r\n1 NAME John  /Doe/\r\n\
+//!               0 @I2@ INDI\r\n1 NAME Jane /Doe/\r\n0 TRLR\r\n";
+//! let mut document = GedcomDocument::parse(source)?;
+//! assert_eq!(document.to_string(), source);
+//!
+//! // Edit the typed model, then map the edit back onto the document
+//! let mut data = document.to_gedcom_data()?;
+//! data.individuals[1].primary_name_mut().unwrap().value = Some("Janet /Doe/".to_string());
+//! document.apply(&data, &GedcomWriter::new())?;
+//!
+//! // The untouched individual keeps its double space; the edited one is rewritten
+//! let output = document.to_string();
+//! assert!(output.contains("1 NAME John  /Doe/\r\n"));
+//! assert!(output.contains("1 NAME Janet /Doe/\r\n"));
+//! # Ok::<(), ged_io::GedcomError>(())
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    builder::ParserConfig,
+    stream::{parse_record, GedcomRecord},
+    types::{GedcomData, Xref},
+    GedcomError, GedcomVersion, GedcomWriter,
+};
+
+/// The terminator that ended a line.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// `\r`
+    Cr,
+    /// No terminator; the last line of a file.
+    None,
+}
+
+impl LineEnding {
+    /// Returns the terminator's characters.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr => "\r",
+            LineEnding::None => "",
+        }
+    }
+}
+
+/// A single GEDCOM line together with its original text.
+///
+/// The parsed parts (level, xref, tag and value) are read-only views of the text; use
+/// [`GedcomLine::set_value`] to change the line, which regenerates the text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GedcomLine {
+    level: u8,
+    xref: Option<Xref>,
+    tag: String,
+    value: Option<String>,
+    /// The line as it appeared, without its line ending
+    text: String,
+    line_ending: LineEnding,
+    /// Blank lines preceding this line, kept verbatim
+    leading: String,
+    /// 1-based line number in the parsed input, or 0 for lines created later
+    line_number: u32,
+}
+
+impl GedcomLine {
+    /// Creates a line with canonical spacing and a `\n` line ending.
+    #[must_use]
+    pub fn new(level: u8, xref: Option<&str>, tag: &str, value: Option<&str>) -> Self {
+        let mut line = Self {
+            level,
+            xref: xref.map(Xref::from),
+            tag: tag.to_string(),
+            value: value.map(ToString::to_string),
+            text: String::new(),
+            line_ending: LineEnding::Lf,
+            leading: String::new(),
+            line_number: 0,
+        };
+        line.regenerate_text();
+        line
+    }
+
+    /// Parses the text of a single line, without its line ending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::ParseError` if the text has no level or tag.
+    pub fn parse(text: &str) -> Result<Self, GedcomError> {
+        Self::parse_numbered(text, LineEnding::Lf, 0)
+    }
+
+    fn parse_numbered(
+        text: &str,
+        line_ending: LineEnding,
+        line_number: u32,
+    ) -> Result<Self, GedcomError> {
+        let error = |message: &str| GedcomError::ParseError {
+            line: line_number,
+            message: message.to_string(),
+        };
+
+        let rest = text.trim_start();
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let level = rest[..digits]
+            .parse::<u8>()
+            .map_err(|_| error("Expected a level number"))?;
+        let rest = &rest[digits..];
+        let rest = rest.trim_start_matches([' ', '\t']);
+        if rest.len() == text.trim_start().len() - digits {
+            return Err(error("Expected a delimiter after the level"));
+        }
+
+        let (xref, rest) = match rest.strip_prefix('@') {
+            Some(_) => {
+                let end = rest.find([' ', '\t']).unwrap_or(rest.len());
+                (
+                    Some(Xref::from(&rest[..end])),
+                    rest[end..].trim_start_matches([' ', '\t']),
+                )
+            }
+            None => (None, rest),
+        };
+
+        let end = rest.find([' ', '\t']).unwrap_or(rest.len());
+        let tag = &rest[..end];
+        if tag.is_empty() {
+            return Err(error("Expected a tag"));
+        }
+        let value = rest.get(end + 1..).map(ToString::to_string);
+
+        Ok(Self {
+            level,
+            xref,
+            tag: tag.to_string(),
+            value,
+            text: text.to_string(),
+            line_ending,
+            leading: String::new(),
+            line_number,
+        })
+    }
+
+    /// Returns the line's level.
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns the line's cross-reference identifier, including its `@` signs.
+    #[must_use]
+    pub fn xref(&self) -> Option<&Xref> {
+        self.xref.as_ref()
+    }
+
+    /// Returns the line's tag.
+    #[must_use]
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the line's value, exactly as written after the tag's delimiter.
+    #[must_use]
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the line as it appeared, without its line ending.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the terminator that ended the line.
+    #[must_use]
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Returns the 1-based line number in the parsed input, or `None` for lines created
+    /// after parsing.
+    #[must_use]
+    pub fn line_number(&self) -> Option<u32> {
+        (self.line_number > 0).then_some(self.line_number)
+    }
+
+    /// Replaces the line's value, regenerating its text with canonical spacing.
+    pub fn set_value(&mut self, value: Option<&str>) {
+        self.value = value.map(ToString::to_string);
+        self.regenerate_text();
+    }
+
+    /// Replaces the line's terminator.
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    fn regenerate_text(&mut self) {
+        let mut text = self.level.to_string();
+        if let Some(xref) = &self.xref {
+            text.push(' ');
+            text.push_str(xref);
+        }
+        text.push(' ');
+        text.push_str(&self.tag);
+        if let Some(value) = &self.value {
+            text.push(' ');
+            text.push_str(value);
+        }
+        self.text = text;
+    }
+}
+
+impl fmt::Display for GedcomLine {
+    /// Writes any preceding blank lines, the line's text and its line ending.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.leading,
+            self.text,
+            self.line_ending.as_str()
+        )
+    }
+}
+
+/// A line and the lines nested under it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GedcomNode {
+    /// The node's own line.
+    pub line: GedcomLine,
+    /// The nodes one level deeper, in their original order.
+    pub children: Vec<GedcomNode>,
+}
+
+impl GedcomNode {
+    /// Creates a node without children.
+    #[must_use]
+    pub fn new(line: GedcomLine) -> Self {
+        Self {
+            line,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the first child with the given tag.
+    #[must_use]
+    pub fn child(&self, tag: &str) -> Option<&GedcomNode> {
+        self.children.iter().find(|child| child.line.tag == tag)
+    }
+
+    /// Returns an iterator over the children with the given tag.
+    pub fn children_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a GedcomNode> {
+        self.children
+            .iter()
+            .filter(move |child| child.line.tag == tag)
+    }
+
+    /// Returns an iterator over this node's line and all nested lines, in file order.
+    pub fn lines(&self) -> impl Iterator<Item = &GedcomLine> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(&node.line)
+        })
+    }
+
+    /// Builds the typed record for a level-0 node with the default [`ParserConfig`],
+    /// validating its xref against GEDCOM 5.5.1.
+    ///
+    /// Returns `None` for the trailer. Use [`GedcomNode::to_record_with_config`] to parse
+    /// with a document's configuration and version.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the node's lines do not parse as a record.
+    pub fn to_record(&self) -> Result<Option<GedcomRecord>, GedcomError> {
+        self.to_record_with_config(&ParserConfig::default(), &GedcomVersion::default())
+    }
+
+    /// Builds the typed record for a level-0 node with the given [`ParserConfig`],
+    /// validating its xref against `version` in strict mode.
+    ///
+    /// Returns `None` for the trailer and for records skipped because of
+    /// [`ParserConfig::ignore_unknown_tags`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the node's lines do not parse as a record.
+    pub fn to_record_with_config(
+        &self,
+        config: &ParserConfig,
+        version: &GedcomVersion,
+    ) -> Result<Option<GedcomRecord>, GedcomError> {
+        if self.line.tag == "TRLR" {
+            return Ok(None);
+        }
+        let mut text = String::new();
+        for line in self.lines() {
+            text.push_str(&line.text);
+            text.push('\n');
+        }
+        parse_record(&text, self.line.line_number.max(1), 0, config, version)
+    }
+
+    fn last_line_mut(&mut self) -> &mut GedcomLine {
+        match self.children.last_mut() {
+            Some(child) => child.last_line_mut(),
+            None => &mut self.line,
+        }
+    }
+}
+
+impl fmt::Display for GedcomNode {
+    /// Writes the node's lines exactly as they appeared.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A whole GEDCOM file as a lossless tree of level-0 nodes.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GedcomDocument {
+    /// Whether the input started with a byte order mark
+    bom: bool,
+    /// The level-0 nodes, including the trailer
+    records: Vec<GedcomNode>,
+    /// Blank lines after the last line, kept verbatim
+    trailing: String,
+    /// The configuration records are parsed with when building the typed model
+    config: ParserConfig,
+}
+
+impl GedcomDocument {
+    /// Parses GEDCOM text into a document.
+    ///
+    /// Blank lines are kept, and lines nest under the closest preceding line with a lower
+    /// level.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::ParseError` if a non-blank line has no level or tag.
+    pub fn parse(content: &str) -> Result<Self, GedcomError> {
+        Self::parse_with_config(content, ParserConfig::default())
+    }
+
+    /// Parses GEDCOM text into a document whose records are turned into typed records
+    /// with the given [`ParserConfig`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::ParseError` if a non-blank line has no level or tag.
+    pub fn parse_with_config(content: &str, config: ParserConfig) -> Result<Self, GedcomError> {
+        let (bom, content) = match content.strip_prefix('\u{FEFF}') {
+            Some(rest) => (true, rest),
+            None => (false, content),
+        };
+
+        let mut lines = Vec::new();
+        let mut leading = String::new();
+        for (index, (text, line_ending)) in split_lines(content).enumerate() {
+            if text.trim().is_empty() {
+                leading.push_str(text);
+                leading.push_str(line_ending.as_str());
+                continue;
+            }
+            let line_number = u32::try_from(index + 1).unwrap_or(u32::MAX);
+            let mut line = GedcomLine::parse_numbered(text, line_ending, line_number)?;
+            line.leading = std::mem::take(&mut leading);
+            lines.push(line);
+        }
+
+        Ok(Self {
+            bom,
+            records: build_tree(lines),
+            trailing: leading,
+            config,
+        })
+    }
+
+    /// Returns the configuration records are parsed with.
+    #[must_use]
+    pub fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    /// Returns the GEDCOM version declared in the header's `GEDC.VERS`, or the default
+    /// version if there is none.
+    #[must_use]
+    pub fn version(&self) -> GedcomVersion {
+        self.records
+            .iter()
+            .find(|node| node.line.tag == "HEAD")
+            .and_then(|header| header.child("GEDC")?.child("VERS")?.line.value())
+            .map_or_else(GedcomVersion::default, GedcomVersion::from_version_str)
+    }
+
+    /// Returns the level-0 nodes, including the trailer.
+    #[must_use]
+    pub fn records(&self) -> &[GedcomNode] {
+        &self.records
+    }
+
+    /// Returns the level-0 nodes for editing.
+    pub fn records_mut(&mut self) -> &mut Vec<GedcomNode> {
+        &mut self.records
+    }
+
+    /// Returns the level-0 node with the given xref.
+    #[must_use]
+    pub fn find_record(&self, xref: &str) -> Option<&GedcomNode> {
+        self.records
+            .iter()
+            .find(|node| node.line.xref.as_ref().is_some_and(|line| line == xref))
+    }
+
+    /// Returns the line ending of the first line, used for lines added by
+    /// [`GedcomDocument::apply`].
+    #[must_use]
+    pub fn line_ending(&self) -> LineEnding {
+        match self.records.first().map(|node| node.line.line_ending) {
+            Some(LineEnding::None) | None => LineEnding::Lf,
+            Some(ending) => ending,
+        }
+    }
+
+    /// Builds the typed model from the document's records, parsed with the document's
+    /// configuration and GEDCOM version.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if a record does not parse.
+    pub fn to_gedcom_data(&self) -> Result<GedcomData, GedcomError> {
+        let version = self.version();
+        let mut records = Vec::with_capacity(self.records.len());
+        for node in &self.records {
+            records.extend(node.to_record_with_config(&self.config, &version)?);
+        }
+        Ok(records.into_iter().collect())
+    }
+
+    /// Maps the typed model back onto the document.
+    ///
+    /// Each record is matched with a record in `data` by xref (the header and
+    /// user-defined records without xrefs are matched by position and equality). Records
+    /// whose typed form is unchanged keep their original lines byte for byte; changed
+    /// records are rewritten in place with `writer`, records missing from `data` are
+    /// removed, and new records are inserted before the trailer. Records that do not parse
+    /// are kept as they are.
+    ///
+    /// `data` is expected to come from [`GedcomDocument::to_gedcom_data`], so that
+    /// unchanged records compare equal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if serializing a changed record fails.
+    pub fn apply(&mut self, data: &GedcomData, writer: &GedcomWriter) -> Result<(), GedcomError> {
+        let writer = writer.clone().line_ending(self.line_ending().as_str());
+        let version = self.version();
+        let mut pending: Vec<Option<GedcomRecord>> =
+            data.to_records().into_iter().map(Some).collect();
+        let by_xref: HashMap<String, usize> = pending
+            .iter()
+            .enumerate()
+            .filter_map(|(i, record)| Some((record.as_ref()?.xref()?.to_string(), i)))
+            .collect();
+
+        let mut records = Vec::with_capacity(pending.len() + 1);
+        let mut trailer = None;
+        for node in std::mem::take(&mut self.records) {
+            if node.line.tag == "TRLR" && trailer.is_none() {
+                trailer = Some(node);
+                continue;
+            }
+            let Ok(Some(current)) = node.to_record_with_config(&self.config, &version) else {
+                records.push(node);
+                continue;
+            };
+
+            let matched = match current.xref() {
+                Some(xref) => by_xref.get(xref).copied().filter(|&i| pending[i].is_some()),
+                None => pending.iter().position(|candidate| {
+                    candidate.as_ref().is_some_and(|candidate| {
+                        candidate.xref().is_none()
+                            && match (&current, candidate) {
+                                (GedcomRecord::Header(_), GedcomRecord::Header(_)) => true,
+                                (GedcomRecord::CustomData(_), GedcomRecord::CustomData(_)) => {
+                                    *candidate == current
+                                }
+                                _ => false,
+                            }
+                    })
+                }),
+            };
+            let Some(replacement) = matched.and_then(|i| pending[i].take()) else {
+                // The record was removed from the typed model
+                continue;
+            };
+
+            if replacement == current {
+                records.push(node);
+            } else {
+                let mut nodes = serialize_record(&writer, &replacement)?;
+                if let Some(first) = nodes.first_mut() {
+                    first.line.leading = node.line.leading;
+                }
+                records.extend(nodes);
+            }
+        }
+
+        let added = pending.into_iter().flatten().collect::<Vec<_>>();
+        if !added.is_empty() {
+            if let Some(last) = records.last_mut() {
+                let line = last.last_line_mut();
+                if line.line_ending == LineEnding::None {
+                    line.line_ending = self.line_ending();
+                }
+            }
+            for record in &added {
+                records.extend(serialize_record(&writer, record)?);
+            }
+        }
+        records.extend(trailer);
+        self.records = records;
+        Ok(())
+    }
+}
+
+impl fmt::Display for GedcomDocument {
+    /// Writes the document exactly as it was parsed, apart from any edits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bom {
+            write!(f, "\u{FEFF}")?;
+        }
+        for node in &self.records {
+            write!(f, "{node}")?;
+        }
+        write!(f, "{}", self.trailing)
+    }
+}
+
+/// Writes a typed record and parses the output back into nodes.
+fn serialize_record(
+    writer: &GedcomWriter,
+    record: &GedcomRecord,
+) -> Result<Vec<GedcomNode>, GedcomError> {
+    let mut text = String::new();
+    writer.write_record(&mut text, record)?;
+    Ok(GedcomDocument::parse(&text)?.records)
+}
+
+/// Splits text into lines and the terminators that ended them.
+fn split_lines(content: &str) -> impl Iterator<Item = (&str, LineEnding)> {
+    let mut rest = content;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let (line, ending, length) = match rest.find(['\r', '\n']) {
+            Some(end) if rest[end..].starts_with("\r\n") => {
+                (&rest[..end], LineEnding::CrLf, end + 2)
+            }
+            Some(end) if rest[end..].starts_with('\r') => (&rest[..end], LineEnding::Cr, end + 1),
+            Some(end) => (&rest[..end], LineEnding::Lf, end + 1),
+            None => (rest, LineEnding::None, rest.len()),
+        };
+        rest = &rest[length..];
+        Some((line, ending))
+    })
+}
+
+/// Nests each line under the closest preceding line with a lower level.
+fn build_tree(lines: Vec<GedcomLine>) -> Vec<GedcomNode> {
+    fn attach(done: GedcomNode, stack: &mut [GedcomNode], roots: &mut Vec<GedcomNode>) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(done),
+            None => roots.push(done),
+        }
+    }
+
+    let mut roots = Vec::new();
+    let mut stack: Vec<GedcomNode> = Vec::new();
+    for line in lines {
+        while let Some(top) = stack.pop() {
+            if top.line.level < line.level {
+                stack.push(top);
+                break;
+            }
+            attach(top, &mut stack, &mut roots);
+        }
+        stack.push(GedcomNode::new(line));
+    }
+    while let Some(top) = stack.pop() {
+        attach(top, &mut stack, &mut roots);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "0 HEAD\r\n1 GEDC\r\n2 VERS 5.5.1\r\n0 @I1@ INDI\r\n1 NAME John  /Doe/\r\n\r\n1 NOTE A long note that was\r\n2 CONC  split by hand\r\n0 @I2@ INDI\r\n1 NAME Jane /Doe/\r\n0 @F1@ FAM\r\n1 HUSB @I1@\r\n1 WIFE @I2@\r\n0 TRLR";
+
+    #[test]
+    fn test_parse_line_parts() {
+        let line = GedcomLine::parse("0 @I1@ INDI").unwrap();
+        assert_eq!(line.level(), 0);
+        assert_eq!(line.xref().map(Xref::as_str), Some("@I1@"));
+        assert_eq!(line.tag(), "INDI");
+        assert_eq!(line.value(), None);
+
+        let line = GedcomLine::parse("2 CONC  split").unwrap();
+        assert_eq!(line.value(), Some(" split"));
+
+        assert!(GedcomLine::parse("NAME John").is_err());
+        assert!(GedcomLine::parse("1").is_err());
+    }
+
+    #[test]
+    fn test_round_trip_is_byte_for_byte() {
+        let document = GedcomDocument::parse(SOURCE).unwrap();
+        assert_eq!(document.records().len(), 5);
+        assert_eq!(document.line_ending(), LineEnding::CrLf);
+        assert_eq!(document.to_string(), SOURCE);
+
+        let with_bom = "\u{FEFF}0 HEAD\n\n0 TRLR\n\n";
+        assert_eq!(
+            GedcomDocument::parse(with_bom).unwrap().to_string(),
+            with_bom
+        );
+    }
+
+    #[test]
+    fn test_tree_structure() {
+        let document = GedcomDocument::parse(SOURCE).unwrap();
+        let individual = document.find_record("@I1@").unwrap();
+        let note = individual.child("NOTE").unwrap();
+        assert_eq!(note.children[0].line.tag(), "CONC");
+        assert_eq!(note.children[0].line.line_number(), Some(8));
+    }
+
+    #[test]
+    fn test_apply_unchanged_data_keeps_text() {
+        let mut document = GedcomDocument::parse(SOURCE).unwrap();
+        let data = document.to_gedcom_data().unwrap();
+        assert_eq!(data.individuals.len(), 2);
+
+        document.apply(&data, &GedcomWriter::new()).unwrap();
+        assert_eq!(document.to_string(), SOURCE);
+    }
+
+    #[test]
+    fn test_apply_rewrites_only_changed_records() {
+        let mut document = GedcomDocument::parse(SOURCE).unwrap();
+        let mut data = document.to_gedcom_data().unwrap();
+        data.individuals[1].primary_name_mut().unwrap().value = Some("Janet /Doe/".to_string());
+        data.families.clear();
+
+        document.apply(&data, &GedcomWriter::new()).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "0 HEAD\r\n1 GEDC\r\n2 VERS 5.5.1\r\n0 @I1@ INDI\r\n1 NAME John  /Doe/\r\n\r\n1 NOTE A long note that was\r\n2 CONC  split by hand\r\n0 @I2@ INDI\r\n1 NAME Janet /Doe/\r\n2 SURN Doe\r\n0 TRLR"
+        );
+    }
+
+    #[test]
+    fn test_apply_inserts_new_records_before_trailer() {
+        let mut document = GedcomDocument::parse("0 HEAD\n0 @I1@ INDI\n0 TRLR\n").unwrap();
+        let mut data = document.to_gedcom_data().unwrap();
+        let mut individual = data.individuals[0].clone();
+        individual.xref = Some("@I2@".into());
+        data.individuals.push(individual);
+
+        document.apply(&data, &GedcomWriter::new()).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "0 HEAD\n0 @I1@ INDI\n0 @I2@ INDI\n0 TRLR\n"
+        );
+    }
+
+    #[test]
+    fn test_to_gedcom_data_uses_config_and_header_version() {
+        let strict = ParserConfig {
+            strict_mode: true,
+            ..ParserConfig::default()
+        };
+        let v7 = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @i1@ INDI\n0 TRLR\n";
+        let document = GedcomDocument::parse_with_config(v7, strict.clone()).unwrap();
+        assert!(document.version().is_v7());
+        assert!(matches!(
+            document.to_gedcom_data(),
+            Err(GedcomError::InvalidValueFormat { line: 4, .. })
+        ));
+        assert!(GedcomDocument::parse(v7).unwrap().to_gedcom_data().is_ok());
+
+        let v5 = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @i1@ INDI\n0 TRLR\n";
+        let document = GedcomDocument::parse_with_config(v5, strict).unwrap();
+        assert_eq!(document.to_gedcom_data().unwrap().individuals.len(), 1);
+    }
+}
@@ -102,10 +102,12 @@ impl fmt::Debug for IndividualDebug<'_> {
             debug.field("xref", xref);
         }
 
-        if let Some(ref name) = self.0.name {
-            if let Some(ref value) = name.value {
-                debug.field("name", value);
-            }
+        if let Some(value) = self.0.primary_name().and_then(|name| name.value.as_ref()) {
+            debug.field("name", value);
+        }
+
+        if self.0.names.len() > 1 {
+            debug.field("names", &format!("[{} names]", self.0.names.len()));
         }
 
         if let Some(ref sex) = self.0.sex {
@@ -142,11 +144,11 @@ impl fmt::Debug for FamilyDebug<'_> {
             debug.field("xref", xref);
         }
 
-        if let Some(ref ind1) = self.0.individual1 {
+        if let Some(ind1) = self.0.individual1() {
             debug.field("individual1", ind1);
         }
 
-        if let Some(ref ind2) = self.0.individual2 {
+        if let Some(ind2) = self.0.individual2() {
             debug.field("individual2", ind2);
         }
 
@@ -227,10 +229,12 @@ impl fmt::Debug for MultimediaDebug<'_> {
             debug.field("title", title);
         }
 
-        if let Some(ref file) = self.0.file {
-            if let Some(ref value) = file.value {
-                debug.field("file", value);
-            }
+        if let Some(value) = self.0.primary_file().and_then(|file| file.value.as_ref()) {
+            debug.field("file", value);
+        }
+
+        if self.0.files.len() > 1 {
+            debug.field("files", &self.0.files.len());
         }
 
         if let Some(ref form) = self.0.form {
@@ -361,13 +365,17 @@ impl fmt::Debug for NoteDebug<'_> {
         if let Some(ref value) = self.0.value {
             // Truncate long notes in debug output
             const MAX_LEN: usize = 50;
-            if value.len() > MAX_LEN {
-                debug.field("value", &format!("{}...", &value[..MAX_LEN]));
+            if let Some((end, _)) = value.char_indices().nth(MAX_LEN) {
+                debug.field("value", &format!("{}...", &value[..end]));
             } else {
                 debug.field("value", value);
             }
         }
 
+        if let Some(ref xref) = self.0.shared_note {
+            debug.field("shared_note", xref);
+        }
+
         if let Some(ref mime) = self.0.mime {
             debug.field("mime", mime);
         }
@@ -580,6 +588,8 @@ mod tests {
             translation: None,
             citation: None,
             language: None,
+            shared_note: None,
+            custom_data: Vec::new(),
         };
 
         let debug_output = format!("{:?}", note.debug());
@@ -596,7 +606,7 @@ mod tests {
             surname: Some("Doe".to_string()),
             prefix: None,
             surname_prefix: None,
-            note: None,
+            notes: Vec::new(),
             suffix: Some("Jr.".to_string()),
             nickname: None,
             source: Vec::new(),
@@ -613,4 +623,15 @@ mod tests {
         assert!(debug_output.contains("surname"));
         assert!(debug_output.contains("Jr."));
     }
+
+    #[test]
+    fn test_note_debug_truncates_multibyte_text() {
+        let note = Note {
+            value: Some("家族の歴史".repeat(20)),
+            ..Default::default()
+        };
+
+        let debug_output = format!("{:?}", NoteDebug(&note));
+        assert!(debug_output.contains(&format!("{}...", "家族の歴史".repeat(10))));
+    }
 }
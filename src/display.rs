@@ -113,12 +113,15 @@ impl fmt::Display for Individual {
             write!(f, "{xref} ")?;
         }
 
-        // Display name
-        if let Some(ref name) = self.name {
+        // Display the primary name, followed by any other names
+        if let Some(name) = self.primary_name() {
             write!(f, "{name}")?;
         } else {
             write!(f, "(Unknown Name)")?;
         }
+        for name in self.names.iter().skip(1) {
+            write!(f, " aka {name}")?;
+        }
 
         // Display sex if available
         if let Some(ref sex) = self.sex {
@@ -203,10 +206,10 @@ impl fmt::Display for Family {
 
         let mut members = Vec::new();
 
-        if let Some(ref ind1) = self.individual1 {
+        if let Some(ind1) = self.individual1() {
             members.push(format!("Partner 1: {ind1}"));
         }
-        if let Some(ref ind2) = self.individual2 {
+        if let Some(ind2) = self.individual2() {
             members.push(format!("Partner 2: {ind2}"));
         }
 
@@ -315,7 +318,7 @@ impl fmt::Display for Multimedia {
 
         if let Some(ref title) = self.title {
             write!(f, "\"{title}\"")?;
-        } else if let Some(ref file) = self.file {
+        } else if let Some(file) = self.primary_file() {
             if let Some(ref file_value) = file.value {
                 write!(f, "{file_value}")?;
             } else {
@@ -372,11 +375,13 @@ impl fmt::Display for Note {
         if let Some(ref value) = self.value {
             // Truncate long notes for display
             const MAX_LEN: usize = 100;
-            if value.len() > MAX_LEN {
-                write!(f, "{}...", &value[..MAX_LEN])?;
+            if let Some((end, _)) = value.char_indices().nth(MAX_LEN) {
+                write!(f, "{}...", &value[..end])?;
             } else {
                 write!(f, "{value}")?;
             }
+        } else if let Some(ref xref) = self.shared_note {
+            write!(f, "(Shared Note {xref})")?;
         } else {
             write!(f, "(Empty Note)")?;
         }
@@ -433,6 +438,25 @@ mod tests {
         assert!(display.contains("b. 15 MAR 1985"));
     }
 
+    #[test]
+    fn test_individual_display_lists_other_names() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 5.5\n\
+            0 @I1@ INDI\n\
+            1 NAME Jane /Smith/\n\
+            1 NAME Jane /Miller/\n\
+            2 TYPE married\n\
+            0 TRLR";
+
+        let mut gedcom = Gedcom::new(sample.chars()).unwrap();
+        let data = gedcom.parse_data().unwrap();
+
+        let display = format!("{}", data.individuals[0]);
+        assert_eq!(display, "@I1@ Jane Smith aka Jane Miller");
+    }
+
     #[test]
     fn test_family_display() {
         let sample = "\
@@ -551,7 +575,7 @@ mod tests {
         let mut gedcom = Gedcom::new(sample.chars()).unwrap();
         let data = gedcom.parse_data().unwrap();
 
-        let name = data.individuals[0].name.as_ref().unwrap();
+        let name = data.individuals[0].primary_name().unwrap();
         let display = format!("{name}");
         assert!(display.contains("Robert"));
         assert!(display.contains("Johnson"));
@@ -608,10 +632,24 @@ mod tests {
             translation: None,
             citation: None,
             language: None,
+            shared_note: None,
+            custom_data: Vec::new(),
         };
 
         let display = format!("{note}");
         assert!(display.ends_with("..."));
         assert!(display.len() < 110); // 100 chars + "..."
     }
+
+    #[test]
+    fn test_note_display_truncates_multibyte_text() {
+        let note = Note {
+            value: Some("Семейная история ".repeat(10)),
+            ..Note::default()
+        };
+
+        let display = format!("{note}");
+        assert!(display.ends_with("..."));
+        assert_eq!(display.chars().count(), 103);
+    }
 }
@@ -23,7 +23,9 @@
 //! ```
 
 use crate::GedcomError;
-use encoding_rs::{Encoding, ISO_8859_15, UTF_16BE, UTF_16LE, WINDOWS_1252};
+use encoding_rs::{
+    DecoderResult, EncoderResult, Encoding, ISO_8859_15, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252,
+};
 
 /// Represents the detected or declared encoding of a GEDCOM file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,6 +63,87 @@ impl std::fmt::Display for GedcomEncoding {
     }
 }
 
+impl GedcomEncoding {
+    /// Returns the value that declares this encoding on the header `CHAR` line.
+    ///
+    /// Both UTF-16 byte orders are declared as `UNICODE`; the byte order mark tells them
+    /// apart. `Unknown` is declared as `UTF-8`.
+    #[must_use]
+    pub fn char_value(self) -> &'static str {
+        match self {
+            GedcomEncoding::Utf8 | GedcomEncoding::Unknown => "UTF-8",
+            GedcomEncoding::Utf16Le | GedcomEncoding::Utf16Be => "UNICODE",
+            GedcomEncoding::Iso8859_1 => "ISO-8859-1",
+            GedcomEncoding::Iso8859_15 => "ISO-8859-15",
+            GedcomEncoding::Ascii => "ASCII",
+            GedcomEncoding::Ansel => "ANSEL",
+        }
+    }
+
+    /// Returns the byte order mark for this encoding, or an empty slice for encodings
+    /// that have none.
+    #[must_use]
+    pub fn byte_order_mark(self) -> &'static [u8] {
+        match self {
+            GedcomEncoding::Utf8 => &[0xEF, 0xBB, 0xBF],
+            GedcomEncoding::Utf16Le => &[0xFF, 0xFE],
+            GedcomEncoding::Utf16Be => &[0xFE, 0xFF],
+            _ => &[],
+        }
+    }
+
+    /// Returns how much of a line's length limit `ch` uses in this encoding.
+    ///
+    /// GEDCOM 5.5.1 limits lines to 255 characters, which are UTF-16 code units for
+    /// `UNICODE` and bytes for the single-byte encodings. ANSEL writes a precomposed letter
+    /// as a diacritic byte followed by its base letter, so it counts twice. UTF-8 text is
+    /// measured in characters.
+    #[must_use]
+    pub fn line_units(self, ch: char) -> usize {
+        match self {
+            GedcomEncoding::Utf16Le | GedcomEncoding::Utf16Be => ch.len_utf16(),
+            GedcomEncoding::Ansel if !ch.is_ascii() => {
+                unicode_precomposed_to_ansel(ch).map_or(1, |bytes| bytes.len())
+            }
+            _ => 1,
+        }
+    }
+}
+
+/// What to do with a character the target encoding cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnmappableCharacters {
+    /// Fail with `GedcomError::EncodingError` naming the character.
+    #[default]
+    Error,
+    /// Write the given character in its place, such as `?`.
+    Replace(char),
+}
+
+impl UnmappableCharacters {
+    /// Handles `ch`, which `encoding` cannot represent, appending any replacement to
+    /// `output`.
+    fn handle(
+        self,
+        ch: char,
+        encoding: GedcomEncoding,
+        output: &mut Vec<u8>,
+    ) -> Result<(), GedcomError> {
+        match self {
+            UnmappableCharacters::Replace(replacement) if replacement != ch => encode_into(
+                replacement.encode_utf8(&mut [0; 4]),
+                encoding,
+                UnmappableCharacters::Error,
+                output,
+            ),
+            _ => Err(GedcomError::EncodingError(format!(
+                "Cannot encode {ch:?} (U+{:04X}) as {encoding}",
+                u32::from(ch)
+            ))),
+        }
+    }
+}
+
 /// Detects the encoding of GEDCOM bytes by examining:
 /// 1. Byte Order Mark (BOM) for UTF-16/UTF-8
 /// 2. The CHAR tag value in the header
@@ -97,6 +180,25 @@ pub fn detect_encoding(bytes: &[u8]) -> GedcomEncoding {
     detect_encoding_by_content(bytes)
 }
 
+/// Detects the encoding a GEDCOM file declares for itself, from its BOM or its header
+/// `CHAR` line.
+///
+/// Unlike [`detect_encoding`], this does not fall back to content heuristics and returns
+/// `None` when the file makes no declaration.
+#[must_use]
+pub fn detect_declared_encoding(bytes: &[u8]) -> Option<GedcomEncoding> {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        return Some(GedcomEncoding::Utf8);
+    }
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        return Some(GedcomEncoding::Utf16Le);
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        return Some(GedcomEncoding::Utf16Be);
+    }
+    detect_encoding_from_char_tag(bytes)
+}
+
 /// Detects encoding by looking for the CHAR tag in the GEDCOM header.
 fn detect_encoding_from_char_tag(bytes: &[u8]) -> Option<GedcomEncoding> {
     // First, try to decode as UTF-8 to search for CHAR tag
@@ -125,7 +227,7 @@ fn detect_encoding_from_char_tag(bytes: &[u8]) -> Option<GedcomEncoding> {
 }
 
 /// Parses an encoding value string to `GedcomEncoding`.
-fn parse_encoding_value(value: &str) -> Option<GedcomEncoding> {
+pub(crate) fn parse_encoding_value(value: &str) -> Option<GedcomEncoding> {
     match value.to_uppercase().as_str() {
         "UTF-8" | "UTF8" => Some(GedcomEncoding::Utf8),
         // UTF-16 variants: generic, LE-specific all map to UTF-16 LE (default)
@@ -386,97 +488,223 @@ fn ansel_special_char(byte: u8) -> Option<char> {
 /// - Combining diacritical marks in 0xE0-0xFE range (these precede the base character)
 fn decode_ansel(bytes: &[u8]) -> String {
     let mut result = String::with_capacity(bytes.len());
-    let mut pending_diacritics: Vec<char> = Vec::new();
-    let mut i = 0;
-
-    while i < bytes.len() {
-        let byte = bytes[i];
-
-        // Check if this is a combining diacritical mark
-        if let Some(combining) = ansel_combining_mark(byte) {
-            // In ANSEL, diacritics precede the base character
-            // Collect them and apply after the base character
-            pending_diacritics.push(combining);
-            i += 1;
-            continue;
-        }
+    AnselDecoder::default().decode(bytes, &mut result, true);
+    result
+}
 
-        // Get the character for this byte
-        let ch = if byte < 0x80 {
-            // ASCII range
-            byte as char
-        } else if let Some(special) = ansel_special_char(byte) {
-            special
-        } else {
-            // Unknown byte - use replacement character or pass through
-            // For compatibility, map high bytes to Latin-1 equivalent
-            char::from_u32(u32::from(byte)).unwrap_or('\u{FFFD}')
-        };
+/// Incremental ANSEL decoder that carries pending diacritics across chunks.
+#[derive(Debug, Default)]
+struct AnselDecoder {
+    pending_diacritics: Vec<char>,
+}
 
-        // Output the base character
-        result.push(ch);
+impl AnselDecoder {
+    fn decode(&mut self, bytes: &[u8], result: &mut String, last: bool) {
+        for &byte in bytes {
+            // Check if this is a combining diacritical mark
+            if let Some(combining) = ansel_combining_mark(byte) {
+                // In ANSEL, diacritics precede the base character
+                // Collect them and apply after the base character
+                self.pending_diacritics.push(combining);
+                continue;
+            }
+
+            // Get the character for this byte
+            let ch = if byte < 0x80 {
+                // ASCII range
+                byte as char
+            } else if let Some(special) = ansel_special_char(byte) {
+                special
+            } else {
+                // Unknown byte - use replacement character or pass through
+                // For compatibility, map high bytes to Latin-1 equivalent
+                char::from_u32(u32::from(byte)).unwrap_or('\u{FFFD}')
+            };
 
-        // Apply any pending diacritics (in reverse order for proper stacking)
-        for diacritic in pending_diacritics.drain(..) {
-            result.push(diacritic);
+            // Output the base character
+            result.push(ch);
+
+            // Apply any pending diacritics (in reverse order for proper stacking)
+            result.extend(self.pending_diacritics.drain(..));
         }
 
-        i += 1;
+        // If there are leftover diacritics with no base character, append them anyway
+        if last {
+            result.extend(self.pending_diacritics.drain(..));
+        }
     }
+}
 
-    // If there are leftover diacritics with no base character, append them anyway
-    for diacritic in pending_diacritics {
-        result.push(diacritic);
+/// Incrementally decodes GEDCOM bytes that arrive in chunks.
+///
+/// This is the streaming counterpart of [`decode_with_encoding`]: multi-byte sequences and
+/// ANSEL diacritics split across chunk boundaries are carried over to the next call. A
+/// byte order mark matching the encoding is removed.
+///
+/// # Example
+///
+/// ```rust
+/// use ged_io::encoding::{GedcomDecoder, GedcomEncoding};
+///
+/// let mut decoder = GedcomDecoder::new(GedcomEncoding::Ansel);
+/// let mut text = String::new();
+/// decoder.decode_to_string(b"1 NAME Jos\xE2", &mut text, false).unwrap();
+/// decoder.decode_to_string(b"e\n", &mut text, true).unwrap();
+/// assert_eq!(text, "1 NAME Jose\u{301}\n");
+/// ```
+pub struct GedcomDecoder {
+    encoding: GedcomEncoding,
+    inner: DecoderInner,
+}
+
+enum DecoderInner {
+    Standard(encoding_rs::Decoder),
+    Ansel(AnselDecoder),
+}
+
+impl GedcomDecoder {
+    /// Creates a decoder for the given encoding.
+    ///
+    /// ASCII and unknown encodings are decoded as UTF-8, and ISO-8859-1 as its
+    /// Windows-1252 superset, matching [`decode_with_encoding`].
+    #[must_use]
+    pub fn new(encoding: GedcomEncoding) -> Self {
+        let standard = |encoding: &'static Encoding| {
+            DecoderInner::Standard(encoding.new_decoder_with_bom_removal())
+        };
+        let inner = match encoding {
+            GedcomEncoding::Utf8 | GedcomEncoding::Ascii | GedcomEncoding::Unknown => {
+                standard(UTF_8)
+            }
+            GedcomEncoding::Utf16Le => standard(UTF_16LE),
+            GedcomEncoding::Utf16Be => standard(UTF_16BE),
+            GedcomEncoding::Iso8859_1 => standard(WINDOWS_1252),
+            GedcomEncoding::Iso8859_15 => standard(ISO_8859_15),
+            GedcomEncoding::Ansel => DecoderInner::Ansel(AnselDecoder::default()),
+        };
+        Self { encoding, inner }
     }
 
-    result
+    /// Returns the encoding this decoder reads.
+    #[must_use]
+    pub fn encoding(&self) -> GedcomEncoding {
+        self.encoding
+    }
+
+    /// Decodes a chunk of bytes, appending the text to `output`.
+    ///
+    /// Set `last` on the final chunk so that any incomplete trailing sequence is
+    /// reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::EncodingError` if the bytes are malformed in this encoding.
+    pub fn decode_to_string(
+        &mut self,
+        bytes: &[u8],
+        output: &mut String,
+        last: bool,
+    ) -> Result<(), GedcomError> {
+        let decoder = match &mut self.inner {
+            DecoderInner::Standard(decoder) => decoder,
+            DecoderInner::Ansel(decoder) => {
+                decoder.decode(bytes, output, last);
+                return Ok(());
+            }
+        };
+
+        let mut remaining = bytes;
+        loop {
+            let needed = decoder
+                .max_utf8_buffer_length_without_replacement(remaining.len())
+                .unwrap_or(remaining.len());
+            output.reserve(needed);
+            let (result, read) =
+                decoder.decode_to_string_without_replacement(remaining, output, last);
+            remaining = &remaining[read..];
+            match result {
+                DecoderResult::InputEmpty => return Ok(()),
+                DecoderResult::OutputFull => {}
+                DecoderResult::Malformed(_, _) => {
+                    return Err(GedcomError::EncodingError(format!(
+                        "Invalid {} sequence",
+                        self.encoding
+                    )));
+                }
+            }
+        }
+    }
 }
 
-/// Encodes a UTF-8 string to ANSEL bytes.
+impl std::fmt::Debug for GedcomDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GedcomDecoder")
+            .field("encoding", &self.encoding)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Encodes a UTF-8 string to ANSEL bytes, appending them to `output`.
 ///
-/// This performs a best-effort conversion. Characters that cannot be represented
-/// in ANSEL will be replaced with '?' or their closest ASCII equivalent.
-fn encode_ansel(content: &str) -> Vec<u8> {
-    let mut result = Vec::with_capacity(content.len());
+/// Combining marks are moved in front of their base character, and precomposed accented
+/// letters are decomposed into a diacritic and a base letter.
+fn encode_ansel(
+    content: &str,
+    unmappable: UnmappableCharacters,
+    output: &mut Vec<u8>,
+) -> Result<(), GedcomError> {
     let mut chars = content.chars().peekable();
 
     while let Some(ch) = chars.next() {
         // Check if this is a base character followed by combining marks
         let base_byte = unicode_to_ansel_base(ch);
 
-        // Collect any following combining marks
-        let mut combining_marks = Vec::new();
+        // In ANSEL, diacritics precede the base character
         while let Some(&next_ch) = chars.peek() {
             if let Some(ansel_diacritic) = unicode_combining_to_ansel(next_ch) {
-                combining_marks.push(ansel_diacritic);
+                output.push(ansel_diacritic);
                 chars.next();
             } else {
                 break;
             }
         }
 
-        // In ANSEL, diacritics precede the base character
-        for diacritic in combining_marks {
-            result.push(diacritic);
-        }
-
         // Output the base character
         if let Some(byte) = base_byte {
-            result.push(byte);
+            output.push(byte);
+        } else if let Some(bytes) = unicode_precomposed_to_ansel(ch) {
+            output.extend_from_slice(&bytes);
         } else {
-            // Try to find precomposed character mapping
-            if let Some(bytes) = unicode_precomposed_to_ansel(ch) {
-                result.extend_from_slice(&bytes);
-            } else if ch.is_ascii() {
-                result.push(ch as u8);
-            } else {
-                // Cannot encode - use '?'
-                result.push(b'?');
-            }
+            unmappable.handle(ch, GedcomEncoding::Ansel, output)?;
         }
     }
 
-    result
+    Ok(())
+}
+
+/// Encodes a UTF-8 string with a single-byte `encoding_rs` encoding, appending the bytes to
+/// `output`.
+fn encode_single_byte(
+    content: &str,
+    target: &'static Encoding,
+    encoding: GedcomEncoding,
+    unmappable: UnmappableCharacters,
+    output: &mut Vec<u8>,
+) -> Result<(), GedcomError> {
+    let mut encoder = target.new_encoder();
+    let mut buffer = [0; 1024];
+    let mut rest = content;
+    loop {
+        let (result, read, written) =
+            encoder.encode_from_utf8_without_replacement(rest, &mut buffer, true);
+        output.extend_from_slice(&buffer[..written]);
+        rest = &rest[read..];
+        match result {
+            EncoderResult::InputEmpty => return Ok(()),
+            EncoderResult::OutputFull => {}
+            EncoderResult::Unmappable(ch) => unmappable.handle(ch, encoding, output)?,
+        }
+    }
 }
 
 /// Maps a Unicode base character to its ANSEL byte (if it's a special ANSEL character).
@@ -806,10 +1034,84 @@ pub fn encode_to_bytes(content: &str, encoding: GedcomEncoding) -> Result<Vec<u8
             }
             Ok(encoded.into_owned())
         }
-        GedcomEncoding::Ansel => Ok(encode_ansel(content)),
+        GedcomEncoding::Ansel => encode_with(content, encoding, UnmappableCharacters::Replace('?')),
     }
 }
 
+/// Encodes a UTF-8 string to bytes with the specified encoding, without a byte order mark.
+///
+/// Unlike [`encode_to_bytes`], every encoding reports characters it cannot represent the
+/// same way, as chosen by `unmappable`. ASCII output rejects anything outside 7-bit ASCII.
+///
+/// # Errors
+///
+/// Returns `GedcomError::EncodingError` naming the first character that cannot be encoded
+/// when `unmappable` is [`UnmappableCharacters::Error`].
+///
+/// # Example
+///
+/// ```rust
+/// use ged_io::encoding::{encode_with, GedcomEncoding, UnmappableCharacters};
+///
+/// let bytes = encode_with("José", GedcomEncoding::Ansel, UnmappableCharacters::Error).unwrap();
+/// assert_eq!(bytes, b"Jos\xE2e");
+///
+/// let bytes = encode_with("Łódź", GedcomEncoding::Iso8859_1, UnmappableCharacters::Replace('?'));
+/// assert_eq!(bytes.unwrap(), b"?\xF3d?");
+/// assert!(encode_with("Łódź", GedcomEncoding::Iso8859_1, UnmappableCharacters::Error).is_err());
+/// ```
+pub fn encode_with(
+    content: &str,
+    encoding: GedcomEncoding,
+    unmappable: UnmappableCharacters,
+) -> Result<Vec<u8>, GedcomError> {
+    let mut output = Vec::with_capacity(content.len());
+    encode_into(content, encoding, unmappable, &mut output)?;
+    Ok(output)
+}
+
+/// Encodes a UTF-8 string with the specified encoding, appending the bytes to `output`.
+pub(crate) fn encode_into(
+    content: &str,
+    encoding: GedcomEncoding,
+    unmappable: UnmappableCharacters,
+    output: &mut Vec<u8>,
+) -> Result<(), GedcomError> {
+    match encoding {
+        GedcomEncoding::Utf8 | GedcomEncoding::Unknown => {
+            output.extend_from_slice(content.as_bytes());
+        }
+        GedcomEncoding::Ascii => {
+            for ch in content.chars() {
+                if ch.is_ascii() {
+                    output.push(ch as u8);
+                } else {
+                    unmappable.handle(ch, encoding, output)?;
+                }
+            }
+        }
+        GedcomEncoding::Utf16Le => {
+            for unit in content.encode_utf16() {
+                output.extend_from_slice(&unit.to_le_bytes());
+            }
+        }
+        GedcomEncoding::Utf16Be => {
+            for unit in content.encode_utf16() {
+                output.extend_from_slice(&unit.to_be_bytes());
+            }
+        }
+        // Windows-1252 is a superset of ISO-8859-1, matching the decoder
+        GedcomEncoding::Iso8859_1 => {
+            encode_single_byte(content, WINDOWS_1252, encoding, unmappable, output)?;
+        }
+        GedcomEncoding::Iso8859_15 => {
+            encode_single_byte(content, ISO_8859_15, encoding, unmappable, output)?;
+        }
+        GedcomEncoding::Ansel => encode_ansel(content, unmappable, output)?,
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1021,7 +1323,8 @@ mod tests {
     #[test]
     fn test_ansel_encode_basic() {
         let content = "John Smith";
-        let bytes = encode_ansel(content);
+        let bytes =
+            encode_with(content, GedcomEncoding::Ansel, UnmappableCharacters::Error).unwrap();
         assert_eq!(bytes, b"John Smith");
     }
 
@@ -1029,7 +1332,8 @@ mod tests {
     fn test_ansel_encode_special_chars() {
         // Test encoding special characters
         let content = "Łódź"; // Polish city name
-        let bytes = encode_ansel(content);
+        let bytes =
+            encode_with(content, GedcomEncoding::Ansel, UnmappableCharacters::Error).unwrap();
         // Ł = 0xA1, ó = acute + o, d = d, ź = acute + z
         assert_eq!(bytes, &[0xA1, 0xE2, b'o', b'd', 0xE2, b'z']);
     }
@@ -1038,7 +1342,8 @@ mod tests {
     fn test_ansel_encode_precomposed() {
         // Test encoding precomposed characters
         let content = "José García";
-        let bytes = encode_ansel(content);
+        let bytes =
+            encode_with(content, GedcomEncoding::Ansel, UnmappableCharacters::Error).unwrap();
         // J, o, s, acute+e, space, G, a, r, c, acute+i, a
         assert_eq!(
             bytes,
@@ -1052,7 +1357,8 @@ mod tests {
         let original_bytes = &[0xA1, 0xB1, 0xA5, 0xB5]; // Ł, ł, Æ, æ
         let decoded = decode_ansel(original_bytes);
         assert_eq!(decoded, "ŁłÆæ");
-        let encoded = encode_ansel(&decoded);
+        let encoded =
+            encode_with(&decoded, GedcomEncoding::Ansel, UnmappableCharacters::Error).unwrap();
         assert_eq!(encoded, original_bytes);
     }
 
@@ -1105,4 +1411,53 @@ mod tests {
         assert_eq!(parse_encoding_value("ANSEL"), Some(GedcomEncoding::Ansel));
         assert_eq!(parse_encoding_value("UNKNOWN"), None);
     }
+
+    #[test]
+    fn test_encode_with_reports_unmappable_characters() {
+        let error = encode_with(
+            "Łódź",
+            GedcomEncoding::Iso8859_1,
+            UnmappableCharacters::Error,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("U+0141"), "{error}");
+        assert!(encode_with("€", GedcomEncoding::Ascii, UnmappableCharacters::Error).is_err());
+        assert!(encode_with("日本", GedcomEncoding::Ansel, UnmappableCharacters::Error).is_err());
+    }
+
+    #[test]
+    fn test_encode_with_replaces_unmappable_characters() {
+        let replace = UnmappableCharacters::Replace('?');
+        assert_eq!(
+            encode_with("Łódź", GedcomEncoding::Iso8859_1, replace).unwrap(),
+            b"?\xF3d?"
+        );
+        assert_eq!(
+            encode_with("Łódź €", GedcomEncoding::Iso8859_15, replace).unwrap(),
+            b"?\xF3d? \xA4"
+        );
+        assert_eq!(
+            encode_with("Zoë 日本", GedcomEncoding::Ascii, replace).unwrap(),
+            b"Zo? ??"
+        );
+        assert_eq!(
+            encode_with("Zoë 日本", GedcomEncoding::Ansel, replace).unwrap(),
+            b"Zo\xE8e ??"
+        );
+        // A replacement the encoding cannot represent either is reported
+        assert!(encode_with(
+            "日本",
+            GedcomEncoding::Ascii,
+            UnmappableCharacters::Replace('\u{FFFD}')
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_encode_with_omits_byte_order_mark() {
+        let bytes = encode_with("0", GedcomEncoding::Utf16Le, UnmappableCharacters::Error);
+        assert_eq!(bytes.unwrap(), [b'0', 0x00]);
+        assert_eq!(GedcomEncoding::Utf16Be.byte_order_mark(), [0xFE, 0xFF]);
+        assert_eq!(GedcomEncoding::Utf16Be.char_value(), "UNICODE");
+    }
 }
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::types::Xref;
+
 /// Represents errors that can occur during GEDCOM parsing.
 ///
 /// This enum provides detailed error information including line numbers
@@ -115,6 +117,77 @@ impl fmt::Display for GedcomError {
 
 impl std::error::Error for GedcomError {}
 
+impl GedcomError {
+    /// Returns the line number carried by the error, if any.
+    #[must_use]
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            GedcomError::ParseError { line, .. } => Some(*line as usize),
+            GedcomError::InvalidTag { line, .. }
+            | GedcomError::UnexpectedLevel { line, .. }
+            | GedcomError::MissingRequiredValue { line, .. }
+            | GedcomError::InvalidValueFormat { line, .. } => Some(*line),
+            GedcomError::InvalidFormat(_)
+            | GedcomError::EncodingError(_)
+            | GedcomError::FileSizeLimitExceeded { .. }
+            | GedcomError::IoError(_) => None,
+        }
+    }
+}
+
+/// The severity of a [`Diagnostic`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The input is questionable, but nothing was lost (e.g. a missing trailer).
+    Warning,
+    /// A record failed to parse and was dropped, or failed validation.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem collected while parsing with
+/// [`ErrorMode::Deferred`](crate::builder::ErrorMode::Deferred).
+///
+/// Instead of aborting on the first [`GedcomError`], the parser records a diagnostic, skips to
+/// the next level-0 record and carries on.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// The 1-based line number where the problem was detected, if known.
+    pub line: Option<usize>,
+    /// The 1-based column of the offending token on `line`, if known.
+    pub column: Option<usize>,
+    /// The xref of the level-0 record the problem belongs to, if the record has one.
+    pub xref: Option<Xref>,
+    /// The underlying error.
+    pub error: GedcomError,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+        if let Some(line) = self.line {
+            write!(f, " at line {line}")?;
+            if let Some(column) = self.column {
+                write!(f, ", column {column}")?;
+            }
+        }
+        if let Some(xref) = &self.xref {
+            write!(f, " in record {xref}")?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
 impl From<std::io::Error> for GedcomError {
     fn from(err: std::io::Error) -> Self {
         GedcomError::IoError(err.to_string())
@@ -229,4 +302,32 @@ mod tests {
             _ => panic!("Expected IoError variant"),
         }
     }
+
+    #[test]
+    fn test_error_line() {
+        let err = GedcomError::InvalidTag {
+            line: 7,
+            tag: "FOO".to_string(),
+        };
+        assert_eq!(err.line(), Some(7));
+        assert_eq!(GedcomError::InvalidFormat("x".to_string()).line(), None);
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic = Diagnostic {
+            severity: Severity::Error,
+            line: Some(4),
+            column: Some(3),
+            xref: Some("@I1@".into()),
+            error: GedcomError::InvalidTag {
+                line: 4,
+                tag: "FOO".to_string(),
+            },
+        };
+        assert_eq!(
+            format!("{diagnostic}"),
+            "error at line 4, column 3 in record @I1@: Invalid tag at line 4: 'FOO'"
+        );
+    }
 }
@@ -324,7 +324,8 @@ impl<W: Write + Seek> GedzipWriter<W> {
         })
     }
 
-    /// Writes the GEDCOM data to the archive as `gedcom.ged`.
+    /// Writes the GEDCOM data to the archive as `gedcom.ged`, in GEDCOM 7.0 as GEDZIP
+    /// requires.
     ///
     /// This should be called before adding any media files.
     ///
@@ -332,7 +333,7 @@ impl<W: Write + Seek> GedzipWriter<W> {
     ///
     /// Returns an error if the GEDCOM data cannot be serialized or written.
     pub fn write_gedcom(&mut self, data: &GedcomData) -> Result<(), GedzipError> {
-        let writer = GedcomWriter::new();
+        let writer = GedcomWriter::new().gedcom_version("7.0");
         let content = writer
             .write_to_string(data)
             .map_err(|e| GedzipError::GedcomError(GedcomError::InvalidFormat(e.to_string())))?;
@@ -21,14 +21,18 @@
 use std::collections::HashMap;
 
 use crate::types::{
-    family::Family, individual::Individual, multimedia::Multimedia, repository::Repository,
-    source::Source, submitter::Submitter, GedcomData,
+    external_id::ExternalId, family::Family, individual::Individual, multimedia::Multimedia,
+    note::Note, repository::Repository, shared_note::SharedNote, source::Source,
+    submitter::Submitter, xref::strip_at_signs, GedcomData, RecordRef,
 };
 
 /// A wrapper around `GedcomData` that provides O(1) lookups by cross-reference ID.
 ///
 /// This structure builds `HashMap` indexes upon creation, trading memory for lookup speed.
 /// It's recommended for use cases that require frequent lookups by xref.
+///
+/// Indexes are keyed by identifiers without their at-signs, so lookups accept `@I1@` and `I1`
+/// alike.
 #[derive(Debug)]
 pub struct IndexedGedcomData {
     /// The underlying GEDCOM data
@@ -45,6 +49,22 @@ pub struct IndexedGedcomData {
     multimedia_index: HashMap<Box<str>, usize>,
     /// Index mapping submitter xrefs to their position in the submitters vector
     submitter_index: HashMap<Box<str>, usize>,
+    /// Index mapping shared note xrefs to their position in the shared notes vector
+    shared_note_index: HashMap<Box<str>, usize>,
+    /// Index mapping `(EXID.TYPE, EXID)` pairs to the first record carrying them
+    external_id_index: HashMap<(Box<str>, Box<str>), RecordSlot>,
+}
+
+/// The kind and position of a record in the underlying `GedcomData`.
+#[derive(Clone, Copy, Debug)]
+enum RecordSlot {
+    Individual(usize),
+    Family(usize),
+    Source(usize),
+    Repository(usize),
+    Multimedia(usize),
+    SharedNote(usize),
+    Submitter(usize),
 }
 
 impl IndexedGedcomData {
@@ -60,6 +80,8 @@ impl IndexedGedcomData {
             repository_index: HashMap::with_capacity(data.repositories.len()),
             multimedia_index: HashMap::with_capacity(data.multimedia.len()),
             submitter_index: HashMap::with_capacity(data.submitters.len()),
+            shared_note_index: HashMap::with_capacity(data.shared_notes.len()),
+            external_id_index: HashMap::new(),
             data,
         };
         indexed.build_indexes();
@@ -71,44 +93,85 @@ impl IndexedGedcomData {
         // Index individuals
         for (i, individual) in self.data.individuals.iter().enumerate() {
             if let Some(ref xref) = individual.xref {
-                self.individual_index.insert(xref.clone().into(), i);
+                self.individual_index.insert(xref.id().into(), i);
             }
         }
 
         // Index families
         for (i, family) in self.data.families.iter().enumerate() {
             if let Some(ref xref) = family.xref {
-                self.family_index.insert(xref.clone().into(), i);
+                self.family_index.insert(xref.id().into(), i);
             }
         }
 
         // Index sources
         for (i, source) in self.data.sources.iter().enumerate() {
             if let Some(ref xref) = source.xref {
-                self.source_index.insert(xref.clone().into(), i);
+                self.source_index.insert(xref.id().into(), i);
             }
         }
 
         // Index repositories
         for (i, repo) in self.data.repositories.iter().enumerate() {
             if let Some(ref xref) = repo.xref {
-                self.repository_index.insert(xref.clone().into(), i);
+                self.repository_index.insert(xref.id().into(), i);
             }
         }
 
         // Index multimedia
         for (i, media) in self.data.multimedia.iter().enumerate() {
             if let Some(ref xref) = media.xref {
-                self.multimedia_index.insert(xref.clone().into(), i);
+                self.multimedia_index.insert(xref.id().into(), i);
             }
         }
 
         // Index submitters
         for (i, submitter) in self.data.submitters.iter().enumerate() {
             if let Some(ref xref) = submitter.xref {
-                self.submitter_index.insert(xref.clone().into(), i);
+                self.submitter_index.insert(xref.id().into(), i);
+            }
+        }
+
+        // Index shared notes
+        for (i, note) in self.data.shared_notes.iter().enumerate() {
+            if let Some(ref xref) = note.xref {
+                self.shared_note_index.insert(xref.id().into(), i);
             }
         }
+
+        // Index external identifiers, keeping the first record for each identifier
+        let mut index = HashMap::new();
+        let mut insert = |slot: RecordSlot, external_ids: &[ExternalId]| {
+            for exid in external_ids {
+                if let Some(ref type_uri) = exid.type_uri {
+                    index
+                        .entry((type_uri.as_str().into(), exid.id.as_str().into()))
+                        .or_insert(slot);
+                }
+            }
+        };
+        for (i, individual) in self.data.individuals.iter().enumerate() {
+            insert(RecordSlot::Individual(i), &individual.external_ids);
+        }
+        for (i, family) in self.data.families.iter().enumerate() {
+            insert(RecordSlot::Family(i), &family.external_ids);
+        }
+        for (i, source) in self.data.sources.iter().enumerate() {
+            insert(RecordSlot::Source(i), &source.external_ids);
+        }
+        for (i, repo) in self.data.repositories.iter().enumerate() {
+            insert(RecordSlot::Repository(i), &repo.external_ids);
+        }
+        for (i, media) in self.data.multimedia.iter().enumerate() {
+            insert(RecordSlot::Multimedia(i), &media.external_ids);
+        }
+        for (i, note) in self.data.shared_notes.iter().enumerate() {
+            insert(RecordSlot::SharedNote(i), &note.external_ids);
+        }
+        for (i, submitter) in self.data.submitters.iter().enumerate() {
+            insert(RecordSlot::Submitter(i), &submitter.external_ids);
+        }
+        self.external_id_index = index;
     }
 
     /// Returns a reference to the underlying `GedcomData`.
@@ -142,7 +205,7 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_individual(&self, xref: &str) -> Option<&Individual> {
         self.individual_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.individuals[idx])
     }
 
@@ -151,7 +214,7 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_family(&self, xref: &str) -> Option<&Family> {
         self.family_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.families[idx])
     }
 
@@ -160,7 +223,7 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_source(&self, xref: &str) -> Option<&Source> {
         self.source_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.sources[idx])
     }
 
@@ -169,7 +232,7 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_repository(&self, xref: &str) -> Option<&Repository> {
         self.repository_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.repositories[idx])
     }
 
@@ -178,7 +241,7 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_multimedia(&self, xref: &str) -> Option<&Multimedia> {
         self.multimedia_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.multimedia[idx])
     }
 
@@ -187,10 +250,45 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn find_submitter(&self, xref: &str) -> Option<&Submitter> {
         self.submitter_index
-            .get(xref)
+            .get(strip_at_signs(xref))
             .map(|&idx| &self.data.submitters[idx])
     }
 
+    /// Finds a shared note by cross-reference ID in O(1) time.
+    #[inline]
+    #[must_use]
+    pub fn find_shared_note(&self, xref: &str) -> Option<&SharedNote> {
+        self.shared_note_index
+            .get(strip_at_signs(xref))
+            .map(|&idx| &self.data.shared_notes[idx])
+    }
+
+    /// Finds the first record with the external identifier `id` issued by the authority
+    /// `type_uri` in O(1) time.
+    #[must_use]
+    pub fn find_by_external_id(&self, type_uri: &str, id: &str) -> Option<RecordRef<'_>> {
+        let key: (Box<str>, Box<str>) = (type_uri.into(), id.into());
+        self.external_id_index.get(&key).map(|&slot| match slot {
+            RecordSlot::Individual(idx) => RecordRef::Individual(&self.data.individuals[idx]),
+            RecordSlot::Family(idx) => RecordRef::Family(&self.data.families[idx]),
+            RecordSlot::Source(idx) => RecordRef::Source(&self.data.sources[idx]),
+            RecordSlot::Repository(idx) => RecordRef::Repository(&self.data.repositories[idx]),
+            RecordSlot::Multimedia(idx) => RecordRef::Multimedia(&self.data.multimedia[idx]),
+            RecordSlot::SharedNote(idx) => RecordRef::SharedNote(&self.data.shared_notes[idx]),
+            RecordSlot::Submitter(idx) => RecordRef::Submitter(&self.data.submitters[idx]),
+        })
+    }
+
+    /// Resolves a note pointer such as `1 NOTE @N1@` to the shared note it refers to.
+    ///
+    /// Returns `None` for inline notes and for pointers without a matching record.
+    #[must_use]
+    pub fn resolve_note(&self, note: &Note) -> Option<&SharedNote> {
+        note.shared_note
+            .as_deref()
+            .and_then(|xref| self.find_shared_note(xref))
+    }
+
     /// Gets the families where an individual is a spouse/partner.
     ///
     /// Note: This is still O(n) where n is the number of families, as it requires
@@ -213,7 +311,7 @@ impl IndexedGedcomData {
         family
             .children
             .iter()
-            .filter_map(|xref| self.find_individual(xref))
+            .filter_map(|child| self.find_individual(&child.xref))
             .collect()
     }
 
@@ -221,12 +319,12 @@ impl IndexedGedcomData {
     #[must_use]
     pub fn get_parents(&self, family: &Family) -> Vec<&Individual> {
         let mut parents = Vec::with_capacity(2);
-        if let Some(ref xref) = family.individual1 {
+        if let Some(xref) = family.individual1() {
             if let Some(ind) = self.find_individual(xref) {
                 parents.push(ind);
             }
         }
-        if let Some(ref xref) = family.individual2 {
+        if let Some(xref) = family.individual2() {
             if let Some(ind) = self.find_individual(xref) {
                 parents.push(ind);
             }
@@ -237,24 +335,10 @@ impl IndexedGedcomData {
     /// Gets the spouse/partner of an individual in a specific family.
     #[must_use]
     pub fn get_spouse(&self, individual_xref: &str, family: &Family) -> Option<&Individual> {
-        if family
-            .individual1
-            .as_ref()
-            .is_some_and(|x| x == individual_xref)
-        {
-            family
-                .individual2
-                .as_ref()
-                .and_then(|x| self.find_individual(x))
-        } else if family
-            .individual2
-            .as_ref()
-            .is_some_and(|x| x == individual_xref)
-        {
-            family
-                .individual1
-                .as_ref()
-                .and_then(|x| self.find_individual(x))
+        if family.individual1().is_some_and(|x| x == individual_xref) {
+            family.individual2().and_then(|x| self.find_individual(x))
+        } else if family.individual2().is_some_and(|x| x == individual_xref) {
+            family.individual1().and_then(|x| self.find_individual(x))
         } else {
             None
         }
@@ -302,6 +386,7 @@ impl IndexedGedcomData {
             repository_index_size: self.repository_index.len(),
             multimedia_index_size: self.multimedia_index.len(),
             submitter_index_size: self.submitter_index.len(),
+            shared_note_index_size: self.shared_note_index.len(),
         }
     }
 }
@@ -327,6 +412,8 @@ pub struct IndexStats {
     pub multimedia_index_size: usize,
     /// Number of entries in the submitter index
     pub submitter_index_size: usize,
+    /// Number of entries in the shared note index
+    pub shared_note_index_size: usize,
 }
 
 impl IndexStats {
@@ -339,6 +426,7 @@ impl IndexStats {
             + self.repository_index_size
             + self.multimedia_index_size
             + self.submitter_index_size
+            + self.shared_note_index_size
     }
 }
 
@@ -367,6 +455,8 @@ mod tests {
             1 TITL Birth Records\n\
             0 @R1@ REPO\n\
             1 NAME Library\n\
+            1 NOTE @N1@\n\
+            0 @N1@ NOTE Opening hours vary\n\
             0 TRLR";
         GedcomBuilder::new().build_from_str(source).unwrap()
     }
@@ -400,7 +490,7 @@ mod tests {
 
         let family = indexed.find_family("@F1@");
         assert!(family.is_some());
-        assert_eq!(family.unwrap().individual1, Some("@I1@".to_string()));
+        assert_eq!(family.unwrap().individual1().unwrap(), "@I1@");
     }
 
     #[test]
@@ -421,6 +511,63 @@ mod tests {
         assert!(repo.is_some());
     }
 
+    #[test]
+    fn test_resolve_note() {
+        let data = create_test_data();
+        let indexed = IndexedGedcomData::from(data);
+
+        let repo = indexed.find_repository("@R1@").unwrap();
+        let note = indexed.resolve_note(&repo.notes[0]).unwrap();
+        assert_eq!(note.text, "Opening hours vary");
+        assert!(indexed.find_shared_note("@N2@").is_none());
+    }
+
+    #[test]
+    fn test_find_by_external_id() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n\
+            0 @I1@ INDI\n1 EXID LZDP-V3V\n2 TYPE https://www.familysearch.org/tree/person/\n\
+            0 @F1@ FAM\n1 EXID Doe-42\n2 TYPE https://www.wikitree.com/wiki/\n\
+            0 @I2@ INDI\n1 EXID LZDP-V3V\n2 TYPE https://www.familysearch.org/tree/person/\n\
+            0 @U1@ SUBM\n1 NAME Archivist\n1 EXID 4\n2 TYPE https://gedcom.io/terms/v7/RIN\n\
+            0 TRLR";
+        let data = GedcomBuilder::new().build_from_str(source).unwrap();
+        let indexed = IndexedGedcomData::from(data);
+
+        let record = indexed
+            .find_by_external_id("https://www.familysearch.org/tree/person/", "LZDP-V3V")
+            .unwrap();
+        assert_eq!(record.xref(), Some("@I1@"));
+        assert_eq!(
+            record,
+            indexed
+                .data()
+                .find_by_external_id("https://www.familysearch.org/tree/person/", "LZDP-V3V")
+                .unwrap()
+        );
+
+        let record = indexed
+            .find_by_external_id("https://www.wikitree.com/wiki/", "Doe-42")
+            .unwrap();
+        assert!(record.as_family().is_some());
+        assert!(indexed
+            .find_by_external_id("https://www.wikitree.com/wiki/", "LZDP-V3V")
+            .is_none());
+
+        let record = indexed
+            .find_by_external_id("https://gedcom.io/terms/v7/RIN", "4")
+            .unwrap();
+        assert!(
+            matches!(record, RecordRef::Submitter(submitter) if submitter.custom_data.is_empty())
+        );
+        assert_eq!(
+            record,
+            indexed
+                .data()
+                .find_by_external_id("https://gedcom.io/terms/v7/RIN", "4")
+                .unwrap()
+        );
+    }
+
     #[test]
     fn test_get_children() {
         let data = create_test_data();
@@ -466,7 +613,8 @@ mod tests {
         assert_eq!(stats.family_index_size, 1);
         assert_eq!(stats.source_index_size, 1);
         assert_eq!(stats.repository_index_size, 1);
-        assert_eq!(stats.total(), 6);
+        assert_eq!(stats.shared_note_index_size, 1);
+        assert_eq!(stats.total(), 7);
     }
 
     #[test]
@@ -146,8 +146,14 @@ pub mod encoding;
 /// - Known GEDCOM tag handling
 #[macro_use]
 pub mod util;
+/// Zero-copy parsing into records that borrow from the input.
+pub mod borrowed;
 /// Builder pattern for configuring GEDCOM parsing.
 pub mod builder;
+/// Conversion of GEDCOM data between versions 5.5.1 and 7.0, reporting every lossy step.
+pub mod convert;
+/// Lossless concrete syntax tree that re-serializes untouched records byte for byte.
+pub mod cst;
 /// Improved Debug trait implementations for GEDCOM data structures.
 pub mod debug;
 /// Display trait implementations for GEDCOM data structures.
@@ -185,9 +191,17 @@ pub mod error;
 #[cfg(feature = "gedzip")]
 pub mod gedzip;
 
+/// Asynchronous streaming parser over tokio `AsyncBufRead` readers.
+///
+/// Requires the `async` feature to be enabled.
+#[cfg(feature = "async")]
+pub mod async_stream;
+
 /// Indexed GEDCOM data structure for O(1) lookups.
 pub mod indexed;
 pub mod parser;
+/// Seekable index of record locations, persisted to a sidecar file.
+pub mod record_index;
 /// Streaming parser for large GEDCOM files.
 ///
 /// This module provides an iterator-based streaming parser that reads GEDCOM files
@@ -252,14 +266,18 @@ pub mod version;
 /// # }
 /// ```
 pub mod writer;
-pub use builder::{GedcomBuilder, ParserConfig};
+#[cfg(feature = "async")]
+pub use async_stream::AsyncGedcomStreamParser;
+pub use builder::{ErrorMode, GedcomBuilder, ParserConfig};
+pub use convert::{ConversionKind, ConversionNote};
 pub use debug::ImprovedDebug;
-pub use encoding::{decode_gedcom_bytes, detect_encoding, GedcomEncoding};
-pub use error::GedcomError;
+pub use encoding::{decode_gedcom_bytes, detect_encoding, GedcomEncoding, UnmappableCharacters};
+pub use error::{Diagnostic, GedcomError, Severity};
+pub use record_index::RecordIndex;
 pub use stream::{GedcomRecord, GedcomStreamParser};
-pub use types::SourceCitationStats;
+pub use types::{RecordRef, SourceCitationStats, Xref, XrefAllocator};
 pub use version::{detect_version, GedcomVersion, VersionFeatures};
-pub use writer::{GedcomWriter, WriterConfig};
+pub use writer::{GedcomStreamWriter, GedcomWriter, WriterConfig};
 
 use crate::{
     tokenizer::{Token, Tokenizer},
@@ -33,12 +33,77 @@ pub trait StreamParser {
     ) -> Result<(), GedcomError>;
 }
 
+/// The result of a [`parse_subset`] tag handler.
+///
+/// Handlers return `Ok(None)` once they have consumed a tag they recognize, and pass on the
+/// result of [`handle_unknown_tag`] for tags they do not, so that `parse_subset` can keep
+/// the unknown structure in its returned custom data.
+pub type TagResult = Result<Option<Box<UserDefinedTag>>, GedcomError>;
+
+/// Handles a standard tag that the enclosing structure does not recognize.
+///
+/// The tokenizer must be positioned on the unknown tag, which sits at `level`. The behavior
+/// follows the tokenizer's [`ParserConfig`](crate::ParserConfig):
+///
+/// - In `strict_mode` (unless `ignore_unknown_tags` is also set) an
+///   [`GedcomError::InvalidTag`] is returned.
+/// - With `ignore_unknown_tags` the tag line and all of its substructures are skipped, so
+///   that they are not mistaken for substructures of the enclosing record.
+/// - Otherwise the tag and its substructures are captured and returned as a
+///   [`UserDefinedTag`], which the tag handler passes back to the enclosing [`parse_subset`]
+///   call, so the enclosing structure keeps them in its custom data and the writer can emit
+///   them again.
+///
+/// # Errors
+///
+/// Returns a `GedcomError` if strict mode rejects the tag or tokenization fails.
+pub fn handle_unknown_tag(tokenizer: &mut Tokenizer<'_>, tag: &str, level: u8) -> TagResult {
+    handle_unknown_tag_stream(tokenizer, tag, level)
+}
+
+/// Generic version of `handle_unknown_tag` that works with any tokenizer implementing
+/// `TokenizerTrait`.
+///
+/// # Errors
+///
+/// Returns a `GedcomError` if strict mode rejects the tag or tokenization fails.
+pub fn handle_unknown_tag_stream<T: TokenizerTrait>(
+    tokenizer: &mut T,
+    tag: &str,
+    level: u8,
+) -> TagResult {
+    let config = tokenizer.config();
+    if config.strict_mode && !config.ignore_unknown_tags {
+        return Err(GedcomError::InvalidTag {
+            line: tokenizer.line() as usize,
+            tag: tag.to_string(),
+        });
+    }
+
+    if !config.ignore_unknown_tags {
+        let unknown = UserDefinedTag::new_from_tokenizer(tokenizer, level, tag)?;
+        return Ok(Some(Box::new(unknown)));
+    }
+
+    // skip over the tag and everything nested below it
+    tokenizer.next_token()?;
+    loop {
+        match tokenizer.current_token() {
+            Token::Level(current) if *current <= level => break,
+            Token::EOF => break,
+            _ => tokenizer.next_token()?,
+        }
+    }
+    Ok(None)
+}
+
 /// Parses GEDCOM tokens at a specific hierarchical level, handling both standard and custom tags.
 ///
 /// This function processes tokens from the tokenizer until it encounters a token at or below
 /// the specified level, effectively parsing all child elements of a GEDCOM structure.
 /// Standard tags are handled by the provided callback, while custom/non-standard tags
-/// are collected and returned.
+/// are collected and returned along with any unknown standard structures the callback
+/// returned from [`handle_unknown_tag`].
 ///
 /// # Errors
 ///
@@ -50,7 +115,7 @@ pub fn parse_subset<F>(
     mut tag_handler: F,
 ) -> Result<Vec<Box<UserDefinedTag>>, GedcomError>
 where
-    F: FnMut(&str, &mut Tokenizer<'_>) -> Result<(), GedcomError>,
+    F: FnMut(&str, &mut Tokenizer<'_>) -> TagResult,
 {
     let mut non_standard_dataset = Vec::new();
     loop {
@@ -63,15 +128,16 @@ where
         match &tokenizer.current_token {
             Token::Tag(tag) => {
                 let tag_clone = tag.clone();
-                tag_handler(tag_clone.as_ref(), tokenizer)?;
+                if let Some(unknown) = tag_handler(tag_clone.as_ref(), tokenizer)? {
+                    non_standard_dataset.push(unknown);
+                }
             }
             Token::CustomTag(tag) => {
                 let tag_clone = tag.clone();
-                non_standard_dataset.push(Box::new(UserDefinedTag::new(
-                    tokenizer,
-                    level + 1,
-                    &tag_clone,
-                )?));
+                let custom = UserDefinedTag::new(tokenizer, level + 1, &tag_clone)?;
+                if !tokenizer.config().ignore_unknown_tags {
+                    non_standard_dataset.push(Box::new(custom));
+                }
             }
             Token::Level(_) => tokenizer.next_token()?,
             Token::LineValue(_) => {
@@ -96,7 +162,8 @@ where
 /// This function processes tokens from the tokenizer until it encounters a token at or below
 /// the specified level, effectively parsing all child elements of a GEDCOM structure.
 /// Standard tags are handled by the provided callback, while custom/non-standard tags
-/// are collected and returned.
+/// are collected and returned along with any unknown standard structures the callback
+/// returned from [`handle_unknown_tag`].
 ///
 /// # Errors
 ///
@@ -109,7 +176,7 @@ pub fn parse_subset_stream<T, F>(
 ) -> Result<Vec<Box<UserDefinedTag>>, GedcomError>
 where
     T: TokenizerTrait,
-    F: FnMut(&str, &mut T) -> Result<(), GedcomError>,
+    F: FnMut(&str, &mut T) -> TagResult,
 {
     let mut non_standard_dataset = Vec::new();
     loop {
@@ -122,15 +189,16 @@ where
         match tokenizer.current_token() {
             Token::Tag(tag) => {
                 let tag_clone = tag.clone();
-                tag_handler(tag_clone.as_ref(), tokenizer)?;
+                if let Some(unknown) = tag_handler(tag_clone.as_ref(), tokenizer)? {
+                    non_standard_dataset.push(unknown);
+                }
             }
             Token::CustomTag(tag) => {
                 let tag_clone = tag.clone();
-                non_standard_dataset.push(Box::new(UserDefinedTag::new_from_tokenizer(
-                    tokenizer,
-                    level + 1,
-                    &tag_clone,
-                )?));
+                let custom = UserDefinedTag::new_from_tokenizer(tokenizer, level + 1, &tag_clone)?;
+                if !tokenizer.config().ignore_unknown_tags {
+                    non_standard_dataset.push(Box::new(custom));
+                }
             }
             Token::Level(_) => tokenizer.next_token()?,
             Token::LineValue(_) => {
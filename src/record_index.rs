@@ -0,0 +1,518 @@
+//! Seekable index of the records in a GEDCOM file.
+//!
+//! [`RecordIndex`] scans a file once and records where each level-0 record starts, how
+//! many bytes it spans and what type of record it is. The index can be saved to a sidecar
+//! file and loaded later, so a single record can be parsed on demand by seeking to it,
+//! giving O(1) lookups by xref without holding a whole [`GedcomData`](crate::GedcomData)
+//! in memory.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use ged_io::record_index::RecordIndex;
+//!
+//! // Build the index once and keep it next to the file
+//! let index = RecordIndex::build(BufReader::new(File::open("huge.ged")?))?;
+//! index.save("huge.ged.idx")?;
+//!
+//! // Later, look up single records without parsing the rest of the file
+//! let index = RecordIndex::load("huge.ged.idx")?;
+//! let mut file = BufReader::new(File::open("huge.ged")?);
+//! if let Some(record) = index.read_record(&mut file, "@I1234@")? {
+//!     println!("{:?}", record.as_individual().and_then(|i| i.full_name()));
+//! }
+//! # Ok::<(), ged_io::GedcomError>(())
+//! ```
+//!
+//! # Encodings
+//!
+//! Offsets are byte offsets into the original file, so the index works for UTF-8, ASCII,
+//! ISO-8859 and ANSEL files, where line breaks and level-0 lines are plain ASCII bytes.
+//! UTF-16 files are rejected.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use crate::{
+    encoding::{parse_encoding_value, GedcomEncoding},
+    stream::{detect_stream_encoding, is_record_start, is_trailer, GedcomRecord, DETECTION_WINDOW},
+    GedcomError, GedcomStreamParser,
+};
+
+/// First line of a persisted index, identifying the format and its version.
+const SIDECAR_MAGIC: &str = "GED_IO RECORD INDEX 1";
+
+/// The type of a level-0 record, as given by its tag.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordKind {
+    /// `HEAD`
+    Header,
+    /// `INDI`
+    Individual,
+    /// `FAM`
+    Family,
+    /// `SOUR`
+    Source,
+    /// `REPO`
+    Repository,
+    /// `SUBM`
+    Submitter,
+    /// `SUBN`
+    Submission,
+    /// `OBJE`
+    Multimedia,
+    /// `SNOTE`, or a GEDCOM 5.5.1 level-0 `NOTE`
+    SharedNote,
+    /// Any other tag, usually a user-defined `_TAG`
+    Custom(String),
+}
+
+impl RecordKind {
+    /// Returns the kind of record introduced by a level-0 tag.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Self {
+        match tag {
+            "HEAD" => RecordKind::Header,
+            "INDI" => RecordKind::Individual,
+            "FAM" => RecordKind::Family,
+            "SOUR" => RecordKind::Source,
+            "REPO" => RecordKind::Repository,
+            "SUBM" => RecordKind::Submitter,
+            "SUBN" => RecordKind::Submission,
+            "OBJE" => RecordKind::Multimedia,
+            "SNOTE" | "NOTE" => RecordKind::SharedNote,
+            _ => RecordKind::Custom(tag.to_string()),
+        }
+    }
+
+    /// Returns the tag for this kind of record.
+    #[must_use]
+    pub fn tag(&self) -> &str {
+        match self {
+            RecordKind::Header => "HEAD",
+            RecordKind::Individual => "INDI",
+            RecordKind::Family => "FAM",
+            RecordKind::Source => "SOUR",
+            RecordKind::Repository => "REPO",
+            RecordKind::Submitter => "SUBM",
+            RecordKind::Submission => "SUBN",
+            RecordKind::Multimedia => "OBJE",
+            RecordKind::SharedNote => "SNOTE",
+            RecordKind::Custom(tag) => tag,
+        }
+    }
+}
+
+impl fmt::Display for RecordKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
+
+/// Where a record is stored within a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordLocation {
+    /// Byte offset of the record's level-0 line.
+    pub offset: u64,
+    /// Number of bytes up to the next level-0 line, including line terminators.
+    pub length: u64,
+    /// The type of record.
+    pub kind: RecordKind,
+}
+
+/// An index from xref to the location of each record in a GEDCOM file.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordIndex {
+    /// Encoding used to decode records read through the index
+    encoding: GedcomEncoding,
+    /// Length in bytes of the indexed file
+    source_len: u64,
+    /// Location of the header record, which has no xref
+    header: Option<RecordLocation>,
+    /// Locations of records by xref
+    records: HashMap<Box<str>, RecordLocation>,
+}
+
+impl RecordIndex {
+    /// Scans GEDCOM data and builds an index of its records.
+    ///
+    /// Only the current line is held in memory. The encoding is detected from the start
+    /// of the input, as [`GedcomStreamParser::new`] does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::EncodingError` for UTF-16 input, or `GedcomError::IoError` if
+    /// reading fails.
+    pub fn build<R: BufRead>(mut reader: R) -> Result<Self, GedcomError> {
+        let mut index = Self {
+            encoding: GedcomEncoding::Utf8,
+            source_len: 0,
+            header: None,
+            records: HashMap::new(),
+        };
+        let mut sample = Vec::with_capacity(DETECTION_WINDOW);
+        let mut line = Vec::with_capacity(256);
+        let mut current: Option<(Option<Box<str>>, RecordLocation)> = None;
+        let mut trailer_seen = false;
+
+        loop {
+            line.clear();
+            let read = reader.read_until(b'\n', &mut line)?;
+            if read == 0 {
+                break;
+            }
+            if index.source_len == 0
+                && (line.starts_with(&[0xFF, 0xFE]) || line.starts_with(&[0xFE, 0xFF]))
+            {
+                return Err(utf16_error());
+            }
+            if sample.len() < DETECTION_WINDOW {
+                sample.extend_from_slice(&line[..read.min(DETECTION_WINDOW - sample.len())]);
+            }
+            let offset = index.source_len;
+            index.source_len += read as u64;
+
+            if trailer_seen || !may_start_record(&line) {
+                continue;
+            }
+            let text = String::from_utf8_lossy(&line);
+            let text = text.trim_start_matches('\u{FEFF}');
+            if !is_record_start(text) {
+                continue;
+            }
+
+            if let Some((xref, location)) = current.take() {
+                index.insert(xref, location, offset);
+            }
+            if is_trailer(text) {
+                trailer_seen = true;
+                continue;
+            }
+
+            let mut parts = text.split_whitespace().skip(1);
+            let first = parts.next().unwrap_or_default();
+            let (xref, tag) = if first.starts_with('@') {
+                (Some(first.into()), parts.next().unwrap_or_default())
+            } else {
+                (None, first)
+            };
+            current = Some((
+                xref,
+                RecordLocation {
+                    offset,
+                    length: 0,
+                    kind: RecordKind::from_tag(tag),
+                },
+            ));
+        }
+
+        if let Some((xref, location)) = current.take() {
+            let end = index.source_len;
+            index.insert(xref, location, end);
+        }
+        index.encoding = detect_stream_encoding(&sample);
+        if is_utf16(index.encoding) {
+            return Err(utf16_error());
+        }
+        Ok(index)
+    }
+
+    /// Records a location that ends at `end`, keeping the first record for each xref.
+    fn insert(&mut self, xref: Option<Box<str>>, mut location: RecordLocation, end: u64) {
+        location.length = end - location.offset;
+        match xref {
+            Some(xref) => {
+                self.records.entry(xref).or_insert(location);
+            }
+            None if location.kind == RecordKind::Header => {
+                self.header.get_or_insert(location);
+            }
+            None => {}
+        }
+    }
+
+    /// Returns the location of the record with the given xref.
+    #[must_use]
+    pub fn get(&self, xref: &str) -> Option<&RecordLocation> {
+        self.records.get(xref)
+    }
+
+    /// Returns the location of the header record.
+    #[must_use]
+    pub fn header(&self) -> Option<&RecordLocation> {
+        self.header.as_ref()
+    }
+
+    /// Returns an iterator over the xrefs and locations of the indexed records.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RecordLocation)> {
+        self.records
+            .iter()
+            .map(|(xref, location)| (xref.as_ref(), location))
+    }
+
+    /// Returns the number of records with an xref.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns true if no records with an xref were indexed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the encoding records are decoded with.
+    #[must_use]
+    pub fn encoding(&self) -> GedcomEncoding {
+        self.encoding
+    }
+
+    /// Returns the length in bytes of the indexed file.
+    ///
+    /// Compare this with the file's current length to detect a stale sidecar.
+    #[must_use]
+    pub fn source_len(&self) -> u64 {
+        self.source_len
+    }
+
+    /// Seeks to the record with the given xref and parses it.
+    ///
+    /// Returns `None` if the xref is not in the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if seeking or reading fails, or if the bytes at the
+    /// recorded location do not parse as a record.
+    pub fn read_record<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+        xref: &str,
+    ) -> Result<Option<GedcomRecord>, GedcomError> {
+        self.get(xref)
+            .map(|location| self.read_location(reader, location))
+            .transpose()
+    }
+
+    /// Seeks to a record location and parses the record stored there.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if seeking or reading fails, or if the bytes at the
+    /// location do not parse as a record.
+    pub fn read_location<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+        location: &RecordLocation,
+    ) -> Result<GedcomRecord, GedcomError> {
+        reader.seek(SeekFrom::Start(location.offset))?;
+        let record = reader.by_ref().take(location.length);
+        GedcomStreamParser::with_encoding(record, self.encoding)
+            .next()
+            .unwrap_or_else(|| {
+                Err(GedcomError::InvalidFormat(format!(
+                    "No {} record at byte offset {}",
+                    location.kind, location.offset
+                )))
+            })
+    }
+
+    /// Writes the index in its sidecar format.
+    ///
+    /// The format is line-based text: a version line, the encoding and source length,
+    /// then one `xref tag offset length` line per record, with `-` standing in for the
+    /// header's missing xref.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::IoError` if writing fails.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), GedcomError> {
+        writeln!(writer, "{SIDECAR_MAGIC}")?;
+        writeln!(writer, "ENCODING {}", self.encoding)?;
+        writeln!(writer, "LENGTH {}", self.source_len)?;
+
+        let mut entries: Vec<_> = self
+            .header
+            .iter()
+            .map(|l| ("-", l))
+            .chain(self.iter())
+            .collect();
+        entries.sort_by_key(|(_, location)| location.offset);
+        for (xref, location) in entries {
+            writeln!(
+                writer,
+                "{xref} {} {} {}",
+                location.kind, location.offset, location.length
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads an index previously written with [`RecordIndex::write_to`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::InvalidFormat` if the data is not a valid index, or
+    /// `GedcomError::IoError` if reading fails.
+    pub fn read_from<R: BufRead>(reader: R) -> Result<Self, GedcomError> {
+        let mut lines = reader.lines();
+        let mut next_line = || lines.next().transpose().map_err(GedcomError::from);
+
+        if next_line()?.as_deref() != Some(SIDECAR_MAGIC) {
+            return Err(sidecar_error("missing or unsupported version line"));
+        }
+        let encoding = next_line()?
+            .as_deref()
+            .and_then(|line| line.strip_prefix("ENCODING "))
+            .and_then(parse_encoding_value)
+            .ok_or_else(|| sidecar_error("missing or unknown encoding"))?;
+        let source_len = next_line()?
+            .as_deref()
+            .and_then(|line| line.strip_prefix("LENGTH "))
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| sidecar_error("missing source length"))?;
+
+        let mut index = Self {
+            encoding,
+            source_len,
+            header: None,
+            records: HashMap::new(),
+        };
+        while let Some(line) = next_line()? {
+            let fields: Vec<&str> = line.split(' ').collect();
+            let [xref, tag, offset, length] = fields[..] else {
+                return Err(sidecar_error(&format!("malformed entry: {line}")));
+            };
+            let (Ok(offset), Ok(length)) = (offset.parse::<u64>(), length.parse::<u64>()) else {
+                return Err(sidecar_error(&format!("malformed entry: {line}")));
+            };
+            let location = RecordLocation {
+                offset,
+                length: 0,
+                kind: RecordKind::from_tag(tag),
+            };
+            let xref = (xref != "-").then(|| xref.into());
+            index.insert(xref, location, offset + length);
+        }
+        Ok(index)
+    }
+
+    /// Saves the index to a sidecar file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::IoError` if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), GedcomError> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    /// Loads an index from a sidecar file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` if the file cannot be read or is not a valid index.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, GedcomError> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Cheaply filters out lines that cannot be level-0 lines before decoding them.
+fn may_start_record(line: &[u8]) -> bool {
+    // Level-0 lines are ASCII in every supported encoding, apart from a leading BOM
+    line.iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|&b| b == b'0' || b == 0xEF)
+}
+
+fn is_utf16(encoding: GedcomEncoding) -> bool {
+    matches!(encoding, GedcomEncoding::Utf16Le | GedcomEncoding::Utf16Be)
+}
+
+fn utf16_error() -> GedcomError {
+    GedcomError::EncodingError(
+        "Record index requires an ASCII-compatible encoding; UTF-16 input detected".to_string(),
+    )
+}
+
+fn sidecar_error(message: &str) -> GedcomError {
+    GedcomError::InvalidFormat(format!("Invalid record index: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const GEDCOM: &str = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 @F1@ FAM\n1 HUSB @I1@\n0 _CUSTOM value\n0 TRLR\n";
+
+    #[test]
+    fn test_build_records_offsets_and_lengths() {
+        let index = RecordIndex::build(GEDCOM.as_bytes()).unwrap();
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.source_len(), GEDCOM.len() as u64);
+
+        let header = index.header().unwrap();
+        assert_eq!((header.offset, header.length), (0, 25));
+
+        let individual = index.get("@I1@").unwrap();
+        assert_eq!(individual.kind, RecordKind::Individual);
+        let start = usize::try_from(individual.offset).unwrap();
+        let end = start + usize::try_from(individual.length).unwrap();
+        assert_eq!(&GEDCOM[start..end], "0 @I1@ INDI\n1 NAME John /Doe/\n");
+
+        // The family ends where the custom record begins
+        let family = index.get("@F1@").unwrap();
+        assert_eq!(family.length, 23);
+    }
+
+    #[test]
+    fn test_read_record_seeks_to_record() {
+        let index = RecordIndex::build(GEDCOM.as_bytes()).unwrap();
+        let mut reader = Cursor::new(GEDCOM.as_bytes());
+
+        let family = index.read_record(&mut reader, "@F1@").unwrap().unwrap();
+        assert_eq!(family.as_family().unwrap().individual1().unwrap(), "@I1@");
+        assert!(index.read_record(&mut reader, "@X9@").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_sidecar_round_trip() {
+        let index = RecordIndex::build(GEDCOM.as_bytes()).unwrap();
+        let mut sidecar = Vec::new();
+        index.write_to(&mut sidecar).unwrap();
+
+        assert!(String::from_utf8_lossy(&sidecar).contains("@I1@ INDI 25 30\n"));
+        assert_eq!(RecordIndex::read_from(sidecar.as_slice()).unwrap(), index);
+    }
+
+    #[test]
+    fn test_sidecar_rejects_malformed_input() {
+        let result = RecordIndex::read_from(
+            "GED_IO RECORD INDEX 1\nENCODING UTF-8\nLENGTH 10\n@I1@ INDI x 3\n".as_bytes(),
+        );
+        assert!(matches!(result, Err(GedcomError::InvalidFormat(_))));
+
+        let result = RecordIndex::read_from("not an index\n".as_bytes());
+        assert!(matches!(result, Err(GedcomError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_build_rejects_utf16() {
+        let bytes: &[u8] = &[0xFF, 0xFE, b'0', 0, b' ', 0, b'H', 0];
+        assert!(matches!(
+            RecordIndex::build(bytes),
+            Err(GedcomError::EncodingError(_))
+        ));
+    }
+}
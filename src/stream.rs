@@ -31,10 +31,14 @@
 //! `GedcomStreamParser` only buffers one record at a time. For files with many small
 //! records, memory usage stays constant regardless of file size.
 //!
-//! # UTF-8 Requirement
+//! # Encodings
 //!
-//! The streaming parser requires UTF-8 encoded input. For files with other encodings,
-//! either convert them to UTF-8 first, or use the in-memory parser with encoding detection.
+//! The encoding is detected from the byte order mark or the header `CHAR` line, as
+//! [`detect_encoding`](crate::detect_encoding) does for in-memory input, and the input is
+//! then decoded incrementally. UTF-8, UTF-16, ISO-8859-1, ISO-8859-15 and ANSEL files can be
+//! streamed without loading them whole. If the start of a file declares nothing and is
+//! plain ASCII, UTF-8 is assumed; use [`GedcomStreamParser::with_encoding`] to override
+//! detection.
 
 use std::io::BufRead;
 
@@ -42,13 +46,15 @@ use std::io::BufRead;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    tokenizer::Tokenizer,
+    builder::ParserConfig,
+    encoding::{detect_encoding, GedcomDecoder, GedcomEncoding},
+    tokenizer::{Token, Tokenizer},
     types::{
         custom::UserDefinedTag, family::Family, header::Header, individual::Individual,
         multimedia::Multimedia, repository::Repository, shared_note::SharedNote, source::Source,
-        submission::Submission, submitter::Submitter, GedcomData,
+        submission::Submission, submitter::Submitter, GedcomData, Xref,
     },
-    GedcomError,
+    GedcomError, GedcomVersion,
 };
 
 /// A single top-level GEDCOM record.
@@ -209,6 +215,22 @@ impl GedcomRecord {
         }
     }
 
+    /// Returns the record's cross-reference identifier, if it has one.
+    #[must_use]
+    pub fn xref(&self) -> Option<&str> {
+        match self {
+            GedcomRecord::Individual(i) => i.xref.as_deref(),
+            GedcomRecord::Family(f) => f.xref.as_deref(),
+            GedcomRecord::Source(s) => s.xref.as_deref(),
+            GedcomRecord::Repository(r) => r.xref.as_deref(),
+            GedcomRecord::Submitter(s) => s.xref.as_deref(),
+            GedcomRecord::Submission(s) => s.xref.as_deref(),
+            GedcomRecord::Multimedia(m) => m.xref.as_deref(),
+            GedcomRecord::SharedNote(n) => n.xref.as_deref(),
+            GedcomRecord::Header(_) | GedcomRecord::CustomData(_) => None,
+        }
+    }
+
     /// Returns true if this is an Individual record.
     #[must_use]
     pub fn is_individual(&self) -> bool {
@@ -285,14 +307,10 @@ impl GedcomRecord {
 /// ```
 pub struct GedcomStreamParser<R: BufRead> {
     reader: R,
-    /// Buffer for the current record's text
-    record_buffer: String,
-    /// Buffer for reading lines
-    line_buffer: String,
-    /// The next line we've peeked (starts with level 0)
-    peeked_line: Option<String>,
-    /// Current line number for error reporting
-    line_number: u32,
+    /// Decodes input bytes and groups them into level-0 records
+    records: RecordReader,
+    /// Parses record text with the configuration and the file's GEDCOM version
+    parser: RecordParser,
     /// Whether we've finished parsing
     finished: bool,
 }
@@ -300,12 +318,13 @@ pub struct GedcomStreamParser<R: BufRead> {
 impl<R: BufRead> GedcomStreamParser<R> {
     /// Creates a new streaming parser from a buffered reader.
     ///
-    /// The reader must provide UTF-8 encoded data.
+    /// The start of the input is read to detect its encoding from the byte order mark or
+    /// the header `CHAR` line; the rest is decoded incrementally as records are read.
     ///
     /// # Errors
     ///
     /// Returns a `GedcomError` if:
-    /// - The input has a UTF-16 BOM (streaming requires UTF-8)
+    /// - The start of the input is not valid in its detected encoding
     /// - An I/O error occurs while reading
     ///
     /// # Example
@@ -319,232 +338,533 @@ impl<R: BufRead> GedcomStreamParser<R> {
     /// let reader = BufReader::new(file);
     /// let parser = GedcomStreamParser::new(reader).unwrap();
     /// ```
-    pub fn new(mut reader: R) -> Result<Self, GedcomError> {
-        // Read first line to check for BOM
-        let mut first_line = String::new();
-        match reader.read_line(&mut first_line) {
-            Ok(0) => {
-                // Empty file
-                return Ok(Self {
-                    reader,
-                    record_buffer: String::with_capacity(4096),
-                    line_buffer: String::with_capacity(256),
-                    peeked_line: None,
-                    line_number: 0,
-                    finished: true,
-                });
+    pub fn new(reader: R) -> Result<Self, GedcomError> {
+        Self::with_config(reader, ParserConfig::default())
+    }
+
+    /// Creates a new streaming parser that parses records with the given
+    /// [`ParserConfig`].
+    ///
+    /// Strict-mode xref validation follows the GEDCOM version declared in the header
+    /// once the header record has been read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` under the same conditions as [`GedcomStreamParser::new`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::stream::GedcomStreamParser;
+    /// use ged_io::ParserConfig;
+    ///
+    /// let gedcom = "0 HEAD\n0 @I1@ INDI\n1 FOO bar\n0 TRLR\n";
+    /// let config = ParserConfig {
+    ///     strict_mode: true,
+    ///     ..ParserConfig::default()
+    /// };
+    /// let result = GedcomStreamParser::with_config(gedcom.as_bytes(), config)
+    ///     .unwrap()
+    ///     .collect::<Result<Vec<_>, _>>();
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn with_config(reader: R, config: ParserConfig) -> Result<Self, GedcomError> {
+        let mut parser = Self {
+            reader,
+            records: RecordReader::new(),
+            parser: RecordParser::new(config),
+            finished: false,
+        };
+        while parser.records.encoding().is_none() {
+            parser.read_input()?;
+        }
+        Ok(parser)
+    }
+
+    /// Creates a new streaming parser that decodes its input with the given encoding,
+    /// skipping detection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::stream::GedcomStreamParser;
+    /// use ged_io::GedcomEncoding;
+    ///
+    /// let latin1: &[u8] = b"0 HEAD\n0 @I1@ INDI\n1 NAME Ren\xE9 /Dupont/\n0 TRLR\n";
+    /// let records = GedcomStreamParser::with_encoding(latin1, GedcomEncoding::Iso8859_1)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// let individual = records[1].as_individual().unwrap();
+    /// assert_eq!(individual.full_name().as_deref(), Some("René Dupont"));
+    /// ```
+    pub fn with_encoding(reader: R, encoding: GedcomEncoding) -> Self {
+        Self {
+            reader,
+            records: RecordReader::with_encoding(encoding),
+            parser: RecordParser::new(ParserConfig::default()),
+            finished: false,
+        }
+    }
+
+    /// Returns the encoding used to decode the input.
+    #[must_use]
+    pub fn encoding(&self) -> GedcomEncoding {
+        self.records.encoding().unwrap_or(GedcomEncoding::Utf8)
+    }
+
+    /// Reads the next chunk of input into the record reader.
+    fn read_input(&mut self) -> Result<(), GedcomError> {
+        let buffer = self
+            .reader
+            .fill_buf()
+            .map_err(|e| GedcomError::IoError(e.to_string()))?;
+        if buffer.is_empty() {
+            return self.records.finish_input();
+        }
+        let length = buffer.len();
+        self.records.feed(buffer)?;
+        self.reader.consume(length);
+        Ok(())
+    }
+
+    /// Reads the next complete record from the stream.
+    ///
+    /// Returns the record text and the line it starts on, or `None` at TRLR or EOF.
+    fn read_next_record(&mut self) -> Result<Option<(String, u32)>, GedcomError> {
+        loop {
+            match self.records.next_record() {
+                NextRecord::Record(text, line) => return Ok(Some((text, line))),
+                NextRecord::Done => return Ok(None),
+                NextRecord::NeedInput => self.read_input()?,
             }
-            Ok(_) => {}
-            Err(e) => {
-                // If read_line fails with invalid UTF-8, it's likely a non-UTF-8 encoding
-                // (e.g., UTF-16). Provide a helpful error message.
-                if e.kind() == std::io::ErrorKind::InvalidData {
-                    return Err(GedcomError::EncodingError(
-                        "Streaming parser requires UTF-8 input; file appears to use a different encoding (possibly UTF-16)".to_string(),
-                    ));
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for GedcomStreamParser<R> {
+    type Item = Result<GedcomRecord, GedcomError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            match self.read_next_record() {
+                Ok(Some((text, line))) => match self.parser.parse(&text, line) {
+                    Ok(Some(record)) => return Some(Ok(record)),
+                    Ok(None) => {}
+                    Err(e) => {
+                        self.finished = true;
+                        return Some(Err(e));
+                    }
+                },
+                Ok(None) => {
+                    self.finished = true;
+                    return None;
+                }
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
                 }
-                return Err(GedcomError::IoError(e.to_string()));
             }
         }
+    }
+}
 
-        // Check for UTF-8 BOM that might look like UTF-16 BOM after decoding (shouldn't happen, but be safe)
-        let bytes = first_line.as_bytes();
-        if bytes.len() >= 2
-            && ((bytes[0] == 0xFF && bytes[1] == 0xFE) || (bytes[0] == 0xFE && bytes[1] == 0xFF))
-        {
-            return Err(GedcomError::EncodingError(
-                "Streaming parser requires UTF-8 input; UTF-16 BOM detected".to_string(),
-            ));
+/// Groups the lines of a GEDCOM stream into level-0 records.
+///
+/// Lines are pushed one at a time; a record is handed back once the first line of the
+/// following record (or the trailer) has been seen. This is shared by the blocking and
+/// asynchronous streaming parsers so that both split input identically.
+#[derive(Debug)]
+pub(crate) struct RecordSplitter {
+    /// Buffer for the current record's text
+    record: String,
+    /// Number of lines pushed so far
+    line_number: u32,
+    /// Line number of the first line of the record in `record`
+    record_line: u32,
+    /// Whether the trailer has been seen
+    done: bool,
+}
+
+impl RecordSplitter {
+    pub(crate) fn new() -> Self {
+        Self {
+            record: String::with_capacity(4096),
+            line_number: 0,
+            record_line: 0,
+            done: false,
+        }
+    }
+
+    /// Adds a line, returning the previous record and its starting line if the line
+    /// begins a new one.
+    pub(crate) fn push_line(&mut self, line: &str) -> Option<(String, u32)> {
+        if self.done {
+            return None;
         }
 
         // Skip UTF-8 BOM if present
-        let first_line = if first_line.starts_with('\u{FEFF}') {
-            first_line['\u{FEFF}'.len_utf8()..].to_string()
+        let line = if self.line_number == 0 {
+            line.strip_prefix('\u{FEFF}').unwrap_or(line)
         } else {
-            first_line
+            line
         };
+        self.line_number += 1;
 
-        Ok(Self {
-            reader,
-            record_buffer: String::with_capacity(4096),
-            line_buffer: String::with_capacity(256),
-            peeked_line: Some(first_line),
-            line_number: 1,
-            finished: false,
-        })
+        if !self.record.is_empty() && !is_record_start(line) {
+            self.record.push_str(line);
+            return None;
+        }
+
+        let completed = self.take_record();
+        if is_trailer(line) {
+            self.done = true;
+        } else {
+            self.record_line = self.line_number;
+            self.record.push_str(line);
+        }
+        completed
     }
 
-    /// Reads the next complete record from the stream.
-    ///
-    /// Returns the record text and whether we hit TRLR or EOF.
-    fn read_next_record(&mut self) -> Result<Option<String>, GedcomError> {
-        self.record_buffer.clear();
+    /// Returns the record still buffered at the end of input, if any.
+    pub(crate) fn finish(&mut self) -> Option<(String, u32)> {
+        self.done = true;
+        self.take_record()
+    }
 
-        // Start with peeked line or read a new one
-        let first_line = if let Some(line) = self.peeked_line.take() {
-            line
-        } else {
-            self.line_buffer.clear();
-            match self.reader.read_line(&mut self.line_buffer) {
-                Ok(0) => return Ok(None),
-                Ok(_) => {
-                    self.line_number += 1;
-                    std::mem::take(&mut self.line_buffer)
-                }
-                Err(e) => return Err(GedcomError::IoError(e.to_string())),
-            }
-        };
+    /// Returns true once the trailer or the end of input has been reached.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
 
-        // Check if this is TRLR
-        let trimmed = first_line.trim();
-        if trimmed == "0 TRLR" || trimmed.starts_with("0 TRLR ") {
-            return Ok(None); // End of file
+    fn take_record(&mut self) -> Option<(String, u32)> {
+        (!self.record.is_empty()).then(|| (std::mem::take(&mut self.record), self.record_line))
+    }
+}
+
+/// Number of bytes examined to detect the encoding of a stream.
+pub(crate) const DETECTION_WINDOW: usize = 8192;
+
+/// The outcome of asking a [`RecordReader`] for its next record.
+#[derive(Debug)]
+pub(crate) enum NextRecord {
+    /// The text of a complete record and the line it starts on.
+    Record(String, u32),
+    /// More input must be fed before the next record is complete.
+    NeedInput,
+    /// The trailer or the end of input has been reached.
+    Done,
+}
+
+/// Decodes a GEDCOM byte stream and groups it into level-0 records.
+///
+/// Input bytes are held back until enough has arrived to detect the encoding from the
+/// byte order mark or the header `CHAR` line, then decoded incrementally. This is shared by
+/// the blocking and asynchronous streaming parsers, which only differ in how they read.
+#[derive(Debug)]
+pub(crate) struct RecordReader {
+    /// The decoder, once the encoding is known
+    decoder: Option<GedcomDecoder>,
+    /// Bytes held back until the encoding is known
+    prefix: Vec<u8>,
+    /// Decoded text that has not been split into lines yet
+    text: String,
+    /// Start of the unsplit text in `text`
+    position: usize,
+    /// Groups lines into level-0 records
+    splitter: RecordSplitter,
+    /// Whether the end of input has been reached
+    input_done: bool,
+}
+
+impl RecordReader {
+    pub(crate) fn new() -> Self {
+        Self {
+            decoder: None,
+            prefix: Vec::new(),
+            text: String::with_capacity(4096),
+            position: 0,
+            splitter: RecordSplitter::new(),
+            input_done: false,
         }
+    }
 
-        // Start accumulating the record
-        self.record_buffer.push_str(&first_line);
+    pub(crate) fn with_encoding(encoding: GedcomEncoding) -> Self {
+        Self {
+            decoder: Some(GedcomDecoder::new(encoding)),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the input encoding, once it has been detected.
+    pub(crate) fn encoding(&self) -> Option<GedcomEncoding> {
+        self.decoder.as_ref().map(GedcomDecoder::encoding)
+    }
+
+    /// Adds a chunk of input bytes.
+    pub(crate) fn feed(&mut self, bytes: &[u8]) -> Result<(), GedcomError> {
+        self.text.drain(..self.position);
+        self.position = 0;
 
-        // Read until we hit another level 0 line or EOF
+        if let Some(decoder) = &mut self.decoder {
+            return decoder.decode_to_string(bytes, &mut self.text, false);
+        }
+        self.prefix.extend_from_slice(bytes);
+        if self.prefix.len() >= DETECTION_WINDOW {
+            self.start_decoding(false)?;
+        }
+        Ok(())
+    }
+
+    /// Signals the end of input.
+    pub(crate) fn finish_input(&mut self) -> Result<(), GedcomError> {
+        self.input_done = true;
+        match &mut self.decoder {
+            Some(decoder) => decoder.decode_to_string(&[], &mut self.text, true),
+            None => self.start_decoding(true),
+        }
+    }
+
+    /// Returns the next complete record, if enough input has been fed.
+    pub(crate) fn next_record(&mut self) -> NextRecord {
         loop {
-            self.line_buffer.clear();
-            match self.reader.read_line(&mut self.line_buffer) {
-                Ok(0) => break, // EOF
-                Ok(_) => {
-                    self.line_number += 1;
-
-                    // Check if this line starts a new level 0 record
-                    let trimmed = self.line_buffer.trim_start();
-                    if trimmed.starts_with('0') && trimmed.len() > 1 {
-                        let second_char = trimmed.chars().nth(1).unwrap_or('x');
-                        if second_char.is_whitespace() {
-                            // This is a new level 0 record - save it for next iteration
-                            self.peeked_line = Some(std::mem::take(&mut self.line_buffer));
-                            break;
-                        }
-                    }
+            if self.splitter.is_done() {
+                return NextRecord::Done;
+            }
 
-                    // Add to current record
-                    self.record_buffer.push_str(&self.line_buffer);
+            let rest = &self.text[self.position..];
+            let line = match rest.find('\n') {
+                Some(index) => &rest[..=index],
+                None if self.input_done && !rest.is_empty() => rest,
+                None if self.input_done => {
+                    return match self.splitter.finish() {
+                        Some((text, line)) => NextRecord::Record(text, line),
+                        None => NextRecord::Done,
+                    };
                 }
-                Err(e) => return Err(GedcomError::IoError(e.to_string())),
+                None => return NextRecord::NeedInput,
+            };
+            self.position += line.len();
+
+            if let Some((text, line)) = self.splitter.push_line(line) {
+                return NextRecord::Record(text, line);
             }
         }
+    }
 
-        Ok(Some(std::mem::take(&mut self.record_buffer)))
+    fn start_decoding(&mut self, last: bool) -> Result<(), GedcomError> {
+        let mut decoder = GedcomDecoder::new(detect_stream_encoding(&self.prefix));
+        let prefix = std::mem::take(&mut self.prefix);
+        decoder.decode_to_string(&prefix, &mut self.text, last)?;
+        self.decoder = Some(decoder);
+        Ok(())
     }
+}
+
+/// Detects the encoding of a stream from its first bytes.
+pub(crate) fn detect_stream_encoding(prefix: &[u8]) -> GedcomEncoding {
+    // The window may end inside a multi-byte UTF-8 sequence, which must not make valid
+    // UTF-8 look like a legacy encoding
+    let sample = match std::str::from_utf8(prefix) {
+        Err(e) if e.error_len().is_none() => &prefix[..e.valid_up_to()],
+        _ => prefix,
+    };
+    detect_encoding(sample)
+}
 
-    /// Parses a record text into a `GedcomRecord`.
-    fn parse_record_text(&self, text: &str) -> Result<GedcomRecord, GedcomError> {
-        use crate::tokenizer::Token;
+/// Returns true if the line starts a level-0 record.
+pub(crate) fn is_record_start(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with('0') && trimmed[1..].starts_with(char::is_whitespace)
+}
 
-        let doc_text = format!("{text}0 TRLR\n");
+/// Returns true if the line is the trailer record (`0 TRLR`).
+pub(crate) fn is_trailer(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed == "0 TRLR" || trimmed.starts_with("0 TRLR ")
+}
 
-        let mut tokenizer = Tokenizer::new(doc_text.chars());
-        tokenizer.next_token()?;
+/// The text of a single level-0 record within a larger GEDCOM string.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RecordChunk<'a> {
+    /// The record's lines, including line terminators.
+    pub text: &'a str,
+    /// The 1-based line number of the record's first line.
+    pub line: u32,
+    /// The byte offset of the record within the string.
+    pub offset: usize,
+}
 
-        let Token::Level(level) = tokenizer.current_token else {
-            if tokenizer.current_token == Token::EOF {
-                return Err(GedcomError::ParseError {
-                    line: self.line_number,
-                    message: "Empty record".to_string(),
+/// Splits GEDCOM content into its level-0 records, stopping at the trailer.
+#[cfg(feature = "rayon")]
+pub(crate) fn split_records(content: &str) -> Vec<RecordChunk<'_>> {
+    let mut chunks = Vec::new();
+    let start = if content.starts_with('\u{FEFF}') {
+        '\u{FEFF}'.len_utf8()
+    } else {
+        0
+    };
+
+    let mut current: Option<RecordChunk<'_>> = None;
+    let mut offset = start;
+    for (index, line) in content[start..].split_inclusive('\n').enumerate() {
+        let line_number = u32::try_from(index + 1).unwrap_or(u32::MAX);
+        if is_record_start(line) || current.is_none() {
+            if let Some(chunk) = current.take() {
+                chunks.push(RecordChunk {
+                    text: &content[chunk.offset..offset],
+                    ..chunk
                 });
             }
-            return Err(GedcomError::ParseError {
-                line: self.line_number,
-                message: format!("Expected Level, found {:?}", tokenizer.current_token),
-            });
-        };
-
-        if level != 0 {
-            return Err(GedcomError::ParseError {
-                line: self.line_number,
-                message: format!("Expected level 0, found level {level}"),
+            if is_trailer(line) {
+                return chunks;
+            }
+            current = Some(RecordChunk {
+                text: "",
+                line: line_number,
+                offset,
             });
         }
+        offset += line.len();
+    }
+    if let Some(chunk) = current {
+        chunks.push(RecordChunk {
+            text: &content[chunk.offset..],
+            ..chunk
+        });
+    }
+    chunks
+}
 
-        tokenizer.next_token()?;
+/// Parses the records of a stream with the caller's configuration.
+///
+/// The GEDCOM version starts at the default and is taken from the header record once it
+/// has been parsed, so that later records are validated against the file's version.
+#[derive(Debug)]
+pub(crate) struct RecordParser {
+    config: ParserConfig,
+    version: GedcomVersion,
+}
 
-        let mut pointer: Option<String> = None;
-        if let Token::Pointer(xref) = &tokenizer.current_token {
-            pointer = Some(xref.to_string());
-            tokenizer.next_token()?;
+impl RecordParser {
+    pub(crate) fn new(config: ParserConfig) -> Self {
+        RecordParser {
+            config,
+            version: GedcomVersion::default(),
         }
+    }
 
-        if let Token::Tag(tag) = &tokenizer.current_token {
-            let record = match tag.as_ref() {
-                "HEAD" => GedcomRecord::Header(Header::new(&mut tokenizer, 0)?),
-                "FAM" => GedcomRecord::Family(Family::new(&mut tokenizer, 0, pointer)?),
-                "INDI" => {
-                    GedcomRecord::Individual(Individual::new(&mut tokenizer, level, pointer)?)
-                }
-                "REPO" => {
-                    GedcomRecord::Repository(Repository::new(&mut tokenizer, level, pointer)?)
-                }
-                "SOUR" => GedcomRecord::Source(Source::new(&mut tokenizer, level, pointer)?),
-                "SUBN" => GedcomRecord::Submission(Submission::new(&mut tokenizer, 0, pointer)?),
-                "SUBM" => GedcomRecord::Submitter(Submitter::new(&mut tokenizer, 0, pointer)?),
-                "OBJE" => GedcomRecord::Multimedia(Multimedia::new(&mut tokenizer, 0, pointer)?),
-                "SNOTE" => GedcomRecord::SharedNote(SharedNote::new(&mut tokenizer, 0, pointer)?),
-                "TRLR" => {
-                    return Err(GedcomError::ParseError {
-                        line: self.line_number,
-                        message: "Unexpected TRLR".to_string(),
-                    });
-                }
-                _ => {
-                    return Err(GedcomError::ParseError {
-                        line: self.line_number,
-                        message: format!("Unhandled tag {tag}"),
-                    });
-                }
-            };
-            Ok(record)
-        } else if let Token::CustomTag(tag) = &tokenizer.current_token {
-            let tag_clone = tag.clone();
-            Ok(GedcomRecord::CustomData(Box::new(UserDefinedTag::new(
-                &mut tokenizer,
-                1,
-                &tag_clone,
-            )?)))
-        } else if tokenizer.current_token == Token::EOF {
-            Err(GedcomError::ParseError {
-                line: self.line_number,
-                message: "Unexpected EOF".to_string(),
-            })
-        } else {
-            Err(GedcomError::ParseError {
-                line: self.line_number,
-                message: format!("Unhandled token {:?}", tokenizer.current_token),
-            })
+    /// Parses the text of the record starting on `line`.
+    pub(crate) fn parse(
+        &mut self,
+        text: &str,
+        line: u32,
+    ) -> Result<Option<GedcomRecord>, GedcomError> {
+        let record = parse_record(text, line, 0, &self.config, &self.version)?;
+        if let Some(version) = record
+            .as_ref()
+            .and_then(GedcomRecord::as_header)
+            .and_then(Header::version)
+        {
+            self.version = GedcomVersion::from_version_str(version);
         }
+        Ok(record)
     }
 }
 
-impl<R: BufRead> Iterator for GedcomStreamParser<R> {
-    type Item = Result<GedcomRecord, GedcomError>;
+/// Parses the text of a single level-0 record.
+///
+/// `line` and `offset` locate the record within the whole file, so that error messages and
+/// spans refer to the original input, and `version` is the file's GEDCOM version that the
+/// record's xref is validated against in strict mode. Returns `None` for records that are
+/// skipped because of [`ParserConfig::ignore_unknown_tags`].
+pub(crate) fn parse_record(
+    text: &str,
+    line: u32,
+    offset: usize,
+    config: &ParserConfig,
+    version: &GedcomVersion,
+) -> Result<Option<GedcomRecord>, GedcomError> {
+    let mut tokenizer = Tokenizer::with_config(text.chars(), config.clone());
+    tokenizer.set_origin(line, offset);
+    tokenizer.set_version(version.clone());
+    tokenizer.next_token()?;
+
+    let Token::Level(level) = tokenizer.current_token else {
+        return Err(GedcomError::ParseError {
+            line,
+            message: if tokenizer.current_token == Token::EOF {
+                "Empty record".to_string()
+            } else {
+                format!("Expected Level, found {:?}", tokenizer.current_token)
+            },
+        });
+    };
+
+    if level != 0 {
+        return Err(GedcomError::ParseError {
+            line,
+            message: format!("Expected level 0, found level {level}"),
+        });
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.finished {
-            return None;
-        }
+    tokenizer.next_token()?;
 
-        match self.read_next_record() {
-            Ok(Some(text)) => match self.parse_record_text(&text) {
-                Ok(record) => Some(Ok(record)),
-                Err(e) => {
-                    self.finished = true;
-                    Some(Err(e))
-                }
-            },
-            Ok(None) => {
-                self.finished = true;
-                None
+    let mut pointer: Option<Xref> = None;
+    if let Token::Pointer(xref) = &tokenizer.current_token {
+        pointer = Some(Xref::from(xref.as_ref()));
+        tokenizer.next_token()?;
+    }
+
+    if let Token::Tag(tag) = &tokenizer.current_token {
+        let record = match tag.as_ref() {
+            "HEAD" => GedcomRecord::Header(Header::new(&mut tokenizer, 0)?),
+            "FAM" => GedcomRecord::Family(Family::new(&mut tokenizer, 0, pointer)?),
+            "INDI" => GedcomRecord::Individual(Individual::new(&mut tokenizer, 0, pointer)?),
+            "REPO" => GedcomRecord::Repository(Repository::new(&mut tokenizer, 0, pointer)?),
+            "SOUR" => GedcomRecord::Source(Source::new(&mut tokenizer, 0, pointer)?),
+            "SUBN" => GedcomRecord::Submission(Submission::new(&mut tokenizer, 0, pointer)?),
+            "SUBM" => GedcomRecord::Submitter(Submitter::new(&mut tokenizer, 0, pointer)?),
+            "OBJE" => GedcomRecord::Multimedia(Multimedia::new(&mut tokenizer, 0, pointer)?),
+            // GEDCOM 5.5.1 NOTE records are the counterpart of GEDCOM 7.0 SNOTE records
+            "NOTE" | "SNOTE" => {
+                GedcomRecord::SharedNote(SharedNote::new(&mut tokenizer, 0, pointer)?)
+            }
+            "TRLR" => {
+                return Err(GedcomError::ParseError {
+                    line,
+                    message: "Unexpected TRLR".to_string(),
+                });
             }
-            Err(e) => {
-                self.finished = true;
-                Some(Err(e))
+            _ if config.ignore_unknown_tags => return Ok(None),
+            _ => {
+                return Err(GedcomError::ParseError {
+                    line,
+                    message: format!("Unhandled tag {tag}"),
+                });
             }
-        }
+        };
+        Ok(Some(record))
+    } else if let Token::CustomTag(tag) = &tokenizer.current_token {
+        let tag_clone = tag.clone();
+        let custom = UserDefinedTag::new(&mut tokenizer, 0, &tag_clone)?;
+        Ok((!config.ignore_unknown_tags).then(|| GedcomRecord::CustomData(Box::new(custom))))
+    } else if tokenizer.current_token == Token::EOF {
+        Err(GedcomError::ParseError {
+            line,
+            message: "Unexpected EOF".to_string(),
+        })
+    } else {
+        Err(GedcomError::ParseError {
+            line,
+            message: format!("Unhandled token {:?}", tokenizer.current_token),
+        })
     }
 }
 
@@ -570,6 +890,58 @@ impl FromIterator<GedcomRecord> for GedcomData {
     }
 }
 
+impl GedcomData {
+    /// Returns copies of all records, in the order [`GedcomWriter`](crate::GedcomWriter)
+    /// writes them.
+    ///
+    /// This is the inverse of collecting records into `GedcomData`.
+    #[must_use]
+    pub fn to_records(&self) -> Vec<GedcomRecord> {
+        let header = self.header.iter().cloned().map(GedcomRecord::Header);
+        header
+            .chain(self.submitters.iter().cloned().map(GedcomRecord::Submitter))
+            .chain(
+                self.submissions
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::Submission),
+            )
+            .chain(
+                self.individuals
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::Individual),
+            )
+            .chain(self.families.iter().cloned().map(GedcomRecord::Family))
+            .chain(self.sources.iter().cloned().map(GedcomRecord::Source))
+            .chain(
+                self.repositories
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::Repository),
+            )
+            .chain(
+                self.multimedia
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::Multimedia),
+            )
+            .chain(
+                self.shared_notes
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::SharedNote),
+            )
+            .chain(
+                self.custom_data
+                    .iter()
+                    .cloned()
+                    .map(GedcomRecord::CustomData),
+            )
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -640,24 +1012,81 @@ mod tests {
         assert_eq!(data.families.len(), 1);
     }
 
+    fn utf16le(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in text.encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        bytes
+    }
+
     #[test]
-    fn test_stream_parser_utf16_rejected() {
-        // UTF-16 LE BOM - read_line will fail with invalid UTF-8 error
-        // which we convert to an EncodingError
-        let bytes: &[u8] = &[0xFF, 0xFE, b'0', 0, b' ', 0];
-        let reader = BufReader::new(bytes);
-        let result = GedcomStreamParser::new(reader);
-
-        assert!(result.is_err());
-        if let Err(GedcomError::EncodingError(msg)) = result {
-            // Message should indicate non-UTF-8 encoding (possibly UTF-16)
-            assert!(
-                msg.contains("UTF-8") || msg.contains("UTF-16"),
-                "Expected encoding error message, got: {msg}"
-            );
-        } else {
-            panic!("Expected EncodingError");
+    fn test_stream_parser_utf16() {
+        let bytes = utf16le("0 HEAD\n1 CHAR UNICODE\n0 @I1@ INDI\n1 NAME Zoë /Ång/\n0 TRLR\n");
+        // A tiny buffer splits code units across reads
+        let parser =
+            GedcomStreamParser::new(BufReader::with_capacity(3, bytes.as_slice())).unwrap();
+        assert_eq!(parser.encoding(), GedcomEncoding::Utf16Le);
+
+        let records: Vec<_> = parser.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        let individual = records[1].as_individual().unwrap();
+        assert_eq!(individual.full_name().as_deref(), Some("Zoë Ång"));
+    }
+
+    #[test]
+    fn test_stream_parser_ansel_from_char_tag() {
+        // "Jos\xE2e" is "José" with the acute accent before its base letter
+        let bytes: &[u8] =
+            b"0 HEAD\n1 CHAR ANSEL\n0 @I1@ INDI\n1 NAME Jos\xE2e /M\xB2ller/\n0 TRLR\n";
+        let parser = GedcomStreamParser::new(BufReader::with_capacity(4, bytes)).unwrap();
+        assert_eq!(parser.encoding(), GedcomEncoding::Ansel);
+
+        let records: Vec<_> = parser.collect::<Result<Vec<_>, _>>().unwrap();
+        let individual = records[1].as_individual().unwrap();
+        assert_eq!(
+            individual.full_name().as_deref(),
+            Some("Jose\u{301} Møller")
+        );
+    }
+
+    #[test]
+    fn test_stream_parser_latin1_from_char_tag() {
+        let bytes: &[u8] =
+            b"0 HEAD\n1 CHAR ISO-8859-1\n0 @I1@ INDI\n1 NAME Ren\xE9 /Dupont/\n0 TRLR\n";
+        let parser = GedcomStreamParser::new(BufReader::new(bytes)).unwrap();
+        assert_eq!(parser.encoding(), GedcomEncoding::Iso8859_1);
+
+        let records: Vec<_> = parser.collect::<Result<Vec<_>, _>>().unwrap();
+        let individual = records[1].as_individual().unwrap();
+        assert_eq!(individual.full_name().as_deref(), Some("René Dupont"));
+    }
+
+    #[test]
+    fn test_stream_parser_utf8_split_across_detection_window() {
+        // Put a multi-byte character across the end of the detection window
+        let mut gedcom = String::from("0 HEAD\n1 CHAR UTF-8\n");
+        while gedcom.len() < DETECTION_WINDOW - 1 {
+            gedcom.push_str("1 NOTE padding\n");
         }
+        gedcom.truncate(DETECTION_WINDOW - 1);
+        gedcom.push_str("é\n0 @I1@ INDI\n1 NAME Zoë /Doe/\n0 TRLR\n");
+
+        let records: Vec<_> = GedcomStreamParser::new(BufReader::new(gedcom.as_bytes()))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn test_stream_parser_invalid_utf8() {
+        let bytes: &[u8] = b"0 HEAD\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME Bad \xFF\n0 TRLR\n";
+        // Short inputs are decoded while detecting the encoding in `new`
+        let result = GedcomStreamParser::new(BufReader::new(bytes))
+            .and_then(|parser| parser.collect::<Result<Vec<_>, _>>());
+
+        assert!(matches!(result, Err(GedcomError::EncodingError(_))));
     }
 
     #[test]
@@ -731,7 +1160,7 @@ mod tests {
 
         assert_eq!(records.len(), 2);
         let indi = records[1].as_individual().unwrap();
-        assert!(indi.note.is_some());
+        assert_eq!(indi.notes.len(), 1);
     }
 
     #[test]
@@ -775,4 +1204,57 @@ mod tests {
             panic!("Expected CustomData");
         }
     }
+
+    #[test]
+    fn test_stream_parser_error_reports_record_line() {
+        let gedcom = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @X1@ BOGUS\n0 TRLR";
+        let reader = BufReader::new(gedcom.as_bytes());
+        let result: Result<Vec<_>, _> = GedcomStreamParser::new(reader).unwrap().collect();
+
+        assert!(matches!(
+            result,
+            Err(GedcomError::ParseError { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_stream_parser_with_config_validates_xrefs_against_header_version() {
+        let strict = ParserConfig {
+            strict_mode: true,
+            ..ParserConfig::default()
+        };
+        let parse = |version: &str| {
+            let gedcom = format!("0 HEAD\n1 GEDC\n2 VERS {version}\n0 @i1@ INDI\n0 TRLR\n");
+            GedcomStreamParser::with_config(gedcom.as_bytes(), strict.clone())
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        assert_eq!(parse("5.5.1").unwrap().len(), 2);
+        assert!(matches!(
+            parse("7.0"),
+            Err(GedcomError::InvalidValueFormat { line: 4, .. })
+        ));
+
+        let lenient =
+            GedcomStreamParser::new("0 HEAD\n0 @I1@ INDI\n1 FOO bar\n0 TRLR\n".as_bytes())
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>();
+        assert!(lenient.is_ok());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_split_records() {
+        let content =
+            "\u{FEFF}0 HEAD\r\n1 GEDC\r\n0 @I1@ INDI\r\n1 NAME A /B/\r\n0 TRLR\r\n0 @I2@ INDI";
+        let chunks = split_records(content);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "0 HEAD\r\n1 GEDC\r\n");
+        assert_eq!(chunks[0].line, 1);
+        assert_eq!(chunks[1].text, "0 @I1@ INDI\r\n1 NAME A /B/\r\n");
+        assert_eq!(chunks[1].line, 3);
+        assert_eq!(&content[chunks[1].offset..][..11], "0 @I1@ INDI");
+    }
 }
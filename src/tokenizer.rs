@@ -6,7 +6,11 @@
 //! Both tokenizers implement the [`TokenizerTrait`] trait, allowing parsers to
 //! work with either implementation.
 
-use crate::GedcomError;
+use crate::{
+    builder::ParserConfig,
+    types::{span::Span, Xref},
+    GedcomError, GedcomVersion,
+};
 use std::io::BufRead;
 use std::str::Chars;
 
@@ -136,6 +140,9 @@ pub trait TokenizerTrait {
 
     /// Returns a debug string with the current line number.
     fn debug(&self) -> String;
+
+    /// Returns the parser configuration this tokenizer was created with.
+    fn config(&self) -> &ParserConfig;
 }
 
 /// Average length estimate for GEDCOM tags (most are 4 chars)
@@ -147,6 +154,25 @@ const VALUE_CAPACITY: usize = 64;
 /// Average length estimate for xref pointers
 const POINTER_CAPACITY: usize = 16;
 
+/// Checks a record identifier against the rules of `version`, reporting a violation at
+/// `line`.
+fn validate_pointer(pointer: &str, version: &GedcomVersion, line: u32) -> Result<(), GedcomError> {
+    Xref::from(pointer)
+        .validate(version)
+        .map_err(|error| match error {
+            GedcomError::InvalidValueFormat {
+                value,
+                expected_format,
+                ..
+            } => GedcomError::InvalidValueFormat {
+                line: line as usize,
+                value,
+                expected_format,
+            },
+            error => error,
+        })
+}
+
 /// The tokenizer that turns the GEDCOM characters into a list of tokens
 pub struct Tokenizer<'a> {
     /// The active token type
@@ -157,17 +183,137 @@ pub struct Tokenizer<'a> {
     chars: Chars<'a>,
     /// The current line number of the file we are parsing
     pub line: u32,
+    /// The 1-based column of `current_char` on its line
+    char_column: u32,
+    /// The 1-based column where the current token starts
+    token_column: u32,
+    /// The total length of the input in bytes
+    input_len: usize,
+    /// The byte offset where the current line starts
+    line_start_offset: usize,
+    /// The byte offset of the input within the whole file
+    offset_base: usize,
+    /// The parser configuration consulted by the record parsers
+    config: ParserConfig,
+    /// The GEDCOM version record identifiers are validated against in strict mode
+    version: GedcomVersion,
 }
 
 impl<'a> Tokenizer<'a> {
     /// Creates a new tokenizer for a char interator of GEDCOM file contents
     #[must_use]
     pub fn new(chars: Chars<'a>) -> Tokenizer<'a> {
+        Tokenizer::with_config(chars, ParserConfig::default())
+    }
+
+    /// Creates a new tokenizer that carries a [`ParserConfig`] down to the record parsers.
+    #[must_use]
+    pub fn with_config(chars: Chars<'a>, config: ParserConfig) -> Tokenizer<'a> {
         Tokenizer {
             current_char: '\n',
             current_token: Token::None,
+            input_len: chars.as_str().len(),
+            line_start_offset: 0,
+            offset_base: 0,
             chars,
             line: 0,
+            char_column: 0,
+            token_column: 0,
+            config,
+            version: GedcomVersion::default(),
+        }
+    }
+
+    /// Returns the parser configuration.
+    #[inline]
+    #[must_use]
+    pub fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    /// Sets the GEDCOM version that record identifiers are validated against in strict
+    /// mode, typically once the header has been read. Defaults to GEDCOM 5.5.1.
+    pub fn set_version(&mut self, version: GedcomVersion) {
+        self.version = version;
+    }
+
+    /// Returns the 1-based column where the current token starts.
+    #[inline]
+    #[must_use]
+    pub fn column(&self) -> u32 {
+        self.token_column
+    }
+
+    /// Declares that the input starts at the given 1-based line number and byte offset of a
+    /// larger file, so that line numbers and spans refer to that file.
+    ///
+    /// Must be called before the first token is read.
+    pub fn set_origin(&mut self, line: u32, offset: usize) {
+        self.line = line.saturating_sub(1);
+        self.offset_base = offset;
+        self.line_start_offset = offset;
+    }
+
+    /// Returns the line number and byte offset of the current line, marking the start of a
+    /// [`Span`], or `None` when span recording is disabled.
+    #[must_use]
+    pub fn span_start(&self) -> Option<(u32, usize)> {
+        self.config
+            .record_spans
+            .then_some((self.line, self.line_start_offset))
+    }
+
+    /// Completes a span begun with [`span_start`](Self::span_start), ending just before the
+    /// line the tokenizer is currently positioned on.
+    #[must_use]
+    pub fn span_end(&self, start: Option<(u32, usize)>) -> Option<Span> {
+        let (start_line, start_offset) = start?;
+        let (end_line, end_offset) = if self.done() {
+            (self.line, self.offset_base + self.input_len)
+        } else {
+            (self.line.saturating_sub(1), self.line_start_offset)
+        };
+        Some(Span {
+            start_line,
+            end_line: end_line.max(start_line),
+            start_offset,
+            end_offset,
+        })
+    }
+
+    /// Returns the byte offset of `current_char` in the input.
+    #[inline]
+    fn char_offset(&self) -> usize {
+        let remaining = self.chars.as_str().len();
+        let consumed = if self.current_char == '\0' {
+            self.input_len - remaining
+        } else {
+            self.input_len - remaining - self.current_char.len_utf8()
+        };
+        self.offset_base + consumed
+    }
+
+    /// Skips ahead to the start of the next level-0 line, leaving the tokenizer positioned on
+    /// its `Level(0)` token (or on `EOF`).
+    ///
+    /// This is used to resume parsing at the next record after an error. If the tokenizer
+    /// is already positioned on a `Level(0)` token, it is left untouched.
+    pub fn skip_to_next_record(&mut self) {
+        loop {
+            if matches!(self.current_token, Token::Level(0) | Token::EOF) {
+                return;
+            }
+            while !matches!(self.current_char, '\n' | '\r' | '\0') {
+                self.next_char();
+            }
+            if self.current_char == '\0' {
+                self.current_token = Token::EOF;
+                return;
+            }
+            // A malformed line fails to tokenize; keep skipping past it.
+            if self.next_token().is_err() {
+                self.current_token = Token::None;
+            }
         }
     }
 
@@ -219,8 +365,16 @@ impl<'a> Tokenizer<'a> {
                 }
             }
 
-            self.current_token = Token::Level(self.extract_number()?);
+            // A BOM at the start of the file is not part of the first line.
+            if self.line == 0 && self.current_char == '\u{FEFF}' {
+                self.next_char();
+                self.char_column = 1;
+            }
+
             self.line += 1;
+            self.token_column = self.char_column;
+            self.line_start_offset = self.char_offset();
+            self.current_token = Token::Level(self.extract_number()?);
             return Ok(());
         }
 
@@ -244,10 +398,15 @@ impl<'a> Tokenizer<'a> {
             return Ok(());
         }
 
+        self.token_column = self.char_column;
         self.current_token = match self.current_token {
             Token::Level(_) => {
                 if self.current_char == '@' {
-                    Token::Pointer(self.extract_word_with_capacity(POINTER_CAPACITY))
+                    let pointer = self.extract_word_with_capacity(POINTER_CAPACITY);
+                    if self.config.strict_mode {
+                        validate_pointer(&pointer, &self.version, self.line)?;
+                    }
+                    Token::Pointer(pointer)
                 } else if self.current_char == '_' {
                     Token::CustomTag(self.extract_word_with_capacity(TAG_CAPACITY))
                 } else {
@@ -289,6 +448,11 @@ impl<'a> Tokenizer<'a> {
 
     #[inline]
     fn next_char(&mut self) {
+        if matches!(self.current_char, '\n' | '\r') {
+            self.char_column = 1;
+        } else {
+            self.char_column += 1;
+        }
         self.current_char = self.chars.next().unwrap_or('\0');
     }
 
@@ -337,6 +501,9 @@ impl<'a> Tokenizer<'a> {
             value.push(self.current_char);
             self.next_char();
         }
+        if !self.config.preserve_formatting {
+            value = normalize_whitespace(&value);
+        }
         value.into_boxed_str()
     }
 
@@ -421,7 +588,8 @@ impl<'a> Tokenizer<'a> {
                     }
                 },
                 Token::Level(_) => self.next_token()?,
-                Token::EOF => break,
+                // A user-defined substructure ends the text; leave it for parse_subset
+                Token::CustomTag(_) | Token::EOF => break,
                 _ => {
                     return Err(GedcomError::ParseError {
                         line: self.line,
@@ -474,6 +642,18 @@ impl TokenizerTrait for Tokenizer<'_> {
     fn debug(&self) -> String {
         Tokenizer::debug(self)
     }
+
+    #[inline]
+    fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+}
+
+/// Collapses runs of whitespace into a single space and trims the value.
+///
+/// Used when [`ParserConfig::preserve_formatting`] is disabled.
+fn normalize_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 // ============================================================================
@@ -528,6 +708,10 @@ pub struct StreamTokenizer<R: BufRead> {
     eof: bool,
     /// Whether this is the initial state (before first token)
     initial: bool,
+    /// The parser configuration consulted by the record parsers
+    config: ParserConfig,
+    /// The GEDCOM version record identifiers are validated against in strict mode
+    version: GedcomVersion,
 }
 
 impl<R: BufRead> StreamTokenizer<R> {
@@ -554,6 +738,16 @@ impl<R: BufRead> StreamTokenizer<R> {
     /// let tokenizer = StreamTokenizer::new(reader).unwrap();
     /// ```
     pub fn new(reader: R) -> Result<Self, GedcomError> {
+        Self::with_config(reader, ParserConfig::default())
+    }
+
+    /// Creates a new streaming tokenizer that carries a [`ParserConfig`] down to the
+    /// record parsers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GedcomError` under the same conditions as [`StreamTokenizer::new`].
+    pub fn with_config(reader: R, config: ParserConfig) -> Result<Self, GedcomError> {
         let mut tokenizer = Self {
             reader,
             line_buffer: String::with_capacity(LINE_BUFFER_CAPACITY),
@@ -563,6 +757,8 @@ impl<R: BufRead> StreamTokenizer<R> {
             line: 0,
             eof: false,
             initial: true,
+            config,
+            version: GedcomVersion::default(),
         };
 
         // Read first line to check for BOM and initialize
@@ -594,6 +790,12 @@ impl<R: BufRead> StreamTokenizer<R> {
         Ok(tokenizer)
     }
 
+    /// Sets the GEDCOM version that record identifiers are validated against in strict
+    /// mode. Defaults to GEDCOM 5.5.1.
+    pub fn set_version(&mut self, version: GedcomVersion) {
+        self.version = version;
+    }
+
     /// Reads the next line from the reader into the line buffer.
     fn read_next_line(&mut self) -> Result<(), GedcomError> {
         self.line_buffer.clear();
@@ -708,6 +910,9 @@ impl<R: BufRead> StreamTokenizer<R> {
             value.push(self.current_char);
             self.next_char()?;
         }
+        if !self.config.preserve_formatting {
+            value = normalize_whitespace(&value);
+        }
         Ok(value.into_boxed_str())
     }
 
@@ -748,8 +953,8 @@ impl<R: BufRead> StreamTokenizer<R> {
                 return Ok(());
             }
 
-            self.current_token = Token::Level(self.extract_number()?);
             self.line += 1;
+            self.current_token = Token::Level(self.extract_number()?);
             return Ok(());
         }
 
@@ -776,7 +981,11 @@ impl<R: BufRead> StreamTokenizer<R> {
         self.current_token = match self.current_token {
             Token::Level(_) => {
                 if self.current_char == '@' {
-                    Token::Pointer(self.extract_word_with_capacity(POINTER_CAPACITY)?)
+                    let pointer = self.extract_word_with_capacity(POINTER_CAPACITY)?;
+                    if self.config.strict_mode {
+                        validate_pointer(&pointer, &self.version, self.line)?;
+                    }
+                    Token::Pointer(pointer)
                 } else if self.current_char == '_' {
                     Token::CustomTag(self.extract_word_with_capacity(TAG_CAPACITY)?)
                 } else {
@@ -873,7 +1082,8 @@ impl<R: BufRead> TokenizerTrait for StreamTokenizer<R> {
                     }
                 },
                 Token::Level(_) => self.next_token()?,
-                Token::EOF => break,
+                // A user-defined substructure ends the text; leave it for parse_subset
+                Token::CustomTag(_) | Token::EOF => break,
                 _ => {
                     return Err(GedcomError::ParseError {
                         line: self.line,
@@ -888,4 +1098,8 @@ impl<R: BufRead> TokenizerTrait for StreamTokenizer<R> {
     fn debug(&self) -> String {
         format!("line {}:", self.line)
     }
+
+    fn config(&self) -> &ParserConfig {
+        &self.config
+    }
 }
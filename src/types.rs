@@ -5,14 +5,13 @@
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
 
-type Xref = String;
-
 pub mod address;
 pub mod age;
 pub mod corporation;
 pub mod custom;
 pub mod date;
 pub mod event;
+pub mod external_id;
 pub mod family;
 pub mod gedcom7;
 pub mod header;
@@ -24,19 +23,24 @@ pub mod place;
 pub mod repository;
 pub mod shared_note;
 pub mod source;
+pub mod span;
 pub mod submission;
 pub mod submitter;
 pub mod translation;
+pub mod xref;
+
+pub use xref::{Xref, XrefAllocator};
 
 use crate::{
-    parser::Parser,
+    error::{Diagnostic, Severity},
+    parser::{handle_unknown_tag, Parser},
     tokenizer::{Token, Tokenizer},
     types::{
-        custom::UserDefinedTag, family::Family, header::Header, individual::Individual,
-        multimedia::Multimedia, repository::Repository, shared_note::SharedNote, source::Source,
-        submission::Submission, submitter::Submitter,
+        custom::UserDefinedTag, external_id::ExternalId, family::Family, header::Header,
+        individual::Individual, multimedia::Multimedia, note::Note, repository::Repository,
+        shared_note::SharedNote, source::Source, submission::Submission, submitter::Submitter,
     },
-    GedcomError,
+    GedcomError, GedcomVersion,
 };
 
 /// Represents a complete parsed GEDCOM genealogy file.
@@ -66,6 +70,76 @@ pub struct SourceCitationStats {
     pub on_other: usize,
 }
 
+/// A borrowed reference to one level-0 record of a [`GedcomData`].
+///
+/// Returned by lookups that can match records of several kinds, such as
+/// [`GedcomData::find_by_external_id`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RecordRef<'a> {
+    /// An individual record (`INDI`).
+    Individual(&'a Individual),
+    /// A family record (`FAM`).
+    Family(&'a Family),
+    /// A source record (`SOUR`).
+    Source(&'a Source),
+    /// A repository record (`REPO`).
+    Repository(&'a Repository),
+    /// A multimedia record (`OBJE`).
+    Multimedia(&'a Multimedia),
+    /// A shared note record (`SNOTE`, or `NOTE` in GEDCOM 5.5.1).
+    SharedNote(&'a SharedNote),
+    /// A submitter record (`SUBM`).
+    Submitter(&'a Submitter),
+}
+
+impl<'a> RecordRef<'a> {
+    /// Returns the cross-reference ID of the record, if it has one.
+    #[must_use]
+    pub fn xref(&self) -> Option<&'a str> {
+        match self {
+            RecordRef::Individual(record) => record.xref.as_deref(),
+            RecordRef::Family(record) => record.xref.as_deref(),
+            RecordRef::Source(record) => record.xref.as_deref(),
+            RecordRef::Repository(record) => record.xref.as_deref(),
+            RecordRef::Multimedia(record) => record.xref.as_deref(),
+            RecordRef::SharedNote(record) => record.xref.as_deref(),
+            RecordRef::Submitter(record) => record.xref.as_deref(),
+        }
+    }
+
+    /// Returns the external identifiers (`EXID`) of the record.
+    #[must_use]
+    pub fn external_ids(&self) -> &'a [ExternalId] {
+        match self {
+            RecordRef::Individual(record) => &record.external_ids,
+            RecordRef::Family(record) => &record.external_ids,
+            RecordRef::Source(record) => &record.external_ids,
+            RecordRef::Repository(record) => &record.external_ids,
+            RecordRef::Multimedia(record) => &record.external_ids,
+            RecordRef::SharedNote(record) => &record.external_ids,
+            RecordRef::Submitter(record) => &record.external_ids,
+        }
+    }
+
+    /// Returns the individual, if this is an individual record.
+    #[must_use]
+    pub fn as_individual(&self) -> Option<&'a Individual> {
+        match self {
+            RecordRef::Individual(record) => Some(record),
+            _ => None,
+        }
+    }
+
+    /// Returns the family, if this is a family record.
+    #[must_use]
+    pub fn as_family(&self) -> Option<&'a Family> {
+        match self {
+            RecordRef::Family(record) => Some(record),
+            _ => None,
+        }
+    }
+}
+
 /// The main data structure for parsed GEDCOM data.
 ///
 /// This contains all the parsed records from a GEDCOM file: individuals and
@@ -76,7 +150,7 @@ pub struct SourceCitationStats {
 ///
 /// This structure supports both GEDCOM 5.5.1 and GEDCOM 7.0 files:
 /// - `submissions` are only present in GEDCOM 5.5.1 files
-/// - `shared_notes` are only present in GEDCOM 7.0 files
+/// - `shared_notes` hold GEDCOM 7.0 `SNOTE` records and GEDCOM 5.5.1 `NOTE` records
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct GedcomData {
@@ -96,7 +170,10 @@ pub struct GedcomData {
     pub sources: Vec<Source>,
     /// A multimedia asset linked to a fact
     pub multimedia: Vec<Multimedia>,
-    /// Shared notes that can be referenced by multiple structures (GEDCOM 7.0 only)
+    /// Shared notes that can be referenced by multiple structures
+    ///
+    /// These are the level-0 `SNOTE` records of GEDCOM 7.0 and the level-0 `NOTE`
+    /// records of GEDCOM 5.5.1.
     ///
     /// A shared note record may be pointed to by multiple other structures.
     /// Shared notes should only be used if editing the note in one place
@@ -232,8 +309,8 @@ impl GedcomData {
             // Direct citations on the individual
             stats.on_individuals += individual.source.len();
 
-            // Citations on name
-            if let Some(ref name) = individual.name {
+            // Citations on names
+            for name in &individual.names {
                 stats.on_names += name.source.len();
             }
 
@@ -306,6 +383,9 @@ impl GedcomData {
 
     /// Finds an individual by their cross-reference ID (xref).
     ///
+    /// Like every `find_*` lookup, this accepts an [`Xref`] or a string, with or without the
+    /// surrounding at-signs.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -317,12 +397,13 @@ impl GedcomData {
     ///
     /// let individual = data.find_individual("@I1@");
     /// assert!(individual.is_some());
+    /// assert_eq!(data.find_individual("I1"), individual);
     /// ```
     #[must_use]
     pub fn find_individual(&self, xref: &str) -> Option<&Individual> {
         self.individuals
             .iter()
-            .find(|i| i.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|i| i.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a family by their cross-reference ID (xref).
@@ -343,7 +424,7 @@ impl GedcomData {
     pub fn find_family(&self, xref: &str) -> Option<&Family> {
         self.families
             .iter()
-            .find(|f| f.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|f| f.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a source by their cross-reference ID (xref).
@@ -351,7 +432,7 @@ impl GedcomData {
     pub fn find_source(&self, xref: &str) -> Option<&Source> {
         self.sources
             .iter()
-            .find(|s| s.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|s| s.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a repository by their cross-reference ID (xref).
@@ -359,7 +440,7 @@ impl GedcomData {
     pub fn find_repository(&self, xref: &str) -> Option<&Repository> {
         self.repositories
             .iter()
-            .find(|r| r.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|r| r.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a multimedia record by their cross-reference ID (xref).
@@ -367,7 +448,7 @@ impl GedcomData {
     pub fn find_multimedia(&self, xref: &str) -> Option<&Multimedia> {
         self.multimedia
             .iter()
-            .find(|m| m.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|m| m.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a submitter by their cross-reference ID (xref).
@@ -375,17 +456,125 @@ impl GedcomData {
     pub fn find_submitter(&self, xref: &str) -> Option<&Submitter> {
         self.submitters
             .iter()
-            .find(|s| s.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|s| s.xref.as_ref().is_some_and(|x| x.matches(xref)))
     }
 
     /// Finds a shared note by their cross-reference ID (xref).
     ///
-    /// This is only relevant for GEDCOM 7.0 files.
+    /// This covers both GEDCOM 7.0 `SNOTE` records and GEDCOM 5.5.1 `NOTE` records.
     #[must_use]
     pub fn find_shared_note(&self, xref: &str) -> Option<&SharedNote> {
         self.shared_notes
             .iter()
-            .find(|n| n.xref.as_ref().is_some_and(|x| x == xref))
+            .find(|n| n.xref.as_ref().is_some_and(|x| x.matches(xref)))
+    }
+
+    /// Returns an [`XrefAllocator`] that mints identifiers not used by any record.
+    ///
+    /// Every record xref in the dataset is reserved, so identifiers minted for new records
+    /// never collide with existing ones.
+    #[must_use]
+    pub fn xref_allocator(&self) -> XrefAllocator {
+        let mut allocator = XrefAllocator::new();
+        let xrefs = self
+            .submitters
+            .iter()
+            .filter_map(|r| r.xref.as_ref())
+            .chain(self.submissions.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.individuals.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.families.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.repositories.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.sources.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.multimedia.iter().filter_map(|r| r.xref.as_ref()))
+            .chain(self.shared_notes.iter().filter_map(|r| r.xref.as_ref()));
+        for xref in xrefs {
+            allocator.reserve(xref);
+        }
+        allocator
+    }
+
+    /// Returns every record that carries at least one external identifier, in record order.
+    ///
+    /// Records are visited by kind: individuals, families, sources, repositories, multimedia,
+    /// shared notes and submitters.
+    pub fn records_with_external_ids(&self) -> impl Iterator<Item = RecordRef<'_>> {
+        let individuals = self.individuals.iter().map(RecordRef::Individual);
+        let families = self.families.iter().map(RecordRef::Family);
+        let sources = self.sources.iter().map(RecordRef::Source);
+        let repositories = self.repositories.iter().map(RecordRef::Repository);
+        let multimedia = self.multimedia.iter().map(RecordRef::Multimedia);
+        let shared_notes = self.shared_notes.iter().map(RecordRef::SharedNote);
+        let submitters = self.submitters.iter().map(RecordRef::Submitter);
+        individuals
+            .chain(families)
+            .chain(sources)
+            .chain(repositories)
+            .chain(multimedia)
+            .chain(shared_notes)
+            .chain(submitters)
+            .filter(|record| !record.external_ids().is_empty())
+    }
+
+    /// Finds the first record with the external identifier `id` issued by the authority
+    /// `type_uri` (the `EXID.TYPE` URI).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::Gedcom;
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n1 EXID LZDP-V3V\n\
+    ///     2 TYPE https://www.familysearch.org/tree/person/\n0 TRLR";
+    /// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+    ///
+    /// let record = data
+    ///     .find_by_external_id("https://www.familysearch.org/tree/person/", "LZDP-V3V")
+    ///     .unwrap();
+    /// assert_eq!(record.xref(), Some("@I1@"));
+    /// ```
+    #[must_use]
+    pub fn find_by_external_id(&self, type_uri: &str, id: &str) -> Option<RecordRef<'_>> {
+        self.records_with_external_ids().find(|record| {
+            record
+                .external_ids()
+                .iter()
+                .any(|exid| exid.matches(type_uri, id))
+        })
+    }
+
+    /// Resolves a note pointer such as `1 NOTE @N1@` to the shared note it refers to.
+    ///
+    /// Returns `None` for inline notes and for pointers without a matching record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::Gedcom;
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NOTE @N1@\n0 @N1@ NOTE Shared text\n0 TRLR";
+    /// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+    ///
+    /// let note = &data.individuals[0].notes[0];
+    /// assert_eq!(data.resolve_note(note).unwrap().text, "Shared text");
+    /// ```
+    #[must_use]
+    pub fn resolve_note(&self, note: &Note) -> Option<&SharedNote> {
+        note.shared_note
+            .as_deref()
+            .and_then(|xref| self.find_shared_note(xref))
+    }
+
+    /// Returns the text of a note, following shared note pointers.
+    ///
+    /// Inline notes return their own value; pointers return the text of the shared note
+    /// they refer to, or `None` if the record is missing.
+    #[must_use]
+    pub fn note_text<'a>(&'a self, note: &'a Note) -> Option<&'a str> {
+        if note.is_shared() {
+            self.resolve_note(note).map(|shared| shared.text.as_str())
+        } else {
+            note.value.as_deref()
+        }
     }
 
     /// Gets the families where an individual is a spouse/partner.
@@ -407,8 +596,8 @@ impl GedcomData {
         self.families
             .iter()
             .filter(|f| {
-                f.individual1.as_ref().is_some_and(|x| x == individual_xref)
-                    || f.individual2.as_ref().is_some_and(|x| x == individual_xref)
+                f.individual1().is_some_and(|x| x.matches(individual_xref))
+                    || f.individual2().is_some_and(|x| x.matches(individual_xref))
             })
             .collect()
     }
@@ -418,7 +607,7 @@ impl GedcomData {
     pub fn get_families_as_child(&self, individual_xref: &str) -> Vec<&Family> {
         self.families
             .iter()
-            .filter(|f| f.children.iter().any(|c| c == individual_xref))
+            .filter(|f| f.children.iter().any(|c| c.xref.matches(individual_xref)))
             .collect()
     }
 
@@ -428,7 +617,7 @@ impl GedcomData {
         family
             .children
             .iter()
-            .filter_map(|xref| self.find_individual(xref))
+            .filter_map(|child| self.find_individual(&child.xref))
             .collect()
     }
 
@@ -436,12 +625,12 @@ impl GedcomData {
     #[must_use]
     pub fn get_parents(&self, family: &Family) -> Vec<&Individual> {
         let mut parents = Vec::new();
-        if let Some(ref xref) = family.individual1 {
+        if let Some(xref) = family.individual1() {
             if let Some(ind) = self.find_individual(xref) {
                 parents.push(ind);
             }
         }
-        if let Some(ref xref) = family.individual2 {
+        if let Some(xref) = family.individual2() {
             if let Some(ind) = self.find_individual(xref) {
                 parents.push(ind);
             }
@@ -453,29 +642,23 @@ impl GedcomData {
     #[must_use]
     pub fn get_spouse(&self, individual_xref: &str, family: &Family) -> Option<&Individual> {
         if family
-            .individual1
-            .as_ref()
-            .is_some_and(|x| x == individual_xref)
+            .individual1()
+            .is_some_and(|x| x.matches(individual_xref))
         {
-            family
-                .individual2
-                .as_ref()
-                .and_then(|x| self.find_individual(x))
+            family.individual2().and_then(|x| self.find_individual(x))
         } else if family
-            .individual2
-            .as_ref()
-            .is_some_and(|x| x == individual_xref)
+            .individual2()
+            .is_some_and(|x| x.matches(individual_xref))
         {
-            family
-                .individual1
-                .as_ref()
-                .and_then(|x| self.find_individual(x))
+            family.individual1().and_then(|x| self.find_individual(x))
         } else {
             None
         }
     }
 
-    /// Searches for individuals whose name contains the given string (case-insensitive).
+    /// Searches for individuals with any name containing the given string (case-insensitive).
+    ///
+    /// Every `NAME` of an individual is searched, so a married name or an alias matches too.
     ///
     /// # Example
     ///
@@ -495,7 +678,7 @@ impl GedcomData {
         self.individuals
             .iter()
             .filter(|i| {
-                i.name.as_ref().is_some_and(|name| {
+                i.names.iter().any(|name| {
                     name.value
                         .as_ref()
                         .is_some_and(|v| v.to_lowercase().contains(&query_lower))
@@ -588,82 +771,159 @@ impl GedcomData {
 impl Parser for GedcomData {
     /// Parses GEDCOM tokens into the data structure.
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
+        let mut xref = None;
+        while self.parse_record(tokenizer, level, &mut xref)? {}
+        Ok(())
+    }
+}
+
+impl GedcomData {
+    /// Parses GEDCOM tokens into the data structure, recovering from errors.
+    ///
+    /// Whenever a record fails to parse, a [`Diagnostic`] is recorded, the offending record
+    /// is dropped and parsing resumes at the next level-0 record. The tokenizer must be
+    /// positioned before the first token.
+    #[must_use]
+    pub fn parse_with_diagnostics(tokenizer: &mut Tokenizer<'_>) -> (GedcomData, Vec<Diagnostic>) {
+        let mut data = GedcomData::default();
+        let mut diagnostics = Vec::new();
+
+        let mut result = tokenizer.next_token();
         loop {
-            let Token::Level(current_level) = tokenizer.current_token else {
-                if tokenizer.current_token == Token::EOF {
-                    // Accept EOF-terminated files (missing TRLR).
-                    break;
+            let mut xref = None;
+            match result.and_then(|()| data.parse_record(tokenizer, 0, &mut xref)) {
+                Ok(true) => result = Ok(()),
+                Ok(false) => break,
+                Err(error) => {
+                    let line = tokenizer.line as usize;
+                    let (line, column) = match error.line() {
+                        Some(error_line) if error_line != line => (Some(error_line), None),
+                        _ => (Some(line), Some(tokenizer.column() as usize)),
+                    };
+                    diagnostics.push(Diagnostic {
+                        severity: Severity::Error,
+                        line,
+                        column,
+                        xref,
+                        error,
+                    });
+                    tokenizer.skip_to_next_record();
+                    result = Ok(());
                 }
-                return Err(GedcomError::ParseError {
+            }
+        }
+
+        if tokenizer.current_token == Token::EOF {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                line: Some(tokenizer.line as usize),
+                column: None,
+                xref: None,
+                error: GedcomError::ParseError {
                     line: tokenizer.line,
-                    message: format!(
-                        "Expected Level, found {token:?}",
-                        token = tokenizer.current_token
-                    ),
-                });
-            };
+                    message: "Missing TRLR record at end of file".to_string(),
+                },
+            });
+        }
 
-            tokenizer.next_token()?;
+        (data, diagnostics)
+    }
 
-            let mut pointer: Option<String> = None;
-            if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
-                tokenizer.next_token()?;
+    /// Parses a single level-0 record, storing its xref (if any) in `xref`.
+    ///
+    /// Returns `false` once the trailer or the end of input has been reached.
+    fn parse_record(
+        &mut self,
+        tokenizer: &mut Tokenizer<'_>,
+        level: u8,
+        xref: &mut Option<Xref>,
+    ) -> Result<bool, GedcomError> {
+        let Token::Level(current_level) = tokenizer.current_token else {
+            if tokenizer.current_token == Token::EOF {
+                // Accept EOF-terminated files (missing TRLR).
+                return Ok(false);
             }
+            return Err(GedcomError::ParseError {
+                line: tokenizer.line,
+                message: format!(
+                    "Expected Level, found {token:?}",
+                    token = tokenizer.current_token
+                ),
+            });
+        };
+
+        tokenizer.next_token()?;
+
+        let mut pointer: Option<Xref> = None;
+        if let Token::Pointer(ptr) = &tokenizer.current_token {
+            pointer = Some(Xref::from(ptr.as_ref()));
+            xref.clone_from(&pointer);
+            tokenizer.next_token()?;
+        }
 
-            if let Token::Tag(tag) = &tokenizer.current_token {
-                match tag.as_ref() {
-                    "HEAD" => self.header = Some(Header::new(tokenizer, level)?),
-                    "FAM" => self.add_family(Family::new(tokenizer, level, pointer)?),
-                    "INDI" => {
-                        self.add_individual(Individual::new(tokenizer, current_level, pointer)?);
-                    }
-                    "REPO" => {
-                        self.add_repository(Repository::new(tokenizer, current_level, pointer)?);
+        if let Token::Tag(tag) = &tokenizer.current_token {
+            match tag.as_ref() {
+                "HEAD" => {
+                    let header = Header::new(tokenizer, level)?;
+                    if let Some(version) = header.version() {
+                        tokenizer.set_version(GedcomVersion::from_version_str(version));
                     }
-                    "SOUR" => self.add_source(Source::new(tokenizer, current_level, pointer)?),
-                    "SUBN" => self.add_submission(Submission::new(tokenizer, level, pointer)?),
-                    "SUBM" => self.add_submitter(Submitter::new(tokenizer, level, pointer)?),
-                    "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level, pointer)?),
-                    // GEDCOM 5.1: NOTE_RECORD
-                    // is similar to
-                    // GEDCOM 7.0: Shared note record SNOTE
-                    "NOTE" | "SNOTE" => {
-                        self.add_shared_note(SharedNote::new(tokenizer, level, pointer)?);
-                    }
-                    // Trailer is optional in the wild; allow EOF-terminated files.
-                    "TRLR" => break,
-                    _ => {
+                    self.header = Some(header);
+                }
+                "FAM" => self.add_family(Family::new(tokenizer, level, pointer)?),
+                "INDI" => {
+                    self.add_individual(Individual::new(tokenizer, current_level, pointer)?);
+                }
+                "REPO" => {
+                    self.add_repository(Repository::new(tokenizer, current_level, pointer)?);
+                }
+                "SOUR" => self.add_source(Source::new(tokenizer, current_level, pointer)?),
+                "SUBN" => self.add_submission(Submission::new(tokenizer, level, pointer)?),
+                "SUBM" => self.add_submitter(Submitter::new(tokenizer, level, pointer)?),
+                "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level, pointer)?),
+                // GEDCOM 5.1: NOTE_RECORD
+                // is similar to
+                // GEDCOM 7.0: Shared note record SNOTE
+                "NOTE" | "SNOTE" => {
+                    self.add_shared_note(SharedNote::new(tokenizer, level, pointer)?);
+                }
+                // Trailer is optional in the wild; allow EOF-terminated files.
+                "TRLR" => return Ok(false),
+                _ => {
+                    if !tokenizer.config().ignore_unknown_tags {
                         return Err(GedcomError::ParseError {
                             line: tokenizer.line,
                             message: format!("Unhandled tag {tag}"),
-                        })
+                        });
                     }
+                    let tag_clone = tag.clone();
+                    handle_unknown_tag(tokenizer, &tag_clone, level)?;
                 }
+            }
 
-                // If we hit EOF after a record (i.e., missing TRLR), stop gracefully.
-                if tokenizer.current_token == Token::EOF {
-                    break;
-                }
-            } else if let Token::CustomTag(tag) = &tokenizer.current_token {
-                let tag_clone = tag.clone();
-                self.add_custom_data(UserDefinedTag::new(tokenizer, level + 1, &tag_clone)?);
-                // self.add_custom_data(parse_custom_tag(tokenizer, tag_clone));
-                while tokenizer.current_token != Token::Level(level) {
-                    tokenizer.next_token()?;
-                }
-            } else if tokenizer.current_token == Token::EOF {
-                // Accept files without a TRLR.
-                break;
-            } else {
-                return Err(GedcomError::ParseError {
-                    line: tokenizer.line,
-                    message: format!("Unhandled token {:?}", tokenizer.current_token),
-                });
+            // If we hit EOF after a record (i.e., missing TRLR), stop gracefully.
+            Ok(tokenizer.current_token != Token::EOF)
+        } else if let Token::CustomTag(tag) = &tokenizer.current_token {
+            let tag_clone = tag.clone();
+            let custom = UserDefinedTag::new(tokenizer, level, &tag_clone)?;
+            if !tokenizer.config().ignore_unknown_tags {
+                self.add_custom_data(custom);
             }
+            while !matches!(tokenizer.current_token, Token::EOF)
+                && tokenizer.current_token != Token::Level(level)
+            {
+                tokenizer.next_token()?;
+            }
+            Ok(tokenizer.current_token != Token::EOF)
+        } else if tokenizer.current_token == Token::EOF {
+            // Accept files without a TRLR.
+            Ok(false)
+        } else {
+            Err(GedcomError::ParseError {
+                line: tokenizer.line,
+                message: format!("Unhandled token {:?}", tokenizer.current_token),
+            })
         }
-
-        Ok(())
     }
 }
 
@@ -687,7 +947,7 @@ mod tests {
 
         assert_eq!(data.shared_notes.len(), 1);
         let note = &data.shared_notes[0];
-        assert_eq!(note.xref, Some("@N1@".to_string()));
+        assert_eq!(note.xref, Some("@N1@".into()));
         assert_eq!(note.text, "This is a shared note.");
     }
 
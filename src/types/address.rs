@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::UserDefinedTag,
     GedcomError,
@@ -51,7 +51,7 @@ impl Parser for Address {
             tokenizer.next_token()?;
         }
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "CONT" | "CONC" => {
                     value.push('\n');
@@ -64,12 +64,9 @@ impl Parser for Address {
                 "STAE" => self.state = Some(tokenizer.take_line_value()?),
                 "POST" => self.post = Some(tokenizer.take_line_value()?),
                 "CTRY" => self.country = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -1,7 +1,11 @@
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parser::parse_subset, tokenizer::Tokenizer, GedcomError};
+use crate::{
+    parser::{handle_unknown_tag, parse_subset},
+    tokenizer::Tokenizer,
+    GedcomError,
+};
 
 /// The age of the individual at the time an event occurred, or the age listed in a document.
 ///
@@ -95,12 +99,17 @@ impl Age {
         };
 
         parse_subset(tokenizer, level, |tag, handler| {
-            if tag == "PHRASE" {
-                if let Age::Numeric { ref mut phrase, .. } = age {
-                    *phrase = Some(handler.take_line_value()?);
+            match tag {
+                "PHRASE" => {
+                    let value = handler.take_line_value()?;
+                    // Keyword ages have no phrase of their own; the keyword is the phrase.
+                    if let Age::Numeric { ref mut phrase, .. } = age {
+                        *phrase = Some(value);
+                    }
                 }
+                _ => return handle_unknown_tag(handler, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         })?;
 
         Ok(age)
@@ -337,6 +346,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_unknown_substructure() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 BIRT\n2 AGE 25y\n3 FOOI x\n\
+                      1 NAME Test /Person/\n0 @F1@ FAM\n1 MARR\n2 HUSB\n3 AGE 25y\n4 FOOI x\n\
+                      1 HUSB @I1@\n0 TRLR";
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+        assert_eq!(data.individuals[0].names.len(), 1);
+        assert!(data.families[0].individual1.is_some());
+    }
+
+    #[test]
+    fn test_parse_keyword_with_phrase() {
+        let sample = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 DEAT Y\n2 AGE CHILD\n\
+                      3 PHRASE Young\n2 PLAC Paris\n0 TRLR";
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+        let event = &data.individuals[0].events[0];
+        assert_eq!(event.age, Some(Age::Child));
+        assert!(event.place.is_some());
+    }
+
     #[test]
     fn test_display_roundtrip() {
         let cases = ["CHILD", "INFANT", "STILLBORN", "75y 3m", "> 80y", "2w 3d"];
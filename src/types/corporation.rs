@@ -1,7 +1,7 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::address::Address,
+    types::{address::Address, custom::UserDefinedTag},
     GedcomError,
 };
 #[cfg(feature = "json")]
@@ -24,6 +24,8 @@ pub struct Corporation {
     pub fax: Option<String>,
     /// tag: WWW
     pub website: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Corporation {
@@ -44,22 +46,19 @@ impl Parser for Corporation {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "ADDR" => self.address = Some(Address::new(tokenizer, level + 1)?),
                 "PHON" => self.phone = Some(tokenizer.take_line_value()?),
                 "EMAIL" => self.email = Some(tokenizer.take_line_value()?),
                 "FAX" => self.fax = Some(tokenizer.take_line_value()?),
                 "WWW" => self.website = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
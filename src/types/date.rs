@@ -4,8 +4,9 @@ pub mod change_date;
 pub mod calendar;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
     GedcomError,
 };
 
@@ -33,6 +34,8 @@ pub struct Date {
     /// This is used when the structured date value doesn't capture
     /// the original wording of the date.
     pub phrase: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Date {
@@ -82,6 +85,7 @@ impl Date {
     ///     value: Some("@#DJULIAN@ 15 MAR 1582".to_string()),
     ///     time: None,
     ///     phrase: None,
+    ///     custom_data: Vec::new(),
     /// };
     /// assert_eq!(date.calendar(), Some(Calendar::Julian));
     /// # }
@@ -113,6 +117,7 @@ impl Date {
     ///     value: Some("@#DJULIAN@ 15 MAR 1582".to_string()),
     ///     time: None,
     ///     phrase: None,
+    ///     custom_data: Vec::new(),
     /// };
     /// assert_eq!(date.value_without_calendar(), Some("15 MAR 1582".to_string()));
     /// ```
@@ -146,6 +151,7 @@ impl Date {
     ///     value: Some("15 MAR 1820".to_string()),
     ///     time: Some("12:34:56".to_string()),
     ///     phrase: None,
+    ///     custom_data: Vec::new(),
     /// };
     /// let parsed = date.parse_datetime()?;
     /// assert_eq!(parsed.year, Some(1820));
@@ -197,6 +203,7 @@ impl Date {
     ///     value: Some("@#DJULIAN@ 15 MAR 1582".to_string()),
     ///     time: None,
     ///     phrase: None,
+    ///     custom_data: Vec::new(),
     /// };
     /// let gregorian = date.convert_to(Calendar::Gregorian)?;
     /// assert_eq!(gregorian.value, Some("25 MAR 1582".to_string()));
@@ -212,6 +219,7 @@ impl Date {
             value: Some(converted.to_gedcom_date()),
             time: converted.to_gedcom_time(),
             phrase: self.phrase.clone(),
+            custom_data: self.custom_data.clone(),
         })
     }
 
@@ -243,27 +251,139 @@ impl Date {
             value: Some(parsed.to_gedcom_date()),
             time: parsed.to_gedcom_time().or(self.time.clone()),
             phrase: self.phrase.clone(),
+            custom_data: self.custom_data.clone(),
         })
     }
 }
 
+/// Gregorian and Julian month codes.
+const GREGORIAN_MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Hebrew month codes.
+const HEBREW_MONTHS: [&str; 13] = [
+    "TSH", "CSH", "KSL", "TVT", "SHV", "ADR", "ADS", "NSN", "IYR", "SVN", "TMZ", "AAV", "ELL",
+];
+
+/// French Republican month codes.
+const FRENCH_MONTHS: [&str; 13] = [
+    "VEND", "BRUM", "FRIM", "NIVO", "PLUV", "VENT", "GERM", "FLOR", "PRAI", "MESS", "THER", "FRUC",
+    "COMP",
+];
+
+/// Checks a DATE payload against the GEDCOM 5.5.1 and 7.0 date grammars.
+///
+/// Accepts exact dates, approximations (`ABT`, `CAL`, `EST`), ranges (`BEF`, `AFT`,
+/// `BET ... AND ...`), periods (`FROM ... TO ...`), interpreted dates (`INT ... (phrase)`),
+/// date phrases in parentheses, and both 5.5.1 calendar escapes (`@#DJULIAN@`) and 7.0
+/// calendar names (`JULIAN`). Keywords and month codes are matched case-insensitively. An
+/// empty payload is accepted because GEDCOM 7.0 allows a DATE that only carries a PHRASE.
+#[must_use]
+pub fn is_valid_date_value(value: &str) -> bool {
+    let trimmed = value.trim();
+    if trimmed.is_empty() || (trimmed.starts_with('(') && trimmed.ends_with(')')) {
+        return true;
+    }
+
+    let upper = trimmed.to_uppercase();
+    let tokens: Vec<&str> = upper.split_whitespace().collect();
+    match tokens[0] {
+        "ABT" | "CAL" | "EST" | "BEF" | "AFT" | "TO" => is_valid_exact_date(&tokens[1..]),
+        "BET" => match tokens.iter().position(|t| *t == "AND") {
+            Some(and) => {
+                is_valid_exact_date(&tokens[1..and]) && is_valid_exact_date(&tokens[and + 1..])
+            }
+            None => false,
+        },
+        "FROM" => match tokens.iter().position(|t| *t == "TO") {
+            Some(to) => {
+                is_valid_exact_date(&tokens[1..to]) && is_valid_exact_date(&tokens[to + 1..])
+            }
+            None => is_valid_exact_date(&tokens[1..]),
+        },
+        "INT" => {
+            let date_part = upper.split('(').next().unwrap_or_default();
+            let date_tokens: Vec<&str> = date_part.split_whitespace().skip(1).collect();
+            is_valid_exact_date(&date_tokens)
+        }
+        _ => is_valid_exact_date(&tokens),
+    }
+}
+
+/// Checks a single `[calendar] [[day] month] year [epoch]` date.
+fn is_valid_exact_date(tokens: &[&str]) -> bool {
+    let (months, mut rest): (Option<&[&str]>, &[&str]) = match tokens.first() {
+        Some(&"@#DGREGORIAN@" | &"@#DJULIAN@" | &"GREGORIAN" | &"JULIAN") => {
+            (Some(&GREGORIAN_MONTHS), &tokens[1..])
+        }
+        Some(&"@#DHEBREW@" | &"HEBREW") => (Some(&HEBREW_MONTHS), &tokens[1..]),
+        Some(&"FRENCH_R") => (Some(&FRENCH_MONTHS), &tokens[1..]),
+        Some(&"@#DFRENCH") if tokens.get(1) == Some(&"R@") => (Some(&FRENCH_MONTHS), &tokens[2..]),
+        // Unknown calendars and extension calendars have their own month names.
+        Some(t) if t.starts_with("@#D") || t.starts_with('_') => (None, &tokens[1..]),
+        _ => (Some(&GREGORIAN_MONTHS), tokens),
+    };
+
+    if let Some(last) = rest.last() {
+        if matches!(*last, "B.C." | "BC" | "BCE") {
+            rest = &rest[..rest.len() - 1];
+        }
+    }
+
+    let is_month = |t: &str| match months {
+        Some(months) => months.contains(&t),
+        None => !t.is_empty() && t.chars().all(|c| c.is_ascii_alphabetic()),
+    };
+    let is_day = |t: &str| {
+        t.len() <= 2
+            && t.chars().all(|c| c.is_ascii_digit())
+            && t.parse::<u8>().is_ok_and(|d| (1..=31).contains(&d))
+    };
+
+    match rest {
+        [year] => is_valid_year(year),
+        [month, year] => is_month(month) && is_valid_year(year),
+        [day, month, year] => is_day(day) && is_month(month) && is_valid_year(year),
+        _ => false,
+    }
+}
+
+/// Checks a year, allowing the 5.5.1 dual-year form (`1699/00`).
+fn is_valid_year(token: &str) -> bool {
+    let (year, dual) = match token.split_once('/') {
+        Some((year, dual)) => (year, Some(dual)),
+        None => (token, None),
+    };
+    let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    all_digits(year) && dual.is_none_or(|d| d.len() <= 2 && all_digits(d))
+}
+
 impl Parser for Date {
     /// parse handles the DATE tag
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        self.value = Some(tokenizer.take_line_value()?);
+        let line = tokenizer.line;
+        let value = tokenizer.take_line_value()?;
+
+        let config = tokenizer.config();
+        if (config.strict_mode || config.date_validation) && !is_valid_date_value(&value) {
+            return Err(GedcomError::InvalidValueFormat {
+                line: line as usize,
+                value,
+                expected_format: "GEDCOM date value".to_string(),
+            });
+        }
+        self.value = Some(value);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TIME" => self.time = Some(tokenizer.take_line_value()?),
                 "PHRASE" => self.phrase = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
         Ok(())
     }
 }
@@ -339,6 +459,7 @@ mod tests {
             2 DATE 1 APR 1998\n\
             3 TIME 12:34:56.789\n\
             2 NOTE A note\n\
+            2 NOTE Another note\n\
             0 TRLR";
 
         let mut doc = Gedcom::new(sample.chars()).unwrap();
@@ -352,8 +473,9 @@ mod tests {
         assert_eq!(date.value.as_ref().unwrap(), "1 APR 1998");
         assert_eq!(date.time.as_ref().unwrap(), "12:34:56.789");
 
-        let chan_note = chan.note.as_ref().unwrap();
-        assert_eq!(chan_note.value.as_ref().unwrap(), "A note");
+        assert_eq!(chan.notes.len(), 2);
+        assert_eq!(chan.notes[0].value.as_ref().unwrap(), "A note");
+        assert_eq!(chan.notes[1].value.as_ref().unwrap(), "Another note");
     }
 
     #[test]
@@ -401,12 +523,14 @@ mod tests {
                 value: Some("26 JUL 1981".to_string()),
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
 
             let mut b = Date {
                 value: Some("27 JUL 1981".to_string()),
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
 
             assert_eq!(a.partial_cmp_parsed(&b).unwrap(), Some(Ordering::Less));
@@ -416,6 +540,7 @@ mod tests {
                 value: Some("26 JUL 1981".to_string()),
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
 
             assert_eq!(a.partial_cmp_parsed(&b).unwrap(), Some(Ordering::Equal));
@@ -424,6 +549,7 @@ mod tests {
                 value: Some("@#DJULIAN@ 13 JUL 1981".to_string()),
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
             assert_eq!(a.partial_cmp_parsed(&b).unwrap(), Some(Ordering::Equal));
 
@@ -431,6 +557,7 @@ mod tests {
                 value: Some("1981".to_string()),
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
             assert_eq!(a.partial_cmp_parsed(&b).unwrap(), None);
         }
@@ -449,6 +576,7 @@ mod tests {
                     value: Some(input.to_string()),
                     time: None,
                     phrase: None,
+                    custom_data: Vec::new(),
                 };
 
                 assert_eq!(
@@ -462,6 +590,7 @@ mod tests {
                 value: Some("15 mar 1820".to_string()),
                 time: Some("12:34:56".to_string()),
                 phrase: Some("The Ides of March".to_string()),
+                custom_data: Vec::new(),
             };
             let normalized = date.normalize().unwrap();
             assert_eq!(normalized.value.unwrap(), "15 MAR 1820");
@@ -473,6 +602,7 @@ mod tests {
                 value: None,
                 time: None,
                 phrase: None,
+                custom_data: Vec::new(),
             };
             assert!(date.normalize().is_err());
         }
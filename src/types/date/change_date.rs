@@ -2,9 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{date::Date, note::Note},
+    types::{custom::UserDefinedTag, date::Date, note::Note},
     GedcomError,
 };
 
@@ -27,7 +27,10 @@ use crate::{
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct ChangeDate {
     pub date: Option<Date>,
-    pub note: Option<Note>,
+    /// Notes about the change (tag: NOTE, or SNOTE in GEDCOM 7.0).
+    pub notes: Vec<Note>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl ChangeDate {
@@ -47,19 +50,16 @@ impl Parser for ChangeDate {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
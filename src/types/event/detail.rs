@@ -3,18 +3,21 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
         age::Age,
+        custom::UserDefinedTag,
         date::Date,
-        event::{family::FamilyEventDetail, Event},
+        event::{family::FamilyEventDetail, spouse::Spouse, Event},
         gedcom7::SortDate,
         individual::{association::Association, family_link::FamilyLink},
         multimedia::Multimedia,
         note::Note,
         place::Place,
         source::citation::Citation,
+        span::Span,
+        Xref,
     },
     GedcomError,
 };
@@ -47,7 +50,7 @@ pub struct Detail {
     /// - Romanized variations (ROMN)
     /// - Place form
     pub place: Option<Place>,
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     pub family_link: Option<FamilyLink>,
     pub family_event_details: Vec<FamilyEventDetail>,
     /// `event_type` handles the TYPE tag, a descriptive word or phrase used to further classify
@@ -100,6 +103,10 @@ pub struct Detail {
     /// A religious denomination to which a person is affiliated or for which
     /// a record applies.
     pub religion: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this event was parsed from, when span recording is enabled.
+    pub span: Option<Span>,
 }
 
 impl Detail {
@@ -109,6 +116,7 @@ impl Detail {
     ///
     /// This function will return an error if parsing fails.
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8, tag: &str) -> Result<Detail, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut event = Detail {
             event: Event::try_from(tag).map_err(|msg| GedcomError::ParseError {
                 line: tokenizer.line,
@@ -117,7 +125,7 @@ impl Detail {
             value: None,
             date: None,
             place: None,
-            note: None,
+            notes: Vec::new(),
             family_link: None,
             family_event_details: Vec::new(),
             event_type: None,
@@ -130,8 +138,11 @@ impl Detail {
             age: None,
             agency: None,
             religion: None,
+            span: None,
+            custom_data: Vec::new(),
         };
         event.parse(tokenizer, level)?;
+        event.span = tokenizer.span_end(span_start);
         Ok(event)
     }
 
@@ -144,6 +155,15 @@ impl Detail {
         self.citations.push(citation);
     }
 
+    /// Returns the age of a spouse at this family event (tag: AGE under HUSB or WIFE).
+    #[must_use]
+    pub fn spouse_age(&self, spouse: &Spouse) -> Option<&Age> {
+        self.family_event_details
+            .iter()
+            .find(|detail| detail.member.as_ref() == Some(spouse))
+            .and_then(|detail| detail.age.as_ref())
+    }
+
     pub fn add_family_event_detail(&mut self, detail: FamilyEventDetail) {
         self.family_event_details.push(detail);
     }
@@ -184,10 +204,10 @@ impl Parser for Detail {
             tokenizer.next_token()?;
         }
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
-            let mut pointer: Option<String> = None;
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            let mut pointer: Option<Xref> = None;
             if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
+                pointer = Some(Xref::from(xref.as_ref()));
                 tokenizer.next_token()?;
             }
             match tag {
@@ -202,7 +222,7 @@ impl Parser for Detail {
                         tag,
                     )?);
                 }
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "TYPE" => self.event_type = Some(tokenizer.take_line_value()?),
                 "OBJE" => {
                     self.add_multimedia_record(Multimedia::new(tokenizer, level + 1, pointer)?);
@@ -216,16 +236,12 @@ impl Parser for Detail {
                 "AGE" => self.age = Some(Age::new(tokenizer, level + 1)?),
                 "AGNC" => self.agency = Some(tokenizer.take_line_value()?),
                 "RELI" => self.religion = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags instead of failing
-                    // This handles non-standard extensions from various GEDCOM generators
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         if !value.is_empty() {
             self.value = Some(value);
@@ -2,9 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{age::Age, event::spouse::Spouse},
+    types::{age::Age, custom::UserDefinedTag, event::spouse::Spouse},
     GedcomError,
 };
 
@@ -14,6 +14,8 @@ use crate::{
 pub struct FamilyEventDetail {
     pub member: Option<Spouse>,
     pub age: Option<Age>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl FamilyEventDetail {
@@ -30,6 +32,7 @@ impl FamilyEventDetail {
         let mut fe = FamilyEventDetail {
             member: Some(Self::from_tag(tag)?),
             age: None,
+            custom_data: Vec::new(),
         };
         fe.parse(tokenizer, level)?;
         Ok(fe)
@@ -56,19 +59,16 @@ impl Parser for FamilyEventDetail {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "AGE" => self.age = Some(Age::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -0,0 +1,133 @@
+//! External identifiers (`EXID`).
+//!
+//! GEDCOM 7.0 records can carry identifiers assigned by outside authorities, such as a
+//! person ID on `FamilySearch` or a profile ID on `WikiTree`. The `TYPE` substructure names the
+//! authority with a URI.
+//!
+//! ```text
+//! 0 @I1@ INDI
+//! 1 EXID LZDP-V3V
+//! 2 TYPE https://www.familysearch.org/tree/person/
+//! ```
+//!
+//! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#EXID>
+
+use crate::{
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
+    tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
+    GedcomError,
+};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// An external identifier for a structure.
+///
+/// An identifier maintained by an external authority that applies to the
+/// subject of the structure. Unlike `UID` and `REFN`, `EXID` does not
+/// identify a structure; structures with the same `EXID` may have
+/// originated independently.
+///
+/// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#EXID>
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct ExternalId {
+    /// The external identifier value.
+    pub id: String,
+
+    /// The authority issuing the identifier, represented as a URI.
+    ///
+    /// If the authority maintains stable URLs for each identifier,
+    /// appending the `id` to this `type_uri` should yield that URL.
+    pub type_uri: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+}
+
+impl ExternalId {
+    /// Creates a new external identifier.
+    #[must_use]
+    pub fn new(id: &str, type_uri: Option<&str>) -> Self {
+        ExternalId {
+            id: id.to_string(),
+            type_uri: type_uri.map(String::from),
+            custom_data: Vec::new(),
+        }
+    }
+
+    /// Creates a new `ExternalId` from an `EXID` structure in a `Tokenizer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn from_tokenizer(
+        tokenizer: &mut Tokenizer<'_>,
+        level: u8,
+    ) -> Result<ExternalId, GedcomError> {
+        let mut exid = ExternalId::default();
+        exid.parse(tokenizer, level)?;
+        Ok(exid)
+    }
+
+    /// Returns true if this is the identifier `id` issued by the authority `type_uri`.
+    #[must_use]
+    pub fn matches(&self, type_uri: &str, id: &str) -> bool {
+        self.id == id && self.type_uri.as_deref() == Some(type_uri)
+    }
+
+    /// Returns the full URL for this identifier, if possible.
+    ///
+    /// This concatenates the type URI with the identifier.
+    #[must_use]
+    pub fn full_url(&self) -> Option<String> {
+        self.type_uri
+            .as_ref()
+            .map(|uri| format!("{}{}", uri, self.id))
+    }
+}
+
+impl Parser for ExternalId {
+    /// Parses an EXID structure and its TYPE substructure.
+    fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
+        self.id = tokenizer.take_line_value()?;
+
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            match tag {
+                "TYPE" => self.type_uri = Some(tokenizer.take_line_value()?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
+            }
+            Ok(None)
+        };
+
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_id() {
+        let exid = ExternalId::new("12345", Some("https://example.com/person/"));
+        assert_eq!(exid.id, "12345");
+        assert_eq!(
+            exid.type_uri,
+            Some("https://example.com/person/".to_string())
+        );
+        assert_eq!(
+            exid.full_url(),
+            Some("https://example.com/person/12345".to_string())
+        );
+
+        let exid_no_type = ExternalId::new("12345", None);
+        assert_eq!(exid_no_type.full_url(), None);
+
+        assert!(exid.matches("https://example.com/person/", "12345"));
+        assert!(!exid.matches("https://example.com/person/", "54321"));
+        assert!(!exid_no_type.matches("https://example.com/person/", "12345"));
+    }
+}
@@ -1,15 +1,18 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
+        age::Age,
         custom::UserDefinedTag,
         date::change_date::ChangeDate,
-        event::{detail::Detail, util::HasEvents},
+        event::{detail::Detail, spouse::Spouse, util::HasEvents, Event},
+        external_id::ExternalId,
         gedcom7::NonEvent,
         lds::LdsOrdinance,
         multimedia::Multimedia,
         note::Note,
         source::citation::Citation,
+        span::Span,
         Xref,
     },
     GedcomError,
@@ -27,16 +30,28 @@ use serde::{Deserialize, Serialize};
 ///
 /// In GEDCOM 7.0, families can have:
 /// - `NO` - Non-event assertions (e.g., "NO CHIL" means no children)
+/// - `PHRASE` under `HUSB`, `WIFE` and `CHIL`, describing the member in words
+///
+/// A family normally has at most one `HUSB` and one `WIFE`. Further pointers found in the
+/// wild are kept in `additional_individual1` and `additional_individual2` rather than
+/// rejected. [`Family::individual1`] and [`Family::individual2`] return the first pointers.
 ///
 /// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#NO>
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Family {
     pub xref: Option<Xref>,
-    pub individual1: Option<Xref>, // mapped from HUSB
-    pub individual2: Option<Xref>, // mapped from WIFE
+    /// The first individual (tag: HUSB), with its phrase.
+    pub individual1: Option<FamilyMember>,
+    /// The second individual (tag: WIFE), with its phrase.
+    pub individual2: Option<FamilyMember>,
+    /// Further `HUSB` pointers after the first one.
+    pub additional_individual1: Vec<FamilyMember>,
+    /// Further `WIFE` pointers after the first one.
+    pub additional_individual2: Vec<FamilyMember>,
     pub family_event: Vec<Detail>,
-    pub children: Vec<Xref>,
+    /// The children of the family (tag: CHIL), with their phrases.
+    pub children: Vec<FamilyMember>,
     pub num_children: Option<String>,
     pub change_date: Option<ChangeDate>,
     pub events: Vec<Detail>,
@@ -87,7 +102,11 @@ pub struct Family {
     /// External identifiers (tag: EXID, GEDCOM 7.0).
     ///
     /// Identifiers maintained by external authorities that apply to this family.
-    pub external_ids: Vec<String>,
+    pub external_ids: Vec<ExternalId>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Family {
@@ -110,6 +129,7 @@ impl Family {
         level: u8,
         xref: Option<Xref>,
     ) -> Result<Family, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut fam = Family::with_xref(xref);
         fam.children = Vec::new();
         fam.events = Vec::new();
@@ -118,43 +138,80 @@ impl Family {
         fam.notes = Vec::new();
         fam.custom_data = Vec::new();
         fam.parse(tokenizer, level)?;
+        fam.span = tokenizer.span_end(span_start);
         Ok(fam)
     }
 
     /// Sets the first individual (e.g., husband) of the family.
     ///
-    /// # Errors
-    ///
-    /// Returns a `GedcomError::ParseError` if the individual already exists.
-    pub fn set_individual1(&mut self, xref: Xref, line: u32) -> Result<(), GedcomError> {
+    /// If the family already has a first individual, the new one is added to
+    /// `additional_individual1` instead.
+    pub fn set_individual1(&mut self, member: FamilyMember) {
         if self.individual1.is_some() {
-            return Err(GedcomError::ParseError {
-                line,
-                message: "First individual of family already exists.".to_string(),
-            });
+            self.additional_individual1.push(member);
+        } else {
+            self.individual1 = Some(member);
         }
-        self.individual1 = Some(xref);
-        Ok(())
     }
 
     /// Sets the second individual (e.g., wife) of the family.
     ///
-    /// # Errors
-    ///
-    /// Returns a `GedcomError::ParseError` if the individual already exists.
-    pub fn set_individual2(&mut self, xref: Xref, line: u32) -> Result<(), GedcomError> {
+    /// If the family already has a second individual, the new one is added to
+    /// `additional_individual2` instead.
+    pub fn set_individual2(&mut self, member: FamilyMember) {
         if self.individual2.is_some() {
-            return Err(GedcomError::ParseError {
-                line,
-                message: "Second individual of family already exists.".to_string(),
-            });
+            self.additional_individual2.push(member);
+        } else {
+            self.individual2 = Some(member);
         }
-        self.individual2 = Some(xref);
-        Ok(())
     }
 
-    pub fn add_child(&mut self, xref: Xref) {
-        self.children.push(xref);
+    /// Returns the pointer to the first individual (e.g., husband) of the family.
+    #[must_use]
+    pub fn individual1(&self) -> Option<&Xref> {
+        self.individual1.as_ref().map(|member| &member.xref)
+    }
+
+    /// Returns the pointer to the second individual (e.g., wife) of the family.
+    #[must_use]
+    pub fn individual2(&self) -> Option<&Xref> {
+        self.individual2.as_ref().map(|member| &member.xref)
+    }
+
+    /// Returns every `HUSB` pointer, the first one followed by any additional ones.
+    pub fn individuals1(&self) -> impl Iterator<Item = &FamilyMember> {
+        self.individual1.iter().chain(&self.additional_individual1)
+    }
+
+    /// Returns every `WIFE` pointer, the first one followed by any additional ones.
+    pub fn individuals2(&self) -> impl Iterator<Item = &FamilyMember> {
+        self.individual2.iter().chain(&self.additional_individual2)
+    }
+
+    pub fn add_child(&mut self, child: impl Into<FamilyMember>) {
+        self.children.push(child.into());
+    }
+
+    /// Returns the age of a spouse at the given family event, from its `HUSB.AGE` or
+    /// `WIFE.AGE` substructure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{types::event::{spouse::Spouse, Event}, Gedcom};
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @F1@ FAM\n1 MARR\n2 HUSB\n3 AGE 25y\n0 TRLR";
+    /// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+    ///
+    /// let age = data.families[0].spouse_age(&Event::Marriage, &Spouse::Spouse1);
+    /// assert_eq!(age.unwrap().to_string(), "25y");
+    /// ```
+    #[must_use]
+    pub fn spouse_age(&self, event: &Event, spouse: &Spouse) -> Option<&Age> {
+        self.events
+            .iter()
+            .filter(|detail| &detail.event == event)
+            .find_map(|detail| detail.spouse_age(spouse))
     }
 
     pub fn add_event(&mut self, family_event: Detail) {
@@ -185,10 +242,10 @@ impl Parser for Family {
         // skip over FAM tag name
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
-            let mut pointer: Option<String> = None;
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            let mut pointer: Option<Xref> = None;
             if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
+                pointer = Some(Xref::from(xref.as_ref()));
                 tokenizer.next_token()?;
             }
 
@@ -197,13 +254,13 @@ impl Parser for Family {
                 | "MARS" | "RESI" | "EVEN" | "SEP" => {
                     self.add_event(Detail::new(tokenizer, level + 1, tag)?);
                 }
-                "HUSB" => self.set_individual1(tokenizer.take_line_value()?, tokenizer.line)?,
-                "WIFE" => self.set_individual2(tokenizer.take_line_value()?, tokenizer.line)?,
-                "CHIL" => self.add_child(tokenizer.take_line_value()?),
+                "HUSB" => self.set_individual1(FamilyMember::new(tokenizer, level + 1)?),
+                "WIFE" => self.set_individual2(FamilyMember::new(tokenizer, level + 1)?),
+                "CHIL" => self.add_child(FamilyMember::new(tokenizer, level + 1)?),
                 "NCHI" => self.num_children = Some(tokenizer.take_line_value()?),
                 "CHAN" => self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?),
                 "SOUR" => self.add_source(Citation::new(tokenizer, level + 1)?),
-                "NOTE" => self.add_note(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.add_note(Note::new(tokenizer, level + 1)?),
                 "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level + 1, pointer)?),
                 "NO" => self.non_events.push(NonEvent::new(tokenizer, level + 1)?),
                 // LDS Sealing to Spouse ordinance
@@ -223,14 +280,13 @@ impl Parser for Family {
                 // Automated record ID
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
                 // External identifier (GEDCOM 7.0)
-                "EXID" => self.external_ids.push(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -239,6 +295,70 @@ impl Parser for Family {
     }
 }
 
+/// A pointer from a family to one of its members (tag: HUSB, WIFE or CHIL).
+///
+/// # GEDCOM 7.0 Additions
+///
+/// The pointer may carry a `PHRASE` describing the member in words, which is mostly used
+/// with `@VOID@` pointers to people without a record.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct FamilyMember {
+    /// The member's individual record.
+    pub xref: Xref,
+    /// A description of the member (tag: PHRASE, GEDCOM 7.0).
+    pub phrase: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+}
+
+impl FamilyMember {
+    /// Creates a new `FamilyMember` from a `Tokenizer`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if parsing fails.
+    pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<FamilyMember, GedcomError> {
+        let mut member = FamilyMember {
+            xref: tokenizer.take_line_value()?.into(),
+            phrase: None,
+            custom_data: Vec::new(),
+        };
+        member.parse(tokenizer, level)?;
+        Ok(member)
+    }
+}
+
+impl Parser for FamilyMember {
+    fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            match tag {
+                "PHRASE" => self.phrase = Some(tokenizer.take_line_value()?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
+            }
+            Ok(None)
+        };
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
+        Ok(())
+    }
+}
+
+impl From<Xref> for FamilyMember {
+    fn from(xref: Xref) -> Self {
+        FamilyMember {
+            xref,
+            phrase: None,
+            custom_data: Vec::new(),
+        }
+    }
+}
+
+impl PartialEq<&str> for FamilyMember {
+    fn eq(&self, other: &&str) -> bool {
+        self.xref == *other
+    }
+}
+
 impl HasEvents for Family {
     fn add_event(&mut self, event: Detail) {
         let event_type = &event.event;
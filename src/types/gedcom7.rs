@@ -12,9 +12,9 @@
 //! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html>
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{date::Date, note::Note},
+    types::{custom::UserDefinedTag, date::Date, note::Note},
     GedcomError,
 };
 
@@ -52,6 +52,8 @@ pub struct SortDate {
 
     /// A free-text phrase describing the date.
     pub phrase: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl SortDate {
@@ -80,18 +82,15 @@ impl Parser for SortDate {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TIME" => self.time = Some(tokenizer.take_line_value()?),
                 "PHRASE" => self.phrase = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
         Ok(())
     }
 }
@@ -118,6 +117,8 @@ impl Parser for SortDate {
 pub struct CreationDate {
     /// The date the record was created.
     pub date: Option<Date>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl CreationDate {
@@ -137,18 +138,15 @@ impl Parser for CreationDate {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -192,6 +190,8 @@ pub struct Crop {
     /// The width of the crop region.
     /// Expressed as a percentage (0-100) of the image width.
     pub width: Option<f32>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Crop {
@@ -214,6 +214,7 @@ impl Crop {
             left: Some(left),
             height: Some(height),
             width: Some(width),
+            custom_data: Vec::new(),
         }
     }
 
@@ -252,7 +253,7 @@ impl Parser for Crop {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             let value_str = tokenizer.take_line_value()?;
             let value: f32 = value_str
                 .parse()
@@ -267,15 +268,12 @@ impl Parser for Crop {
                 "LEFT" => self.left = Some(value),
                 "HEIGHT" => self.height = Some(value),
                 "WIDTH" => self.width = Some(value),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -312,11 +310,13 @@ pub struct NonEvent {
     /// For example, "BEF 1900" means the event did not occur before 1900.
     pub date: Option<Date>,
 
-    /// A note providing additional context about the non-event.
-    pub note: Option<Note>,
+    /// Notes providing additional context about the non-event.
+    pub notes: Vec<Note>,
 
     /// Source citations supporting the claim that the event did not occur.
     pub source_citations: Vec<crate::types::source::citation::Citation>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl NonEvent {
@@ -366,10 +366,10 @@ impl Parser for NonEvent {
         // The event type is the line value of the NO tag
         self.event_type = tokenizer.take_line_value()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "SOUR" => {
                     self.source_citations
                         .push(crate::types::source::citation::Citation::new(
@@ -377,15 +377,12 @@ impl Parser for NonEvent {
                             level + 1,
                         )?);
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -6,7 +6,7 @@ pub mod source;
 
 use super::UserDefinedTag;
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
         date::Date,
@@ -14,6 +14,8 @@ use crate::{
             encoding::Encoding, meta::HeadMeta, place::HeadPlac, schema::Schema, source::HeadSour,
         },
         note::Note,
+        span::Span,
+        Xref,
     },
     GedcomError,
 };
@@ -75,12 +77,12 @@ pub struct Header {
     ///
     /// A pointer to a submitter record.
     /// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#SUBM>.
-    pub submitter_tag: Option<String>,
+    pub submitter_tag: Option<Xref>,
 
     /// tag: SUBN (GEDCOM 5.5.1 only)
     ///
     /// A pointer to a submission record. This was removed in GEDCOM 7.0.
-    pub submission_tag: Option<String>,
+    pub submission_tag: Option<Xref>,
 
     /// tag: COPR
     ///
@@ -121,6 +123,10 @@ pub struct Header {
 
     /// Custom data (extension tags).
     pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets the header was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Header {
@@ -130,8 +136,10 @@ impl Header {
     ///
     /// This function will return an error if parsing fails.
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<Header, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut header = Header::default();
         header.parse(tokenizer, level)?;
+        header.span = tokenizer.span_end(span_start);
         Ok(header)
     }
 
@@ -195,27 +203,24 @@ impl Parser for Header {
         // skip over HEAD tag name
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "GEDC" => self.gedcom = Some(HeadMeta::new(tokenizer, level + 1)?),
                 "SCHMA" => self.schema = Some(Schema::new(tokenizer, level + 1)?),
                 "SOUR" => self.source = Some(HeadSour::new(tokenizer, level + 1)?),
                 "DEST" => self.destination = Some(tokenizer.take_line_value()?),
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
-                "SUBM" => self.submitter_tag = Some(tokenizer.take_line_value()?),
-                "SUBN" => self.submission_tag = Some(tokenizer.take_line_value()?),
+                "SUBM" => self.submitter_tag = Some(tokenizer.take_line_value()?.into()),
+                "SUBN" => self.submission_tag = Some(tokenizer.take_line_value()?.into()),
                 "FILE" => self.filename = Some(tokenizer.take_line_value()?),
                 "COPR" => self.copyright = Some(tokenizer.take_continued_text(level + 1)?),
                 "CHAR" => self.encoding = Some(Encoding::new(tokenizer, level + 1)?),
                 "LANG" => self.language = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
                 "PLAC" => self.place = Some(HeadPlac::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
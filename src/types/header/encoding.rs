@@ -1,7 +1,7 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::source::citation::Citation,
+    types::{custom::UserDefinedTag, source::citation::Citation},
     GedcomError,
 };
 #[cfg(feature = "json")]
@@ -18,6 +18,8 @@ pub struct Encoding {
     /// Source citations (non-standard but used by some generators)
     /// tag: SOUR
     pub source: Option<Citation>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Encoding {
@@ -38,20 +40,17 @@ impl Parser for Encoding {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "VERS" => self.version = Some(tokenizer.take_line_value()?),
                 // SOUR is non-standard but used by some generators (e.g., Geneanet/GeneWeb)
                 "SOUR" => self.source = Some(Citation::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags instead of failing
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
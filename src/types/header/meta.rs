@@ -1,6 +1,7 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
     GedcomError,
 };
 #[cfg(feature = "json")]
@@ -16,6 +17,8 @@ pub struct HeadMeta {
     pub version: Option<String>,
     /// tag: FORM; see Gedcom 5.5.1 specification, p. 50
     pub form: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl HeadMeta {
@@ -37,7 +40,7 @@ impl Parser for HeadMeta {
         // skip GEDC tag
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "VERS" => self.version = Some(tokenizer.take_line_value()?),
                 "FORM" => {
@@ -52,16 +55,13 @@ impl Parser for HeadMeta {
                     }
                     self.form = Some(form);
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
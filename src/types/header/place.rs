@@ -1,6 +1,7 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
     GedcomError,
 };
 #[cfg(feature = "json")]
@@ -16,6 +17,8 @@ pub struct HeadPlac {
     /// structure. As with PLAC, this shall be ordered from lowest to highest jurisdiction.
     /// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#PLAC-FORM>.
     pub form: Vec<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl HeadPlac {
@@ -54,7 +57,7 @@ impl Parser for HeadPlac {
         // https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#HEAD-PLAC
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "FORM" => {
                     let form = tokenizer.take_line_value()?;
@@ -65,14 +68,11 @@ impl Parser for HeadPlac {
                         self.push_jurisdictional_title(v.to_string());
                     }
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
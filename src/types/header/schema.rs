@@ -16,7 +16,7 @@
 //! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#SCHMA>
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::custom::UserDefinedTag,
     GedcomError,
@@ -208,7 +208,7 @@ impl Parser for Schema {
         // Skip over SCHMA tag name
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TAG" => {
                     let payload = tokenizer.take_line_value()?;
@@ -216,12 +216,9 @@ impl Parser for Schema {
                         self.tag_definitions.push(definition);
                     }
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
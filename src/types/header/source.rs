@@ -4,9 +4,9 @@ pub mod data;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{corporation::Corporation, header::source::data::HeadSourData},
+    types::{corporation::Corporation, custom::UserDefinedTag, header::source::data::HeadSourData},
     GedcomError,
 };
 
@@ -26,6 +26,8 @@ pub struct HeadSour {
     pub corporation: Option<Corporation>,
     /// tag: DATA
     pub data: Option<HeadSourData>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl HeadSour {
@@ -46,21 +48,18 @@ impl Parser for HeadSour {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "VERS" => self.version = Some(tokenizer.take_line_value()?),
                 "NAME" => self.name = Some(tokenizer.take_line_value()?),
                 "CORP" => self.corporation = Some(Corporation::new(tokenizer, level + 1)?),
                 "DATA" => self.data = Some(HeadSourData::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
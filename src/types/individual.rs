@@ -5,12 +5,13 @@ pub mod gender;
 pub mod name;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
         custom::UserDefinedTag,
         date::change_date::ChangeDate,
         event::{detail::Detail, util::HasEvents},
+        external_id::ExternalId,
         gedcom7::NonEvent,
         individual::{
             association::Association,
@@ -23,6 +24,7 @@ use crate::{
         multimedia::Multimedia,
         note::Note,
         source::citation::Citation,
+        span::Span,
         Xref,
     },
     GedcomError,
@@ -46,7 +48,12 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Individual {
     pub xref: Option<Xref>,
-    pub name: Option<Name>,
+    /// Names of the individual (tag: NAME), in the order they appear.
+    ///
+    /// An individual can have several names, such as a birth name, a married name, an alias
+    /// or a religious name. The first name is the preferred one; see
+    /// [`primary_name`](Self::primary_name).
+    pub names: Vec<Name>,
     pub sex: Option<Gender>,
     pub families: Vec<FamilyLink>,
     pub attributes: Vec<AttributeDetail>,
@@ -54,7 +61,7 @@ pub struct Individual {
     pub events: Vec<Detail>,
     pub multimedia: Vec<Multimedia>,
     pub last_updated: Option<String>,
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     pub change_date: Option<ChangeDate>,
     pub custom_data: Vec<Box<UserDefinedTag>>,
     /// Non-event assertions for GEDCOM 7.0.
@@ -128,7 +135,11 @@ pub struct Individual {
     /// External identifiers (tag: EXID, GEDCOM 7.0).
     ///
     /// Identifiers maintained by external authorities that apply to this individual.
-    pub external_ids: Vec<String>,
+    pub external_ids: Vec<ExternalId>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Individual {
@@ -150,8 +161,10 @@ impl Individual {
         level: u8,
         xref: Option<Xref>,
     ) -> Result<Individual, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut indi = Individual::with_xref(xref);
         indi.parse(tokenizer, level)?;
+        indi.span = tokenizer.span_end(span_start);
         Ok(indi)
     }
 
@@ -168,6 +181,37 @@ impl Individual {
         }
     }
 
+    pub fn add_name(&mut self, name: Name) {
+        self.names.push(name);
+    }
+
+    /// Returns the primary (preferred) name, which is the first `NAME` of the record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::Gedcom;
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME Mary /Smith/\n\
+    ///     1 NAME Mary /Jones/\n2 TYPE married\n0 TRLR";
+    /// let mut gedcom = Gedcom::new(source.chars()).unwrap();
+    /// let data = gedcom.parse_data().unwrap();
+    ///
+    /// let individual = &data.individuals[0];
+    /// assert_eq!(individual.names.len(), 2);
+    /// assert_eq!(individual.primary_name().unwrap().value.as_deref(), Some("Mary /Smith/"));
+    /// ```
+    #[must_use]
+    pub fn primary_name(&self) -> Option<&Name> {
+        self.names.first()
+    }
+
+    /// Returns a mutable reference to the primary (preferred) name.
+    #[must_use]
+    pub fn primary_name_mut(&mut self) -> Option<&mut Name> {
+        self.names.first_mut()
+    }
+
     pub fn add_source_citation(&mut self, sour: Citation) {
         self.source.push(sour);
     }
@@ -189,7 +233,7 @@ impl Individual {
     // Convenience Methods for Common Data Access (Issue #29)
     // ========================================================================
 
-    /// Gets the full name as a formatted string, removing GEDCOM slashes.
+    /// Gets the full primary name as a formatted string, removing GEDCOM slashes.
     ///
     /// # Example
     ///
@@ -205,19 +249,19 @@ impl Individual {
     /// ```
     #[must_use]
     pub fn full_name(&self) -> Option<String> {
-        self.name.as_ref().and_then(name::Name::full_name)
+        self.primary_name().and_then(name::Name::full_name)
     }
 
-    /// Gets the given (first) name if available.
+    /// Gets the given (first) name of the primary name if available.
     #[must_use]
     pub fn given_name(&self) -> Option<&str> {
-        self.name.as_ref().and_then(|n| n.given.as_deref())
+        self.primary_name().and_then(|n| n.given.as_deref())
     }
 
-    /// Gets the surname (family name) if available.
+    /// Gets the surname (family name) of the primary name if available.
     #[must_use]
     pub fn surname(&self) -> Option<&str> {
-        self.name.as_ref().and_then(|n| n.surname.as_deref())
+        self.primary_name().and_then(|n| n.surname.as_deref())
     }
 
     /// Checks if the individual is male.
@@ -321,10 +365,10 @@ impl Parser for Individual {
         // skip over INDI tag name
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 // TODO handle xref
-                "NAME" => self.name = Some(Name::new(tokenizer, level + 1)?),
+                "NAME" => self.add_name(Name::new(tokenizer, level + 1)?),
                 "SEX" => self.sex = Some(Gender::new(tokenizer, level + 1)?),
                 "ADOP" | "BIRT" | "BAPM" | "BARM" | "BASM" | "BLES" | "BURI" | "CENS" | "CHR"
                 | "CHRA" | "CONF" | "CREM" | "DEAT" | "EMIG" | "FCOM" | "GRAD" | "IMMI"
@@ -343,7 +387,7 @@ impl Parser for Individual {
                     self.add_source_citation(Citation::new(tokenizer, level + 1)?);
                 }
                 "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level + 1, None)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "NO" => self.non_events.push(NonEvent::new(tokenizer, level + 1)?),
                 // LDS Ordinances (INIL is GEDCOM 7.0 only)
                 "BAPL" | "CONL" | "INIL" | "ENDL" | "SLGC" => {
@@ -369,20 +413,19 @@ impl Parser for Individual {
                 // Ancestral File Number (LDS)
                 "AFN" => self.ancestral_file_number = Some(tokenizer.take_line_value()?),
                 // Alias pointer
-                "ALIA" => self.aliases.push(tokenizer.take_line_value()?),
+                "ALIA" => self.aliases.push(tokenizer.take_line_value()?.into()),
                 // Interest in ancestors
-                "ANCI" => self.ancestor_interest = Some(tokenizer.take_line_value()?),
+                "ANCI" => self.ancestor_interest = Some(tokenizer.take_line_value()?.into()),
                 // Interest in descendants
-                "DESI" => self.descendant_interest = Some(tokenizer.take_line_value()?),
+                "DESI" => self.descendant_interest = Some(tokenizer.take_line_value()?.into()),
                 // External identifier (GEDCOM 7.0)
-                "EXID" => self.external_ids.push(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -412,7 +455,7 @@ mod tests {
         let indi = &data.individuals[0];
         assert_eq!(indi.xref.as_ref().unwrap(), "@PERSON1@");
         assert_eq!(
-            indi.name.as_ref().unwrap().value.as_ref().unwrap(),
+            indi.primary_name().unwrap().value.as_ref().unwrap(),
             "John Doe"
         );
         assert_eq!(indi.sex.as_ref().unwrap().value.to_string(), "Male");
@@ -497,11 +540,46 @@ mod tests {
         let indi = &data.individuals[0];
         assert_eq!(indi.xref.as_ref().unwrap(), "@PERSON1@");
         assert_eq!(
-            indi.name.as_ref().unwrap().value.as_ref().unwrap(),
+            indi.primary_name().unwrap().value.as_ref().unwrap(),
             "John Doe"
         );
     }
 
+    #[test]
+    fn test_parse_multiple_names() {
+        let sample = "\
+           0 HEAD\n\
+           1 GEDC\n\
+           2 VERS 5.5.1\n\
+           0 @I1@ INDI\n\
+           1 NAME Mary /Smith/\n\
+           1 NAME Mary /Jones/\n\
+           2 TYPE married\n\
+           1 NAME Sister /Mary Agnes/\n\
+           2 TYPE religious\n\
+           0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+
+        let indi = &data.individuals[0];
+        let values: Vec<_> = indi
+            .names
+            .iter()
+            .map(|name| name.value.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            ["Mary /Smith/", "Mary /Jones/", "Sister /Mary Agnes/"]
+        );
+        assert_eq!(
+            indi.primary_name().unwrap().value.as_deref(),
+            Some("Mary /Smith/")
+        );
+        assert_eq!(indi.surname(), Some("Smith"));
+        assert_eq!(indi.full_name().as_deref(), Some("Mary Smith"));
+    }
+
     #[test]
     fn test_parse_attribute_detail_record() {
         let sample = "\
@@ -565,8 +643,8 @@ mod tests {
                 .data
                 .as_ref()
                 .unwrap()
-                .text
-                .as_ref()
+                .texts
+                .first()
                 .unwrap()
                 .value
                 .as_ref()
@@ -578,7 +656,7 @@ mod tests {
             "Direct"
         );
         assert_eq!(
-            a_sour.note.as_ref().unwrap().value.as_ref().unwrap(),
+            a_sour.notes.first().unwrap().value.as_ref().unwrap(),
             "A note\nNote continued here. The word TEST should not be broken!"
         );
     }
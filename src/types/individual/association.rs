@@ -2,26 +2,186 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{custom::UserDefinedTag, note::Note, Xref},
+    types::{custom::UserDefinedTag, note::Note, source::citation::Citation, Xref},
     GedcomError,
 };
 
+/// The role of an associated individual (tag: ROLE, GEDCOM 7.0).
+///
+/// GEDCOM 5.5.1 describes the relationship with free text in `RELA` instead; use
+/// [`Role::from_relationship`] to map such text onto this enumeration.
+///
+/// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#enumset-ROLE>
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub enum Role {
+    /// Child
+    Child,
+    /// Religious official in an event exercising authority to perform religious ceremonies
+    Clergy,
+    /// Father
+    Father,
+    /// Friend
+    Friend,
+    /// Godparent or someone acting in that role
+    Godparent,
+    /// Husband
+    Husband,
+    /// Mother
+    Mother,
+    /// A person with several roles, described in the phrase
+    Multiple,
+    /// Neighbor
+    Neighbor,
+    /// Officiator of the event
+    Officiator,
+    /// Parent
+    Parent,
+    /// Spouse
+    Spouse,
+    /// Wife
+    Wife,
+    /// Witness
+    Witness,
+    /// A role not covered by the other values, described in the phrase
+    Other,
+    /// A value outside the standard enumeration, kept as written
+    Custom(String),
+}
+
+impl Role {
+    /// Parses a `ROLE` enumeration value into a `Role`.
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_uppercase().as_str() {
+            "CHIL" => Role::Child,
+            "CLERGY" => Role::Clergy,
+            "FATH" => Role::Father,
+            "FRIEND" => Role::Friend,
+            "GODP" => Role::Godparent,
+            "HUSB" => Role::Husband,
+            "MOTH" => Role::Mother,
+            "MULTIPLE" => Role::Multiple,
+            "NGHBR" => Role::Neighbor,
+            "OFFICIATOR" => Role::Officiator,
+            "PARENT" => Role::Parent,
+            "SPOU" => Role::Spouse,
+            "WIFE" => Role::Wife,
+            "WITN" => Role::Witness,
+            "OTHER" => Role::Other,
+            _ => Role::Custom(value.to_string()),
+        }
+    }
+
+    /// Maps a GEDCOM 5.5.1 `RELA` description such as `Godfather` or `witness` to a role.
+    ///
+    /// Descriptions that match no standard role yield [`Role::Other`]; the original text
+    /// then belongs in the role's `PHRASE`.
+    #[must_use]
+    pub fn from_relationship(description: &str) -> Self {
+        match description.trim().to_lowercase().as_str() {
+            "child" | "son" | "daughter" | "chil" => Role::Child,
+            "clergy" | "priest" | "minister" | "pastor" | "rabbi" | "vicar" | "curate" => {
+                Role::Clergy
+            }
+            "father" | "fath" => Role::Father,
+            "friend" => Role::Friend,
+            "godparent" | "godfather" | "godmother" | "sponsor" | "godp" => Role::Godparent,
+            "husband" | "husb" => Role::Husband,
+            "mother" | "moth" => Role::Mother,
+            "neighbor" | "neighbour" | "nghbr" => Role::Neighbor,
+            "officiator" | "officiant" | "registrar" => Role::Officiator,
+            "parent" => Role::Parent,
+            "spouse" | "spou" => Role::Spouse,
+            "wife" => Role::Wife,
+            "witness" | "witn" => Role::Witness,
+            _ => Role::Other,
+        }
+    }
+
+    /// Returns the GEDCOM 7.0 enumeration value for this role.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Role::Child => "CHIL",
+            Role::Clergy => "CLERGY",
+            Role::Father => "FATH",
+            Role::Friend => "FRIEND",
+            Role::Godparent => "GODP",
+            Role::Husband => "HUSB",
+            Role::Mother => "MOTH",
+            Role::Multiple => "MULTIPLE",
+            Role::Neighbor => "NGHBR",
+            Role::Officiator => "OFFICIATOR",
+            Role::Parent => "PARENT",
+            Role::Spouse => "SPOU",
+            Role::Wife => "WIFE",
+            Role::Witness => "WITN",
+            Role::Other => "OTHER",
+            Role::Custom(s) => s,
+        }
+    }
+
+    /// Returns a human-readable description, as used for a GEDCOM 5.5.1 `RELA` value.
+    #[must_use]
+    pub fn description(&self) -> &str {
+        match self {
+            Role::Child => "Child",
+            Role::Clergy => "Clergy",
+            Role::Father => "Father",
+            Role::Friend => "Friend",
+            Role::Godparent => "Godparent",
+            Role::Husband => "Husband",
+            Role::Mother => "Mother",
+            Role::Multiple => "Multiple",
+            Role::Neighbor => "Neighbor",
+            Role::Officiator => "Officiator",
+            Role::Parent => "Parent",
+            Role::Spouse => "Spouse",
+            Role::Wife => "Wife",
+            Role::Witness => "Witness",
+            Role::Other => "Other",
+            Role::Custom(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Association (tag: ASSO) is an optional pointer to an individual with whom this
 /// individual has some relationship not covered by other standard tags.
+///
+/// GEDCOM 5.5.1 describes the relationship with free text in `RELA`; GEDCOM 7.0 uses an
+/// enumerated `ROLE` with an optional `PHRASE`. Both are kept as parsed, and
+/// [`Association::resolved_role`] gives the role whichever version the data came from.
 /// See GEDCOM 5.5.1 specification, page 58.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Association {
-    /// Reference to associated individual
+    /// Reference to associated individual, or `@VOID@` in GEDCOM 7.0
     pub xref: Xref,
-    /// tag: RELA, relationship to this individual
+    /// tag: PHRASE, a description of the associated individual (GEDCOM 7.0)
+    pub phrase: Option<String>,
+    /// tag: RELA, relationship to this individual (GEDCOM 5.5.1)
     pub relationship: Option<String>,
+    /// tag: ROLE, role of the associated individual (GEDCOM 7.0)
+    pub role: Option<Role>,
+    /// tag: PHRASE under ROLE, a description of the role (GEDCOM 7.0)
+    pub role_phrase: Option<String>,
+    /// Custom tags under ROLE
+    pub role_custom_data: Vec<Box<UserDefinedTag>>,
     /// tag: TYPE, indicator of the type of association
     pub association_type: Option<String>,
+    /// tag: SOUR, citations supporting this association
+    pub source_citations: Vec<Citation>,
     /// tag: NOTE, additional notes about this association
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     /// Custom tags not defined in GEDCOM specification
     pub custom_data: Vec<Box<UserDefinedTag>>,
 }
@@ -34,30 +194,79 @@ impl Association {
     /// This function will return an error if parsing fails.
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<Association, GedcomError> {
         let mut association = Association {
-            xref: tokenizer.take_line_value()?,
+            xref: tokenizer.take_line_value()?.into(),
+            phrase: None,
             relationship: None,
+            role: None,
+            role_phrase: None,
+            role_custom_data: Vec::new(),
             association_type: None,
-            note: None,
+            source_citations: Vec::new(),
+            notes: Vec::new(),
             custom_data: Vec::new(),
         };
         association.parse(tokenizer, level)?;
         Ok(association)
     }
+
+    /// Returns the role of the associated individual, mapping a GEDCOM 5.5.1 `RELA`
+    /// description when no `ROLE` was given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{types::individual::association::Role, Gedcom};
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 ASSO @I2@\n2 RELA Godfather\n0 TRLR";
+    /// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+    ///
+    /// let association = &data.individuals[0].associations[0];
+    /// assert_eq!(association.resolved_role(), Some(Role::Godparent));
+    /// ```
+    #[must_use]
+    pub fn resolved_role(&self) -> Option<Role> {
+        self.role
+            .clone()
+            .or_else(|| self.relationship.as_deref().map(Role::from_relationship))
+    }
+
+    /// Returns the description of the role: the `ROLE.PHRASE` if present, otherwise the
+    /// GEDCOM 5.5.1 `RELA` text.
+    #[must_use]
+    pub fn role_description(&self) -> Option<&str> {
+        self.role_phrase.as_deref().or(self.relationship.as_deref())
+    }
+
+    pub fn add_source_citation(&mut self, citation: Citation) {
+        self.source_citations.push(citation);
+    }
 }
 
 impl Parser for Association {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
+                "PHRASE" => self.phrase = Some(tokenizer.take_line_value()?),
                 "RELA" => self.relationship = Some(tokenizer.take_line_value()?),
-                "TYPE" => self.association_type = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
+                "ROLE" => {
+                    self.role = Some(Role::parse(&tokenizer.take_line_value()?));
+                    let mut phrase = None;
+                    self.role_custom_data =
+                        parse_subset(tokenizer, level + 1, |tag, tokenizer| {
+                            match tag {
+                                "PHRASE" => phrase = Some(tokenizer.take_line_value()?),
+                                _ => return handle_unknown_tag(tokenizer, tag, level + 2),
+                            }
+                            Ok(None)
+                        })?;
+                    self.role_phrase = phrase;
                 }
+                "TYPE" => self.association_type = Some(tokenizer.take_line_value()?),
+                "SOUR" => self.add_source_citation(Citation::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -68,6 +277,7 @@ impl Parser for Association {
 
 #[cfg(test)]
 mod tests {
+    use super::Role;
     use crate::Gedcom;
 
     #[test]
@@ -97,4 +307,50 @@ mod tests {
             "COWORKER"
         );
     }
+
+    #[test]
+    fn test_parse_gedcom_7_association() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 7.0\n\
+            0 @I1@ INDI\n\
+            1 ASSO @VOID@\n\
+            2 PHRASE Mr Stockdale\n\
+            2 ROLE OTHER\n\
+            3 PHRASE Landlord\n\
+            2 SOUR @S1@\n\
+            3 PAGE 12\n\
+            1 BAPM\n\
+            2 ASSO @I2@\n\
+            3 ROLE GODP\n\
+            0 @I2@ INDI\n\
+            0 @S1@ SOUR\n\
+            0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+
+        let association = &data.individuals[0].associations[0];
+        assert!(association.xref.is_void());
+        assert_eq!(association.phrase.as_deref(), Some("Mr Stockdale"));
+        assert_eq!(association.role, Some(Role::Other));
+        assert_eq!(association.role_description(), Some("Landlord"));
+        assert_eq!(association.source_citations[0].xref, "@S1@");
+        assert!(association.custom_data.is_empty());
+
+        let event_association = &data.individuals[0].events[0].associations[0];
+        assert_eq!(event_association.role, Some(Role::Godparent));
+    }
+
+    #[test]
+    fn test_role_from_relationship() {
+        assert_eq!(Role::from_relationship("Godmother"), Role::Godparent);
+        assert_eq!(Role::from_relationship(" witness "), Role::Witness);
+        assert_eq!(Role::from_relationship("Neighbour"), Role::Neighbor);
+        assert_eq!(Role::from_relationship("Landlord"), Role::Other);
+        assert_eq!(Role::parse("witn"), Role::Witness);
+        assert_eq!(Role::parse("_MENTOR"), Role::Custom("_MENTOR".to_string()));
+        assert_eq!(Role::Custom("_MENTOR".to_string()).to_string(), "_MENTOR");
+    }
 }
@@ -2,11 +2,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
-        address::Address, age::Age, date::Date, individual::attribute::IndividualAttribute,
-        note::Note, place::Place, source::citation::Citation,
+        address::Address, age::Age, custom::UserDefinedTag, date::Date,
+        individual::attribute::IndividualAttribute, note::Note, place::Place,
+        source::citation::Citation, span::Span,
     },
     GedcomError,
 };
@@ -33,7 +34,7 @@ pub struct AttributeDetail {
     pub place: Option<Place>,
     pub date: Option<Date>,
     pub sources: Vec<Citation>,
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     /// `attribute_type` handles the TYPE tag, a descriptive word or phrase used to further
     /// classify the parent event or attribute tag. This should be used to define what kind of
     /// identification number or fact classification is being defined.
@@ -58,6 +59,11 @@ pub struct AttributeDetail {
     pub cause: Option<String>,
     /// Responsible agency (tag: AGNC).
     pub agency: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this attribute was parsed from, when span recording is
+    /// enabled.
+    pub span: Option<Span>,
 }
 
 impl AttributeDetail {
@@ -71,21 +77,25 @@ impl AttributeDetail {
         level: u8,
         tag: &str,
     ) -> Result<AttributeDetail, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut attribute = AttributeDetail {
             attribute: Self::from_tag(tag, tokenizer.line)?,
             place: None,
             value: None,
             date: None,
             sources: Vec::new(),
-            note: None,
+            notes: Vec::new(),
             attribute_type: None,
             restriction: None,
             age: None,
             address: None,
             cause: None,
             agency: None,
+            span: None,
+            custom_data: Vec::new(),
         };
         attribute.parse(tokenizer, level)?;
+        attribute.span = tokenizer.span_end(span_start);
         Ok(attribute)
     }
 
@@ -133,28 +143,25 @@ impl Parser for AttributeDetail {
             tokenizer.next_token()?;
         }
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
                 "SOUR" => self.add_source_citation(Citation::new(tokenizer, level + 1)?),
                 "PLAC" => self.place = Some(Place::new(tokenizer, level + 1)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "TYPE" => self.attribute_type = Some(tokenizer.take_continued_text(level + 1)?),
                 "RESN" => self.restriction = Some(tokenizer.take_line_value()?),
                 "AGE" => self.age = Some(Age::new(tokenizer, level + 1)?),
                 "ADDR" => self.address = Some(Address::new(tokenizer, level + 1)?),
                 "CAUS" => self.cause = Some(tokenizer.take_continued_text(level + 1)?),
                 "AGNC" => self.agency = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags instead of failing
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         if !value.is_empty() {
             self.value = Some(value);
@@ -6,7 +6,7 @@ pub mod pedigree;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
         custom::UserDefinedTag,
@@ -47,7 +47,7 @@ pub struct FamilyLink {
     pub pedigree_linkage_type: Option<Pedigree>,
     pub child_linkage_status: Option<ChildLinkStatus>,
     pub adopted_by: Option<AdoptedByWhichParent>,
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
@@ -62,7 +62,7 @@ impl FamilyLink {
         level: u8,
         tag: &str,
     ) -> Result<FamilyLink, GedcomError> {
-        let xref = tokenizer.take_line_value()?;
+        let xref = Xref::from(tokenizer.take_line_value()?);
         let link_type = match tag {
             "FAMC" => FamilyLinkType::Child,
             "FAMS" => FamilyLinkType::Spouse,
@@ -77,7 +77,7 @@ impl FamilyLink {
             pedigree_linkage_type: None,
             child_linkage_status: None,
             adopted_by: None,
-            note: None,
+            notes: Vec::new(),
             custom_data: Vec::new(),
         };
         family_link.parse(tokenizer, level)?;
@@ -163,7 +163,7 @@ impl FamilyLink {
 
 impl Parser for FamilyLink {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "PEDI" => {
                     self.set_pedigree(tokenizer.take_line_value()?.as_str(), tokenizer.line)?;
@@ -172,17 +172,14 @@ impl Parser for FamilyLink {
                     tokenizer.take_line_value()?.as_str(),
                     tokenizer.line,
                 )?,
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "ADOP" => self.set_adopted_by_which_parent(
                     tokenizer.take_line_value()?.as_str(),
                     tokenizer.line,
                 )?,
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
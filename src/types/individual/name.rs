@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{custom::UserDefinedTag, note::Note, source::citation::Citation},
     GedcomError,
@@ -112,6 +112,8 @@ pub struct NameVariation {
 
     /// Nickname in this variation.
     pub nickname: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl NameVariation {
@@ -142,7 +144,7 @@ impl NameVariation {
 
 impl Parser for NameVariation {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TYPE" => self.variation_type = Some(tokenizer.take_line_value()?),
                 "GIVN" => self.given = Some(tokenizer.take_line_value()?),
@@ -151,15 +153,12 @@ impl Parser for NameVariation {
                 "SPFX" => self.surname_prefix = Some(tokenizer.take_line_value()?),
                 "NSFX" => self.suffix = Some(tokenizer.take_line_value()?),
                 "NICK" => self.nickname = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -195,7 +194,7 @@ pub struct Name {
     pub surname_prefix: Option<String>,
 
     /// Note about the name.
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
 
     /// Name suffix (e.g., "Jr.", "III") (tag: NSFX).
     pub suffix: Option<String>,
@@ -298,7 +297,7 @@ impl Parser for Name {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.parse_value(&tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "GIVN" => self.given = Some(tokenizer.take_line_value()?),
                 "NPFX" => self.prefix = Some(tokenizer.take_line_value()?),
@@ -307,7 +306,7 @@ impl Parser for Name {
                 "SURN" => self.surname = Some(tokenizer.take_line_value()?),
                 "NICK" => self.nickname = Some(tokenizer.take_line_value()?),
                 "SOUR" => self.add_source_citation(Citation::new(tokenizer, level + 1)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "TYPE" => {
                     let type_value = tokenizer.take_line_value()?;
                     self.name_type = Some(NameType::parse(&type_value));
@@ -318,12 +317,9 @@ impl Parser for Name {
                 "ROMN" => self
                     .romanized
                     .push(NameVariation::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags instead of failing
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
@@ -351,7 +347,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         name.clone()
     }
 
@@ -416,7 +412,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         assert_eq!(name.name_type, Some(NameType::Maiden));
         assert_eq!(name.given.as_ref().unwrap(), "Mary");
         assert_eq!(name.surname.as_ref().unwrap(), "Smith");
@@ -438,7 +434,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         assert_eq!(name.name_type, Some(NameType::Maiden));
         assert_eq!(name.given.as_ref().unwrap(), "Mary");
         assert_eq!(name.surname.as_ref().unwrap(), "Smith"); // The surname between the slash in
@@ -461,7 +457,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         assert_eq!(name.name_type, Some(NameType::Maiden));
         assert_eq!(name.given.as_ref().unwrap(), "Mary");
         assert_eq!(name.surname, None); // The surname between the slash in NAME
@@ -485,7 +481,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         assert!(name.has_phonetic());
         assert_eq!(name.phonetic.len(), 1);
         assert_eq!(name.phonetic[0].value, "Yamada /Taro/");
@@ -510,7 +506,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let indi = &data.individuals[0];
-        let name = indi.name.as_ref().unwrap();
+        let name = indi.primary_name().unwrap();
         assert!(name.has_romanized());
         assert_eq!(name.romanized.len(), 1);
         assert_eq!(name.romanized[0].value, "Wang /Xiaoming/");
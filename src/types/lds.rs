@@ -24,9 +24,9 @@
 //! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#LDS_INDIVIDUAL_ORDINANCE>
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{date::Date, note::Note, source::citation::Citation},
+    types::{custom::UserDefinedTag, date::Date, note::Note, source::citation::Citation, Xref},
     GedcomError,
 };
 
@@ -233,13 +233,15 @@ pub struct LdsOrdinance {
     /// A reference to the family where this sealing was performed.
     ///
     /// Used with `SLGC` to indicate the family to which the child was sealed.
-    pub family_xref: Option<String>,
+    pub family_xref: Option<Xref>,
 
     /// Notes about this ordinance.
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
 
     /// Source citations for this ordinance.
     pub source_citations: Vec<Citation>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl LdsOrdinance {
@@ -321,7 +323,7 @@ impl Parser for LdsOrdinance {
         // Skip over the ordinance tag
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
                 "TEMP" => self.temple = Some(tokenizer.take_line_value()?),
@@ -329,21 +331,18 @@ impl Parser for LdsOrdinance {
                     let status_str = tokenizer.take_line_value()?;
                     self.status = LdsOrdinanceStatus::parse(&status_str);
                 }
-                "FAMC" => self.family_xref = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "FAMC" => self.family_xref = Some(tokenizer.take_line_value()?.into()),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "SOUR" => {
                     self.source_citations
                         .push(Citation::new(tokenizer, level + 1)?);
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -4,13 +4,16 @@ pub mod link;
 pub mod user;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
+        custom::UserDefinedTag,
         date::change_date::ChangeDate,
+        external_id::ExternalId,
         multimedia::{file::Reference, format::Format, user::UserReferenceNumber},
         note::Note,
         source::citation::Citation,
+        span::Span,
         Xref,
     },
     GedcomError,
@@ -34,18 +37,38 @@ use serde::{Deserialize, Serialize};
 pub struct Multimedia {
     /// Optional reference to link to this submitter
     pub xref: Option<Xref>,
-    pub file: Option<Reference>,
+    /// The file references of this record (tag: FILE), in file order.
+    ///
+    /// Each file is one variant of the same media, such as a scan and its thumbnail.
+    pub files: Vec<Reference>,
     /// The 5.5 spec, page 26, shows FORM as a sub-structure of FILE, but the struct appears as a
     /// sibling in an Ancestry.com export.
     pub form: Option<Format>,
     /// The 5.5 spec, page 26, shows TITL as a sub-structure of FILE, but the struct appears as a
     /// sibling in an Ancestry.com export.
     pub title: Option<String>,
-    pub user_reference_number: Option<UserReferenceNumber>,
+    /// Restriction notice (tag: RESN, GEDCOM 7.0).
+    pub restriction: Option<String>,
+    /// User reference numbers (tag: REFN).
+    pub user_reference_numbers: Vec<UserReferenceNumber>,
+    /// Unique identifiers (tag: UID, GEDCOM 7.0).
+    pub uids: Vec<String>,
+    /// External identifiers (tag: EXID, GEDCOM 7.0).
+    pub external_ids: Vec<ExternalId>,
     pub automated_record_id: Option<String>,
-    pub source_citation: Option<Citation>,
+    /// Source citations (tag: SOUR).
+    pub source_citations: Vec<Citation>,
     pub change_date: Option<ChangeDate>,
-    pub note_structure: Option<Note>,
+    /// The date this record was created (tag: CREA, GEDCOM 7.0).
+    pub creation_date: Option<ChangeDate>,
+    /// Notes (tag: NOTE or SNOTE).
+    pub notes: Vec<Note>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Multimedia {
@@ -67,10 +90,47 @@ impl Multimedia {
         level: u8,
         xref: Option<Xref>,
     ) -> Result<Multimedia, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut obje = Multimedia::with_xref(xref);
         obje.parse(tokenizer, level)?;
+        obje.span = tokenizer.span_end(span_start);
         Ok(obje)
     }
+
+    /// Adds a file reference to this record.
+    pub fn add_file(&mut self, file: Reference) {
+        self.files.push(file);
+    }
+
+    /// Returns the first file reference, which is the one most readers display.
+    #[must_use]
+    pub fn primary_file(&self) -> Option<&Reference> {
+        self.files.first()
+    }
+
+    /// Returns every file path of this record, including translated variants (`FILE.TRAN`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::Gedcom;
+    ///
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @O1@ OBJE\n1 FILE scan.tif\n2 FORM image/tiff\n\
+    ///     2 TRAN scan.jpg\n3 FORM image/jpeg\n1 FILE audio.wav\n2 FORM audio/wav\n0 TRLR";
+    /// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+    ///
+    /// let paths: Vec<&str> = data.multimedia[0].file_paths().collect();
+    /// assert_eq!(paths, ["scan.tif", "scan.jpg", "audio.wav"]);
+    /// ```
+    pub fn file_paths(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().flat_map(|file| {
+            file.value.as_deref().into_iter().chain(
+                file.translations
+                    .iter()
+                    .filter_map(|translation| translation.value.as_deref()),
+            )
+        })
+    }
 }
 
 impl Parser for Multimedia {
@@ -78,28 +138,34 @@ impl Parser for Multimedia {
         // skip current line
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
-                "FILE" => self.file = Some(Reference::new(tokenizer, level + 1)?),
+                "FILE" => self.add_file(Reference::new(tokenizer, level + 1)?),
                 "FORM" => self.form = Some(Format::new(tokenizer, level + 1)?),
                 "TITL" => self.title = Some(tokenizer.take_line_value()?),
+                "RESN" => self.restriction = Some(tokenizer.take_line_value()?),
                 "REFN" => {
-                    self.user_reference_number =
-                        Some(UserReferenceNumber::new(tokenizer, level + 1)?);
+                    self.user_reference_numbers
+                        .push(UserReferenceNumber::new(tokenizer, level + 1)?);
+                }
+                "UID" => self.uids.push(tokenizer.take_line_value()?),
+                "EXID" => {
+                    self.external_ids
+                        .push(ExternalId::from_tokenizer(tokenizer, level + 1)?);
                 }
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note_structure = Some(Note::new(tokenizer, level + 1)?),
-                "SOUR" => self.source_citation = Some(Citation::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                "SOUR" => self
+                    .source_citations
+                    .push(Citation::new(tokenizer, level + 1)?),
                 "CHAN" => self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "CREA" => self.creation_date = Some(ChangeDate::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -166,7 +232,7 @@ mod tests {
         let form = obje.form.as_ref().unwrap();
         assert_eq!(form.value.as_ref().unwrap(), "jpg");
 
-        let file = obje.file.as_ref().unwrap();
+        let file = obje.primary_file().unwrap();
         assert_eq!(file.value.as_ref().unwrap(), "http://trees.ancestry.com/rd?f=image&guid=Xxxxxxxx-Xxxx-Xxxx-Xxxx-Xxxxxxxxxxxx&tid=Xxxxxxxx&pid=1");
     }
 
@@ -187,7 +253,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
         assert_eq!(data.multimedia.len(), 1);
 
-        let file = data.multimedia[0].file.as_ref().unwrap();
+        let file = data.multimedia[0].primary_file().unwrap();
         assert_eq!(
             file.value.as_ref().unwrap(),
             "/home/user/media/file_name.bmp"
@@ -216,7 +282,7 @@ mod tests {
         let data = doc.parse_data().unwrap();
         assert_eq!(data.multimedia.len(), 1);
 
-        let file = data.multimedia[0].file.as_ref().unwrap();
+        let file = data.multimedia[0].primary_file().unwrap();
 
         let form = file.form.as_ref().unwrap();
         assert_eq!(form.value.as_ref().unwrap(), "bmp");
@@ -240,11 +306,60 @@ mod tests {
         let data = doc.parse_data().unwrap();
         assert_eq!(data.multimedia.len(), 1);
 
-        let user_ref = data.multimedia[0].user_reference_number.as_ref().unwrap();
+        let user_ref = &data.multimedia[0].user_reference_numbers[0];
         assert_eq!(user_ref.value.as_ref().unwrap(), "000");
         assert_eq!(
             user_ref.user_reference_type.as_ref().unwrap(),
             "User Reference Type"
         );
     }
+
+    #[test]
+    fn test_parse_gedcom_7_multimedia_record() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 7.0\n\
+            0 @O1@ OBJE\n\
+            1 FILE photo.tif\n\
+            2 FORM image/tiff\n\
+            3 MEDI PHOTO\n\
+            2 TRAN photo.jpg\n\
+            3 FORM image/jpeg\n\
+            1 FILE photo-back.tif\n\
+            2 FORM image/tiff\n\
+            1 REFN 1\n\
+            1 REFN 2\n\
+            1 UID 8f2d7a5c-1c4e-4e0a-9d7e-3b0c5a9e6f10\n\
+            1 EXID 42\n\
+            2 TYPE https://example.com/\n\
+            1 NOTE First\n\
+            1 NOTE Second\n\
+            1 SOUR @S1@\n\
+            1 SOUR @S2@\n\
+            0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+        let obje = &data.multimedia[0];
+
+        assert_eq!(obje.files.len(), 2);
+        let form = obje.files[0].form.as_ref().unwrap();
+        assert_eq!(form.value.as_deref(), Some("image/tiff"));
+        assert_eq!(form.source_media_type.as_deref(), Some("PHOTO"));
+        let translation = &obje.files[0].translations[0];
+        assert_eq!(translation.value.as_deref(), Some("photo.jpg"));
+        assert_eq!(
+            translation.form.as_ref().unwrap().value.as_deref(),
+            Some("image/jpeg")
+        );
+        assert_eq!(obje.user_reference_numbers.len(), 2);
+        assert_eq!(obje.uids.len(), 1);
+        assert_eq!(
+            obje.external_ids[0].type_uri.as_deref(),
+            Some("https://example.com/")
+        );
+        assert_eq!(obje.notes.len(), 2);
+        assert_eq!(obje.source_citations.len(), 2);
+    }
 }
@@ -2,9 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{gedcom7::Crop, multimedia::Format},
+    types::{custom::UserDefinedTag, gedcom7::Crop, multimedia::Format},
     GedcomError,
 };
 
@@ -16,6 +16,7 @@ use crate::{
 ///
 /// In GEDCOM 7.0, file references can have additional substructures:
 /// - `CROP` - Image cropping information specifying a region to display
+/// - `TRAN` - Alternate versions of the file, such as a transcoded copy
 ///
 /// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#MULTIMEDIA_LINK>
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -24,11 +25,18 @@ pub struct Reference {
     pub value: Option<String>,
     pub title: Option<String>,
     pub form: Option<Format>,
+    /// Alternate versions of this file (tag: TRAN, GEDCOM 7.0).
+    ///
+    /// Each translation is the same media in another format, such as a JPEG rendition of a
+    /// TIFF scan.
+    pub translations: Vec<FileTranslation>,
     /// Image cropping information (GEDCOM 7.0).
     ///
     /// Specifies a region of the image to display, defined by coordinates
     /// relative to the image dimensions.
     pub crop: Option<Crop>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Reference {
@@ -47,19 +55,66 @@ impl Reference {
 impl Parser for Reference {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TITL" => self.title = Some(tokenizer.take_line_value()?),
                 "FORM" => self.form = Some(Format::new(tokenizer, level + 1)?),
                 "CROP" => self.crop = Some(Crop::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
+                "TRAN" => {
+                    self.translations
+                        .push(FileTranslation::new(tokenizer, level + 1)?);
                 }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
+            }
+            Ok(None)
+        };
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
+
+        Ok(())
+    }
+}
+
+/// An alternate version of a multimedia file (`FILE.TRAN`).
+///
+/// A translation is a file that represents the same media as its parent `FILE` in a different
+/// format, for example a web-friendly copy of an archival scan.
+///
+/// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#FILE-TRAN>
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct FileTranslation {
+    /// The file path or URL of the alternate version.
+    pub value: Option<String>,
+    /// The media type of the alternate version (tag: FORM).
+    pub form: Option<Format>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
+}
+
+impl FileTranslation {
+    /// Creates a new `FileTranslation` from a `Tokenizer`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if parsing fails.
+    pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<FileTranslation, GedcomError> {
+        let mut translation = FileTranslation::default();
+        translation.parse(tokenizer, level)?;
+        Ok(translation)
+    }
+}
+
+impl Parser for FileTranslation {
+    fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
+        self.value = Some(tokenizer.take_line_value()?);
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            match tag {
+                "FORM" => self.form = Some(Format::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -2,8 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
     GedcomError,
 };
 
@@ -18,7 +19,14 @@ use crate::{
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Format {
     pub value: Option<String>,
+    /// The medium of the source (tag: MEDI, or TYPE in GEDCOM 5.5), such as `PHOTO`.
     pub source_media_type: Option<String>,
+    /// Free-text description of the medium (tag: MEDI.PHRASE, GEDCOM 7.0).
+    pub source_media_phrase: Option<String>,
+    /// Custom data under the medium (tag: MEDI or TYPE).
+    pub source_media_custom_data: Vec<Box<UserDefinedTag>>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Format {
@@ -38,18 +46,27 @@ impl Parser for Format {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         self.value = Some(tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
-                "TYPE" => self.source_media_type = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
+                "TYPE" | "MEDI" => {
+                    self.source_media_type = Some(tokenizer.take_line_value()?);
+                    let mut phrase = None;
+                    self.source_media_custom_data =
+                        parse_subset(tokenizer, level + 1, |tag, tokenizer| {
+                            match tag {
+                                "PHRASE" => phrase = Some(tokenizer.take_line_value()?),
+                                _ => return handle_unknown_tag(tokenizer, tag, level + 2),
+                            }
+                            Ok(None)
+                        })?;
+                    self.source_media_phrase = phrase;
                 }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -2,9 +2,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
+        custom::UserDefinedTag,
         multimedia::{Format, Reference},
         Xref,
     },
@@ -28,6 +29,8 @@ pub struct Link {
     /// The 5.5 spec, page 26, shows TITL as a sub-structure of FILE, but the struct appears as a
     /// sibling in an Ancestry.com export.
     pub title: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Link {
@@ -46,6 +49,7 @@ impl Link {
             file: None,
             form: None,
             title: None,
+            custom_data: Vec::new(),
         };
         obje.parse(tokenizer, level)?;
         Ok(obje)
@@ -57,20 +61,17 @@ impl Parser for Link {
         // skip current line
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "FILE" => self.file = Some(Reference::new(tokenizer, level + 1)?),
                 "FORM" => self.form = Some(Format::new(tokenizer, level + 1)?),
                 "TITL" => self.title = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
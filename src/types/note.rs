@@ -1,7 +1,8 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{source::Source, translation::Translation},
+    types::{custom::UserDefinedTag, source::Source, translation::Translation, Xref},
+    util::is_pointer,
     GedcomError,
 };
 
@@ -25,6 +26,12 @@ use serde::{Deserialize, Serialize};
 /// Conforms to the GEDCOM `NOTE_STRUCTURE` specification.
 /// See: <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#NOTE>
 /// See: <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#NOTE_STRUCTURE>
+///
+/// # Shared Notes
+///
+/// A note can also point to a shared note record instead of carrying its own text, written
+/// `1 SNOTE @N1@` in GEDCOM 7.0 and `1 NOTE @N1@` in GEDCOM 5.5.1. Such a note has no
+/// `value`; its `shared_note` holds the pointer. See [`Note::shared`].
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Note {
@@ -44,6 +51,11 @@ pub struct Note {
     /// the Text-typed payloads of the superstructure and its substructures appear. See
     /// <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#LANG>.
     pub language: Option<String>,
+    /// The shared note record this note points to (`SNOTE @N1@` in GEDCOM 7.0, `NOTE @N1@` in
+    /// GEDCOM 5.5.1), in place of text of its own.
+    pub shared_note: Option<Xref>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Note {
@@ -57,26 +69,53 @@ impl Note {
         note.parse(tokenizer, level)?;
         Ok(note)
     }
+
+    /// Creates a note that points to the shared note record `xref`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::types::note::Note;
+    ///
+    /// let note = Note::shared("@N1@");
+    /// assert!(note.is_shared());
+    /// assert_eq!(note.value, None);
+    /// ```
+    #[must_use]
+    pub fn shared(xref: &str) -> Note {
+        Note {
+            shared_note: Some(xref.into()),
+            ..Note::default()
+        }
+    }
+
+    /// Returns true if this note points to a shared note record.
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        self.shared_note.is_some()
+    }
 }
 
 impl Parser for Note {
-    /// parse handles the NOTE tag
+    /// parse handles the NOTE and SNOTE tags
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        self.value = Some(tokenizer.take_continued_text(level)?);
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let value = tokenizer.take_continued_text(level)?;
+        if is_pointer(&value) {
+            self.shared_note = Some(value.into());
+        } else {
+            self.value = Some(value);
+        }
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "MIME" => self.mime = Some(tokenizer.take_line_value()?),
                 "TRANS" => self.translation = Some(Translation::new(tokenizer, level + 1)?),
                 "LANG" => self.language = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -118,4 +157,50 @@ mod tests {
         let note = data.header.unwrap().note.unwrap();
         assert_eq!(note.value.unwrap().chars().count(), 1441);
     }
+
+    #[test]
+    fn test_parse_shared_note_pointers() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 5.5.1\n\
+            0 @I1@ INDI\n\
+            1 NOTE @N1@\n\
+            1 NOTE @@N2@ is not a pointer\n\
+            1 NOTE Plain text\n\
+            0 @N1@ NOTE Shared text\n\
+            0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+
+        let notes = &data.individuals[0].notes;
+        assert_eq!(notes.len(), 3);
+        assert_eq!(notes[0].shared_note.as_deref(), Some("@N1@"));
+        assert_eq!(notes[0].value, None);
+        assert!(!notes[1].is_shared());
+        assert_eq!(notes[2].value.as_deref(), Some("Plain text"));
+    }
+
+    #[test]
+    fn test_parse_gedcom_7_snote_pointer() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 7.0\n\
+            0 @I1@ INDI\n\
+            1 BIRT\n\
+            2 SNOTE @N1@\n\
+            2 NOTE Born at home\n\
+            0 @N1@ SNOTE Shared text\n\
+            0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+
+        let notes = &data.individuals[0].events[0].notes;
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0], super::Note::shared("@N1@"));
+        assert_eq!(notes[1].value.as_deref(), Some("Born at home"));
+    }
 }
@@ -11,9 +11,11 @@
 //! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#PLACE_STRUCTURE>
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{custom::UserDefinedTag, note::Note, source::citation::Citation},
+    types::{
+        custom::UserDefinedTag, external_id::ExternalId, note::Note, source::citation::Citation,
+    },
     GedcomError,
 };
 
@@ -62,7 +64,7 @@ pub struct Place {
     pub notes: Vec<Note>,
 
     /// External identifiers for this place (GEDCOM 7.0).
-    pub external_ids: Vec<String>,
+    pub external_ids: Vec<ExternalId>,
 
     /// Source citations supporting this place.
     pub citations: Vec<Citation>,
@@ -97,6 +99,8 @@ pub struct MapCoordinates {
     /// In GEDCOM 5.5.1, format is `E|W<degrees>.<decimal>`
     /// In GEDCOM 7.0, format is a signed decimal number.
     pub longitude: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl MapCoordinates {
@@ -117,6 +121,7 @@ impl MapCoordinates {
         MapCoordinates {
             latitude: Some(latitude.to_string()),
             longitude: Some(longitude.to_string()),
+            custom_data: Vec::new(),
         }
     }
 
@@ -167,19 +172,16 @@ impl Parser for MapCoordinates {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "LATI" => self.latitude = Some(tokenizer.take_line_value()?),
                 "LONG" => self.longitude = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -208,6 +210,8 @@ pub struct PlaceVariation {
     /// - `romaji` - Japanese Romaji
     /// - `wadegiles` - Wade-Giles romanization
     pub variation_type: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl PlaceVariation {
@@ -220,6 +224,7 @@ impl PlaceVariation {
         let mut variation = PlaceVariation {
             value: tokenizer.take_line_value()?,
             variation_type: None,
+            custom_data: Vec::new(),
         };
         variation.parse(tokenizer, level)?;
         Ok(variation)
@@ -231,24 +236,22 @@ impl PlaceVariation {
         PlaceVariation {
             value: value.to_string(),
             variation_type: Some(variation_type.to_string()),
+            custom_data: Vec::new(),
         }
     }
 }
 
 impl Parser for PlaceVariation {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "TYPE" => self.variation_type = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -327,7 +330,7 @@ impl Place {
 
 impl Parser for Place {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "FORM" => self.form = Some(tokenizer.take_line_value()?),
                 "MAP" => self.map = Some(MapCoordinates::new(tokenizer, level + 1)?),
@@ -337,15 +340,14 @@ impl Parser for Place {
                 "ROMN" => self
                     .romanized
                     .push(PlaceVariation::new(tokenizer, level + 1)?),
-                "NOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "SOUR" => self.citations.push(Citation::new(tokenizer, level + 1)?),
-                "EXID" => self.external_ids.push(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -392,6 +394,7 @@ mod tests {
         let incomplete = MapCoordinates {
             latitude: Some("N50.0".to_string()),
             longitude: None,
+            custom_data: Vec::new(),
         };
         assert!(!incomplete.is_complete());
     }
@@ -1,10 +1,11 @@
 pub mod citation;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{
-        address::Address, custom::UserDefinedTag, date::change_date::ChangeDate, note::Note, Xref,
+        address::Address, custom::UserDefinedTag, date::change_date::ChangeDate,
+        external_id::ExternalId, note::Note, span::Span, Xref,
     },
     GedcomError,
 };
@@ -84,10 +85,14 @@ pub struct Repository {
     /// External identifiers (tag: EXID, GEDCOM 7.0).
     ///
     /// Identifiers maintained by external authorities that apply to this repository.
-    pub external_ids: Vec<String>,
+    pub external_ids: Vec<ExternalId>,
 
     /// Custom data (extension tags).
     pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Repository {
@@ -107,10 +112,12 @@ impl Repository {
     pub fn new(
         tokenizer: &mut Tokenizer<'_>,
         level: u8,
-        xref: Option<String>,
+        xref: Option<Xref>,
     ) -> Result<Repository, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut repo = Repository::with_xref(xref);
         repo.parse(tokenizer, level)?;
+        repo.span = tokenizer.span_end(span_start);
         Ok(repo)
     }
 
@@ -118,7 +125,7 @@ impl Repository {
     #[must_use]
     pub fn with_name(xref: &str, name: &str) -> Self {
         Self {
-            xref: Some(xref.to_string()),
+            xref: Some(xref.into()),
             name: Some(name.to_string()),
             ..Default::default()
         }
@@ -166,7 +173,7 @@ impl Parser for Repository {
         // skip REPO tag
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "NAME" => self.name = Some(tokenizer.take_line_value()?),
                 "ADDR" => self.address = Some(Address::new(tokenizer, level + 1)?),
@@ -174,7 +181,7 @@ impl Parser for Repository {
                 "EMAIL" => self.email.push(tokenizer.take_line_value()?),
                 "FAX" => self.fax.push(tokenizer.take_line_value()?),
                 "WWW" => self.website.push(tokenizer.take_line_value()?),
-                "NOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "CHAN" => self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?),
                 "REFN" => {
                     self.user_reference_number = Some(tokenizer.take_line_value()?);
@@ -182,14 +189,13 @@ impl Parser for Repository {
                 }
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
                 "UID" => self.uid = Some(tokenizer.take_line_value()?),
-                "EXID" => self.external_ids.push(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -292,7 +298,7 @@ mod tests {
     #[test]
     fn test_repository_with_name() {
         let repo = super::Repository::with_name("@R1@", "Test Repository");
-        assert_eq!(repo.xref, Some("@R1@".to_string()));
+        assert_eq!(repo.xref, Some("@R1@".into()));
         assert_eq!(repo.name, Some("Test Repository".to_string()));
     }
 
@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
     types::{custom::UserDefinedTag, note::Note, Xref},
     GedcomError,
@@ -73,7 +73,7 @@ impl Citation {
     /// This function will return an error if parsing fails.
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<Citation, GedcomError> {
         let xref = tokenizer.take_line_value()?;
-        let mut rc = Citation::with_xref(xref);
+        let mut rc = Citation::with_xref(xref.into());
         rc.parse(tokenizer, level)?;
         Ok(rc)
     }
@@ -82,7 +82,7 @@ impl Citation {
     #[must_use]
     pub fn for_repository(xref: &str) -> Self {
         Self {
-            xref: xref.to_string(),
+            xref: xref.into(),
             ..Default::default()
         }
     }
@@ -112,20 +112,17 @@ impl Citation {
 
 impl Parser for Citation {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "CALN" => {
                     self.call_number = Some(tokenizer.take_line_value()?);
                     // MEDI can be a substructure of CALN in some GEDCOM versions
                 }
                 "MEDI" => self.media_type = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
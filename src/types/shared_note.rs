@@ -1,8 +1,9 @@
-//! Shared note record for GEDCOM 7.0.
+//! Shared note records.
 //!
-//! GEDCOM 7.0 introduces the `SNOTE` (shared note) record type, which allows
-//! a single note to be referenced by multiple structures. This is different
-//! from the inline `NOTE` structure which is specific to its containing structure.
+//! A shared note is a level-0 note record which allows a single note to be referenced
+//! by multiple structures. This is different from the inline `NOTE` structure which is
+//! specific to its containing structure. GEDCOM 7.0 writes these records with the
+//! `SNOTE` tag, while GEDCOM 5.5.1 uses a `NOTE` record.
 //!
 //! # Example
 //!
@@ -13,19 +14,32 @@
 //! 1 LANG en
 //! ```
 //!
+//! The same record in GEDCOM 5.5.1:
+//!
+//! ```text
+//! 0 @N1@ NOTE "Gordon" is a traditional Scottish surname.
+//! 1 CONT It became a given name in honor of Charles George Gordon.
+//! 1 RIN 1234
+//! ```
+//!
 //! See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#SHARED_NOTE_RECORD>
 
 use crate::{
-    parser::{parse_subset, Parser},
-    tokenizer::Tokenizer,
-    types::{custom::UserDefinedTag, date::change_date::ChangeDate, source::citation::Citation},
-    GedcomError,
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
+    tokenizer::{Token, Tokenizer},
+    types::{
+        custom::UserDefinedTag, date::change_date::ChangeDate,
+        multimedia::user::UserReferenceNumber, source::citation::Citation, span::Span, Xref,
+    },
+    GedcomError, GedcomVersion,
 };
 
+pub use crate::types::external_id::ExternalId;
+
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
 
-/// A shared note record (SNOTE).
+/// A shared note record (`SNOTE` in GEDCOM 7.0, `NOTE` in GEDCOM 5.5.1).
 ///
 /// A catch-all location for information that does not fully fit within other structures.
 /// It may include research notes, additional context, alternative interpretations,
@@ -35,16 +49,19 @@ use serde::{Deserialize, Serialize};
 /// should only be used if editing the note in one place should edit it in all other
 /// places or if the note itself requires an identifier structure.
 ///
-/// # GEDCOM 7.0 Only
+/// # Version Differences
 ///
-/// This structure is only valid in GEDCOM 7.0 and later.
+/// Both versions share the text, source citations, `REFN` and `CHAN` substructures.
+/// `MIME`, `LANG`, `TRAN`, `EXID` and `CREA` are GEDCOM 7.0 only, and `RIN` is
+/// GEDCOM 5.5.1 only. The [`version`](Self::version) field records which form the
+/// record was read from; the writer always emits the form of its target version.
 ///
 /// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#SHARED_NOTE_RECORD>
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct SharedNote {
     /// The cross-reference identifier for this shared note (e.g., `@N1@`).
-    pub xref: Option<String>,
+    pub xref: Option<Xref>,
 
     /// The text content of the note.
     ///
@@ -76,6 +93,18 @@ pub struct SharedNote {
     /// External identifiers for this note.
     pub external_ids: Vec<ExternalId>,
 
+    /// User reference numbers for this note (tag: REFN).
+    pub user_reference_numbers: Vec<UserReferenceNumber>,
+
+    /// Automated record ID (tag: RIN, GEDCOM 5.5.1 only).
+    pub automated_record_id: Option<String>,
+
+    /// The GEDCOM version whose record form this note was parsed from.
+    ///
+    /// `V5_5_1` for a `0 @N1@ NOTE` record and `V7_0` for a `0 @N1@ SNOTE` record.
+    /// `None` for notes built in code.
+    pub version: Option<GedcomVersion>,
+
     /// The date of the most recent change to this record.
     pub change_date: Option<ChangeDate>,
 
@@ -84,6 +113,10 @@ pub struct SharedNote {
 
     /// Custom data (extension tags).
     pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 /// A translation of a note into a different language or media type.
@@ -105,48 +138,6 @@ pub struct NoteTranslation {
     pub language: Option<String>,
 }
 
-/// An external identifier for a structure.
-///
-/// An identifier maintained by an external authority that applies to the
-/// subject of the structure. Unlike `UID` and `REFN`, `EXID` does not
-/// identify a structure; structures with the same `EXID` may have
-/// originated independently.
-///
-/// See <https://gedcom.io/specifications/FamilySearchGEDCOMv7.html#EXID>
-#[derive(Clone, Debug, Default, PartialEq)]
-#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
-pub struct ExternalId {
-    /// The external identifier value.
-    pub id: String,
-
-    /// The authority issuing the identifier, represented as a URI.
-    ///
-    /// If the authority maintains stable URLs for each identifier,
-    /// appending the `id` to this `type_uri` should yield that URL.
-    pub type_uri: Option<String>,
-}
-
-impl ExternalId {
-    /// Creates a new external identifier.
-    #[must_use]
-    pub fn new(id: &str, type_uri: Option<&str>) -> Self {
-        ExternalId {
-            id: id.to_string(),
-            type_uri: type_uri.map(String::from),
-        }
-    }
-
-    /// Returns the full URL for this identifier, if possible.
-    ///
-    /// This concatenates the type URI with the identifier.
-    #[must_use]
-    pub fn full_url(&self) -> Option<String> {
-        self.type_uri
-            .as_ref()
-            .map(|uri| format!("{}{}", uri, self.id))
-    }
-}
-
 impl NoteTranslation {
     /// Creates a new note translation.
     #[must_use]
@@ -176,13 +167,15 @@ impl SharedNote {
     pub fn new(
         tokenizer: &mut Tokenizer<'_>,
         level: u8,
-        xref: Option<String>,
+        xref: Option<Xref>,
     ) -> Result<SharedNote, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut note = SharedNote {
             xref,
             ..Default::default()
         };
         note.parse(tokenizer, level)?;
+        note.span = tokenizer.span_end(span_start);
         Ok(note)
     }
 
@@ -190,7 +183,7 @@ impl SharedNote {
     #[must_use]
     pub fn with_text(xref: &str, text: &str) -> Self {
         SharedNote {
-            xref: Some(xref.to_string()),
+            xref: Some(xref.into()),
             text: text.to_string(),
             ..Default::default()
         }
@@ -307,10 +300,16 @@ fn remove_html_tags(input: &str) -> String {
 impl Parser for SharedNote {
     /// Parses SNOTE record from tokens.
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
-        // Get the note text (payload of SNOTE line)
+        self.version = match &tokenizer.current_token {
+            Token::Tag(tag) if tag.as_ref() == "NOTE" => Some(GedcomVersion::V5_5_1),
+            Token::Tag(tag) if tag.as_ref() == "SNOTE" => Some(GedcomVersion::V7_0),
+            _ => None,
+        };
+
+        // Get the note text (payload of the NOTE/SNOTE line)
         self.text = tokenizer.take_continued_text(level)?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "MIME" => {
                     self.mime = Some(tokenizer.take_line_value()?);
@@ -331,24 +330,23 @@ impl Parser for SharedNote {
                         .push(Citation::new(tokenizer, level + 1)?);
                 }
                 "EXID" => {
-                    let id = tokenizer.take_line_value()?;
-                    self.external_ids.push(ExternalId {
-                        id,
-                        type_uri: None, // TYPE substructure would be parsed here
-                    });
+                    self.external_ids
+                        .push(ExternalId::from_tokenizer(tokenizer, level + 1)?);
+                }
+                "REFN" => {
+                    self.user_reference_numbers
+                        .push(UserReferenceNumber::new(tokenizer, level + 1)?);
                 }
+                "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
                 "CHAN" => {
                     self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?);
                 }
                 "CREA" => {
                     self.creation_date = Some(ChangeDate::new(tokenizer, level + 1)?);
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -382,14 +380,15 @@ mod tests {
         let data = doc.parse_data().unwrap();
 
         let note = &data.shared_notes[0];
-        assert_eq!(note.xref, Some("@N1@".to_string()));
+        assert_eq!(note.xref, Some("@N1@".into()));
+        assert_eq!(note.version, Some(GedcomVersion::V5_5_1));
         assert_eq!(note.text, "Bill Clinton was born William Jefferson Blythe IV.  His last name was legally\nchanged to Clinton on 12 June 1962 in Garland, Arkansas.  Won the 1992\nelection over then president George Bush (votes not currently available).\nHe was inaugurated as the 42nd President of the United States\non 20 January 1993.");
     }
 
     #[test]
     fn test_shared_note_with_text() {
         let note = SharedNote::with_text("@N1@", "This is a test note.");
-        assert_eq!(note.xref, Some("@N1@".to_string()));
+        assert_eq!(note.xref, Some("@N1@".into()));
         assert_eq!(note.text, "This is a test note.");
     }
 
@@ -408,23 +407,6 @@ mod tests {
         assert!(!invalid.is_valid());
     }
 
-    #[test]
-    fn test_external_id() {
-        let exid = ExternalId::new("12345", Some("https://example.com/person/"));
-        assert_eq!(exid.id, "12345");
-        assert_eq!(
-            exid.type_uri,
-            Some("https://example.com/person/".to_string())
-        );
-        assert_eq!(
-            exid.full_url(),
-            Some("https://example.com/person/12345".to_string())
-        );
-
-        let exid_no_type = ExternalId::new("12345", None);
-        assert_eq!(exid_no_type.full_url(), None);
-    }
-
     #[test]
     fn test_is_html_and_plain_text() {
         let mut note = SharedNote::default();
@@ -4,12 +4,12 @@ pub mod quay;
 pub mod text;
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
         custom::UserDefinedTag, date::change_date::ChangeDate, event::detail::Detail,
-        multimedia::Multimedia, note::Note, repository::citation::Citation, source::data::Data,
-        Xref,
+        external_id::ExternalId, multimedia::Multimedia, note::Note,
+        repository::citation::Citation, source::data::Data, span::Span, Xref,
     },
     GedcomError,
 };
@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Source {
-    pub xref: Option<String>,
+    pub xref: Option<Xref>,
     pub data: Data,
     pub abbreviation: Option<String>,
     pub title: Option<String>,
@@ -64,7 +64,11 @@ pub struct Source {
     /// External identifiers (tag: EXID, GEDCOM 7.0).
     ///
     /// Identifiers maintained by external authorities that apply to this source.
-    pub external_ids: Vec<String>,
+    pub external_ids: Vec<ExternalId>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Source {
@@ -85,10 +89,12 @@ impl Source {
     pub fn new(
         tokenizer: &mut Tokenizer<'_>,
         level: u8,
-        xref: Option<String>,
+        xref: Option<Xref>,
     ) -> Result<Source, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut sour = Source::with_xref(xref);
         sour.parse(tokenizer, level)?;
+        sour.span = tokenizer.span_end(span_start);
         Ok(sour)
     }
 
@@ -110,10 +116,10 @@ impl Parser for Source {
         // skip SOUR tag
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
-            let mut pointer: Option<String> = None;
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            let mut pointer: Option<Xref> = None;
             if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
+                pointer = Some(Xref::from(xref.as_ref()));
                 tokenizer.next_token()?;
             }
             match tag {
@@ -123,7 +129,7 @@ impl Parser for Source {
                     let mut event = Detail::new(tokenizer, level + 2, "OTHER")?;
                     event.with_source_data(events_recorded);
                     self.data.add_event(event);
-                    return Ok(());
+                    return Ok(None);
                 }
                 "AGNC" => self.data.agency = Some(tokenizer.take_line_value()?),
                 "ABBR" => self.abbreviation = Some(tokenizer.take_continued_text(level + 1)?),
@@ -135,7 +141,7 @@ impl Parser for Source {
                     self.citation_from_source = Some(tokenizer.take_continued_text(level + 1)?);
                 }
                 "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level + 1, pointer)?),
-                "NOTE" => self.add_note(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.add_note(Note::new(tokenizer, level + 1)?),
                 "REPO" => self.add_repo_citation(Citation::new(tokenizer, level + 1)?),
                 "RFN" => self.submitter_registered_rfn = Some(tokenizer.take_line_value()?),
                 // Unique identifier (GEDCOM 7.0)
@@ -148,14 +154,13 @@ impl Parser for Source {
                 // Automated record ID
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
                 // External identifier (GEDCOM 7.0)
-                "EXID" => self.external_ids.push(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -232,7 +237,7 @@ mod tests {
         let citation_data = data.individuals[0].source[0].data.as_ref().unwrap();
 
         assert_eq!(
-            citation_data.text.as_ref().unwrap().value.as_ref().unwrap(),
+            citation_data.texts[0].value.as_ref().unwrap(),
             "a sample text\nSample text continued here. The word TEST should not be broken!"
         );
     }
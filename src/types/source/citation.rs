@@ -4,13 +4,14 @@ pub mod data;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
         custom::UserDefinedTag,
         multimedia::Multimedia,
         note::Note,
         source::{citation::data::SourceCitationData, quay::CertaintyAssessment},
+        span::Span,
         Xref,
     },
     GedcomError,
@@ -26,7 +27,8 @@ pub struct Citation {
     /// Page number of source
     pub page: Option<String>,
     pub data: Option<SourceCitationData>,
-    pub note: Option<Note>,
+    /// Notes about the citation (tag: NOTE, or SNOTE in GEDCOM 7.0).
+    pub notes: Vec<Note>,
     pub certainty_assessment: Option<CertaintyAssessment>,
     /// handles "RFN" tag; found in Ancestry.com export
     pub submitter_registered_rfn: Option<String>,
@@ -36,10 +38,21 @@ pub struct Citation {
     ///
     /// Indicates what type of event was cited from the source.
     pub event_type: Option<String>,
-    /// Role in the cited event (tag: ROLE).
+    /// Free-text description of the cited event (tag: PHRASE under EVEN, GEDCOM 7.0).
+    pub event_phrase: Option<String>,
+    /// Custom data under the cited event (tag: EVEN).
+    pub event_custom_data: Vec<Box<UserDefinedTag>>,
+    /// Role in the cited event (tag: ROLE under EVEN).
     ///
     /// Indicates the role the person played in the cited event.
     pub role: Option<String>,
+    /// Free-text description of the role (tag: PHRASE under ROLE, GEDCOM 7.0).
+    pub role_phrase: Option<String>,
+    /// Custom data under the role (tag: ROLE).
+    pub role_custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this citation was parsed from, when span recording is
+    /// enabled.
+    pub span: Option<Span>,
 }
 
 impl Citation {
@@ -49,25 +62,64 @@ impl Citation {
     ///
     /// This function will return an error if parsing fails.
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<Citation, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut citation = Citation {
-            xref: tokenizer.take_line_value()?,
+            xref: tokenizer.take_line_value()?.into(),
             page: None,
             data: None,
-            note: None,
+            notes: Vec::new(),
             certainty_assessment: None,
             multimedia: Vec::new(),
             custom_data: Vec::new(),
             submitter_registered_rfn: None,
             event_type: None,
+            event_phrase: None,
+            event_custom_data: Vec::new(),
             role: None,
+            role_phrase: None,
+            role_custom_data: Vec::new(),
+            span: None,
         };
         citation.parse(tokenizer, level)?;
+        citation.span = tokenizer.span_end(span_start);
         Ok(citation)
     }
 
     pub fn add_multimedia(&mut self, m: Multimedia) {
         self.multimedia.push(m);
     }
+
+    pub fn add_note(&mut self, note: Note) {
+        self.notes.push(note);
+    }
+
+    /// Parses the substructures of `EVEN`: its `PHRASE` and the `ROLE` with its own `PHRASE`.
+    fn parse_event(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
+        self.event_type = Some(tokenizer.take_line_value()?);
+
+        self.event_custom_data = parse_subset(tokenizer, level, |tag, tokenizer| {
+            match tag {
+                "PHRASE" => self.event_phrase = Some(tokenizer.take_line_value()?),
+                "ROLE" => {
+                    self.role = Some(tokenizer.take_line_value()?);
+                    let mut phrase = None;
+                    self.role_custom_data =
+                        parse_subset(tokenizer, level + 1, |tag, tokenizer| {
+                            match tag {
+                                "PHRASE" => phrase = Some(tokenizer.take_line_value()?),
+                                _ => return handle_unknown_tag(tokenizer, tag, level + 2),
+                            }
+                            Ok(None)
+                        })?;
+                    self.role_phrase = phrase;
+                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
+            }
+            Ok(None)
+        })?;
+
+        Ok(())
+    }
 }
 
 impl Parser for Citation {
@@ -75,36 +127,29 @@ impl Parser for Citation {
         // Note: Don't call next_token() here - the tokenizer is already positioned
         // at the next Level token after Citation::new() called take_line_value()
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
-            let mut pointer: Option<String> = None;
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            let mut pointer: Option<Xref> = None;
             if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
+                pointer = Some(Xref::from(xref.as_ref()));
                 tokenizer.next_token()?;
             }
             match tag {
                 "PAGE" => self.page = Some(tokenizer.take_continued_text(level + 1)?),
                 "DATA" => self.data = Some(SourceCitationData::new(tokenizer, level + 1)?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.add_note(Note::new(tokenizer, level + 1)?),
                 "QUAY" => {
                     self.certainty_assessment =
                         Some(CertaintyAssessment::new(tokenizer, level + 1)?);
                 }
                 "RFN" => self.submitter_registered_rfn = Some(tokenizer.take_line_value()?),
                 "OBJE" => self.add_multimedia(Multimedia::new(tokenizer, level + 1, pointer)?),
-                "EVEN" => {
-                    self.event_type = Some(tokenizer.take_line_value()?);
-                    // Parse ROLE if it's a substructure of EVEN
-                    // The ROLE tag should be at level + 2 (under EVEN at level + 1)
-                }
+                "EVEN" => self.parse_event(tokenizer, level + 1)?,
+                // Some exporters write ROLE next to EVEN instead of under it
                 "ROLE" => self.role = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags instead of failing
-                    // This handles non-standard extensions from various GEDCOM generators
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
@@ -146,4 +191,51 @@ mod tests {
         assert_eq!(sour.event_type.as_ref().unwrap(), "BIRT");
         assert_eq!(sour.role.as_ref().unwrap(), "CHIL");
     }
+
+    #[test]
+    fn test_parse_gedcom_7_source_citation() {
+        let sample = "\
+            0 HEAD\n\
+            1 GEDC\n\
+            2 VERS 7.0\n\
+            0 @I1@ INDI\n\
+            1 BIRT\n\
+            2 SOUR @S1@\n\
+            3 DATA\n\
+            4 DATE 1 JAN 1900\n\
+            4 TEXT Born to Thomas\n\
+            5 LANG en\n\
+            4 TEXT <b>Geboren</b>\n\
+            5 MIME text/html\n\
+            5 LANG de\n\
+            3 EVEN BIRT\n\
+            4 PHRASE Birth entry\n\
+            4 ROLE OTHER\n\
+            5 PHRASE Midwife\n\
+            3 NOTE First\n\
+            3 SNOTE @N1@\n\
+            0 @S1@ SOUR\n\
+            0 @N1@ SNOTE Shared\n\
+            0 TRLR";
+
+        let mut doc = Gedcom::new(sample.chars()).unwrap();
+        let data = doc.parse_data().unwrap();
+
+        let sour = &data.individuals[0].events[0].citations[0];
+        let citation_data = sour.data.as_ref().unwrap();
+        assert_eq!(
+            citation_data.date.as_ref().unwrap().value.as_deref(),
+            Some("1 JAN 1900")
+        );
+        assert_eq!(citation_data.texts.len(), 2);
+        assert_eq!(citation_data.texts[0].language.as_deref(), Some("en"));
+        assert_eq!(citation_data.texts[1].mime.as_deref(), Some("text/html"));
+        assert_eq!(sour.event_type.as_deref(), Some("BIRT"));
+        assert_eq!(sour.event_phrase.as_deref(), Some("Birth entry"));
+        assert_eq!(sour.role.as_deref(), Some("OTHER"));
+        assert_eq!(sour.role_phrase.as_deref(), Some("Midwife"));
+        assert_eq!(sour.notes.len(), 2);
+        assert!(sour.notes[1].is_shared());
+        assert!(sour.custom_data.is_empty());
+    }
 }
@@ -2,9 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{date::Date, source::text::Text},
+    types::{custom::UserDefinedTag, date::Date, source::text::Text},
     GedcomError,
 };
 
@@ -15,8 +15,12 @@ use crate::{
 #[derive(Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct SourceCitationData {
+    /// The date the entry was recorded in the source (tag: DATE).
     pub date: Option<Date>,
-    pub text: Option<Text>,
+    /// Verbatim excerpts of the source (tag: TEXT). Both versions allow several.
+    pub texts: Vec<Text>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl SourceCitationData {
@@ -29,32 +33,30 @@ impl SourceCitationData {
         tokenizer: &mut Tokenizer<'_>,
         level: u8,
     ) -> Result<SourceCitationData, GedcomError> {
-        let mut data = SourceCitationData {
-            date: None,
-            text: None,
-        };
+        let mut data = SourceCitationData::default();
         data.parse(tokenizer, level)?;
         Ok(data)
     }
+
+    pub fn add_text(&mut self, text: Text) {
+        self.texts.push(text);
+    }
 }
 
 impl Parser for SourceCitationData {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         // skip because this DATA tag should have now line value
         tokenizer.next_token()?;
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "DATE" => self.date = Some(Date::new(tokenizer, level + 1)?),
-                "TEXT" => self.text = Some(Text::new(tokenizer, level + 1)?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "TEXT" => self.add_text(Text::new(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         Ok(())
     }
@@ -2,8 +2,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
+    types::custom::UserDefinedTag,
     GedcomError,
 };
 
@@ -16,6 +17,12 @@ use crate::{
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Text {
     pub value: Option<String>,
+    /// The media type of the text (tag: MIME, GEDCOM 7.0), such as `text/html`.
+    pub mime: Option<String>,
+    /// The language of the text (tag: LANG, GEDCOM 7.0), as a BCP 47 tag.
+    pub language: Option<String>,
+    /// Custom data (extension tags).
+    pub custom_data: Vec<Box<UserDefinedTag>>,
 }
 
 impl Text {
@@ -26,7 +33,7 @@ impl Text {
     /// This function will return an error if parsing fails.
     #[allow(clippy::double_must_use)]
     pub fn new(tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<Text, GedcomError> {
-        let mut text = Text { value: None };
+        let mut text = Text::default();
         text.parse(tokenizer, level)?;
         Ok(text)
     }
@@ -37,23 +44,22 @@ impl Parser for Text {
         let mut value = String::new();
         value.push_str(&tokenizer.take_line_value()?);
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "CONC" => value.push_str(&tokenizer.take_line_value()?),
                 "CONT" => {
                     value.push('\n');
                     value.push_str(&tokenizer.take_line_value()?);
                 }
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "MIME" => self.mime = Some(tokenizer.take_line_value()?),
+                "LANG" => self.language = Some(tokenizer.take_line_value()?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
-        parse_subset(tokenizer, level, handle_subset)?;
+        self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
 
         if !value.is_empty() {
             self.value = Some(value);
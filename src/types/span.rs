@@ -0,0 +1,72 @@
+//! Source locations of parsed structures.
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// The location of a structure in the GEDCOM text it was parsed from.
+///
+/// Spans cover the structure's own line and all of its substructures. They are only
+/// recorded when [`GedcomBuilder::record_spans`](crate::GedcomBuilder::record_spans) is
+/// enabled. Byte offsets refer to the decoded UTF-8 text, so for input in another encoding
+/// they index the string produced by decoding rather than the raw bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct Span {
+    /// The 1-based line number of the structure's first line.
+    pub start_line: u32,
+    /// The 1-based line number of the structure's last line, including substructures.
+    pub end_line: u32,
+    /// The byte offset where the structure's first line starts.
+    pub start_offset: usize,
+    /// The byte offset just past the structure's last line, including its line terminator.
+    pub end_offset: usize,
+}
+
+impl Span {
+    /// Returns the byte range covered by the span.
+    #[must_use]
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        self.start_offset..self.end_offset
+    }
+
+    /// Returns true if the given 1-based line number falls within the span.
+    #[must_use]
+    pub fn contains_line(&self, line: u32) -> bool {
+        (self.start_line..=self.end_line).contains(&line)
+    }
+}
+
+impl std::fmt::Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start_line == self.end_line {
+            write!(f, "line {}", self.start_line)
+        } else {
+            write!(f, "lines {}-{}", self.start_line, self.end_line)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_display_and_lines() {
+        let span = Span {
+            start_line: 4,
+            end_line: 6,
+            start_offset: 30,
+            end_offset: 75,
+        };
+        assert_eq!(span.to_string(), "lines 4-6");
+        assert!(span.contains_line(5));
+        assert!(!span.contains_line(7));
+        assert_eq!(span.byte_range(), 30..75);
+
+        let single = Span {
+            end_line: 4,
+            ..span
+        };
+        assert_eq!(single.to_string(), "line 4");
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::Tokenizer,
-    types::{custom::UserDefinedTag, date::change_date::ChangeDate, note::Note, Xref},
+    types::{custom::UserDefinedTag, date::change_date::ChangeDate, note::Note, span::Span, Xref},
     GedcomError,
 };
 
@@ -41,7 +41,7 @@ pub struct Submission {
     /// Reference to who is submitting this data (optional)
     /// Points to a submitter record that contains contact information
     /// Tag: `SUBM`
-    pub submitter_ref: Option<String>,
+    pub submitter_ref: Option<Xref>,
     /// Number of generations of ancestors to include
     /// Controls the scope of ancestral data in the submission
     /// Tag: `ANCE`
@@ -61,7 +61,7 @@ pub struct Submission {
     /// Collection of note structures providing additional information
     /// Can contain multiple notes with various details about the submission
     /// Tag: `NOTE`
-    pub note: Option<Note>,
+    pub notes: Vec<Note>,
     /// When this submission record was last changed (optional) Helps track the history of
     /// modifications to your submission
     /// Tag: `CHAN`
@@ -71,6 +71,10 @@ pub struct Submission {
     /// non-standard or proprietary data associated with the submission.
     /// Tag: `_XXXX` (where XXXX is a user-defined tag)
     pub custom: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Submission {
@@ -93,8 +97,10 @@ impl Submission {
         level: u8,
         xref: Option<Xref>,
     ) -> Result<Submission, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut subn = Submission::with_xref(xref);
         subn.parse(tokenizer, level)?;
+        subn.span = tokenizer.span_end(span_start);
         Ok(subn)
     }
 }
@@ -103,23 +109,20 @@ impl Parser for Submission {
     fn parse(&mut self, tokenizer: &mut Tokenizer<'_>, level: u8) -> Result<(), GedcomError> {
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
             match tag {
                 "ANCE" => self.ancestor_generations = Some(tokenizer.take_line_value()?),
                 "CHAN" => self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?),
                 "DESC" => self.descendant_generations = Some(tokenizer.take_line_value()?),
                 "FAMF" => self.family_file_name = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "ORDI" => self.ordinance_process_flag = Some(tokenizer.take_line_value()?),
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
-                "SUBM" => self.submitter_ref = Some(tokenizer.take_line_value()?),
+                "SUBM" => self.submitter_ref = Some(tokenizer.take_line_value()?.into()),
                 "TEMP" => self.temple_code = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
-            Ok(())
+            Ok(None)
         };
 
         self.custom = parse_subset(tokenizer, level, handle_subset)?;
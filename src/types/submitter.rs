@@ -1,9 +1,9 @@
 use crate::{
-    parser::{parse_subset, Parser},
+    parser::{handle_unknown_tag, parse_subset, Parser, TagResult},
     tokenizer::{Token, Tokenizer},
     types::{
         address::Address, custom::UserDefinedTag, date::change_date::ChangeDate,
-        multimedia::link::Link, note::Note, Xref,
+        external_id::ExternalId, multimedia::link::Link, note::Note, span::Span, Xref,
     },
     GedcomError,
 };
@@ -39,8 +39,8 @@ pub struct Submitter {
     pub automated_record_id: Option<String>,
     /// Date of the last change to the record
     pub change_date: Option<ChangeDate>,
-    /// Note provided by submitter about the enclosing data
-    pub note: Option<Note>,
+    /// Notes provided by submitter about the enclosing data
+    pub notes: Vec<Note>,
     /// Phone number(s) of the submitter (tag: PHON).
     pub phone: Vec<String>,
     /// Email address(es) of the submitter (tag: EMAIL).
@@ -58,7 +58,15 @@ pub struct Submitter {
     /// A user-defined number or text that the submitter uses to identify
     /// this record.
     pub user_reference_number: Option<String>,
+    /// External identifiers (tag: EXID, GEDCOM 7.0).
+    ///
+    /// Identifiers maintained by external authorities that apply to this submitter.
+    pub external_ids: Vec<ExternalId>,
     pub custom_data: Vec<Box<UserDefinedTag>>,
+    /// The lines and byte offsets this record was parsed from.
+    ///
+    /// Only set when span recording is enabled on the builder.
+    pub span: Option<Span>,
 }
 
 impl Submitter {
@@ -81,8 +89,10 @@ impl Submitter {
         level: u8,
         xref: Option<Xref>,
     ) -> Result<Submitter, GedcomError> {
+        let span_start = tokenizer.span_start();
         let mut subm = Submitter::with_xref(xref);
         subm.parse(tokenizer, level)?;
+        subm.span = tokenizer.span_end(span_start);
         Ok(subm)
     }
 
@@ -98,10 +108,10 @@ impl Parser for Submitter {
         // skip over SUBM tag name
         tokenizer.next_token()?;
 
-        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> Result<(), GedcomError> {
-            let mut pointer: Option<String> = None;
+        let handle_subset = |tag: &str, tokenizer: &mut Tokenizer<'_>| -> TagResult {
+            let mut pointer: Option<Xref> = None;
             if let Token::Pointer(xref) = &tokenizer.current_token {
-                pointer = Some(xref.to_string());
+                pointer = Some(Xref::from(xref.as_ref()));
                 tokenizer.next_token()?;
             }
             match tag {
@@ -109,7 +119,7 @@ impl Parser for Submitter {
                 "ADDR" => self.address = Some(Address::new(tokenizer, level + 1)?),
                 "OBJE" => self.add_multimedia(Link::new(tokenizer, level + 1, pointer)?),
                 "LANG" => self.language = Some(tokenizer.take_line_value()?),
-                "NOTE" => self.note = Some(Note::new(tokenizer, level + 1)?),
+                "NOTE" | "SNOTE" => self.notes.push(Note::new(tokenizer, level + 1)?),
                 "CHAN" => self.change_date = Some(ChangeDate::new(tokenizer, level + 1)?),
                 "PHON" => self.phone.push(tokenizer.take_line_value()?),
                 "EMAIL" => self.email.push(tokenizer.take_line_value()?),
@@ -119,13 +129,13 @@ impl Parser for Submitter {
                 "RIN" => self.automated_record_id = Some(tokenizer.take_line_value()?),
                 "RFN" => self.registered_refn = Some(tokenizer.take_line_value()?),
                 "REFN" => self.user_reference_number = Some(tokenizer.take_line_value()?),
-                _ => {
-                    // Gracefully skip unknown tags
-                    tokenizer.take_line_value()?;
-                }
+                "EXID" => self
+                    .external_ids
+                    .push(ExternalId::from_tokenizer(tokenizer, level + 1)?),
+                _ => return handle_unknown_tag(tokenizer, tag, level + 1),
             }
 
-            Ok(())
+            Ok(None)
         };
 
         self.custom_data = parse_subset(tokenizer, level, handle_subset)?;
@@ -0,0 +1,423 @@
+//! Cross-reference identifiers.
+//!
+//! Every level-0 record except the header and trailer can carry a cross-reference identifier
+//! (xref), and pointers such as `1 FAMC @F1@` refer to records by it. An [`Xref`] keeps the
+//! identifier exactly as written in the file, at-signs included, so lenient parsing never
+//! loses data; [`Xref::parse`] and [`Xref::validate`] check it against the rules of a GEDCOM
+//! version.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ged_io::{types::Xref, GedcomVersion};
+//!
+//! let xref = Xref::parse("I1").unwrap();
+//! assert_eq!(xref, "@I1@");
+//! assert_eq!(format!("{xref}"), "@I1@");
+//! assert_eq!(format!("{xref:#}"), "I1");
+//! assert!(xref.validate(&GedcomVersion::V7_0).is_ok());
+//!
+//! // GEDCOM 7.0 only allows uppercase letters, digits and underscores
+//! let xref = Xref::parse("@i-1@").unwrap();
+//! assert!(xref.validate(&GedcomVersion::V5_5_1).is_ok());
+//! assert!(xref.validate(&GedcomVersion::V7_0).is_err());
+//! ```
+
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Deref,
+    str::FromStr,
+};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::{record_index::RecordKind, GedcomError, GedcomVersion};
+
+/// The longest identifier, without at-signs, that GEDCOM 5.5.1 allows.
+const V5_MAX_ID_LENGTH: usize = 20;
+
+/// A cross-reference identifier such as `@I1@`.
+///
+/// The value is stored as written, normally including the surrounding at-signs. Values built
+/// with `From` are taken verbatim so that malformed identifiers in real-world files survive a
+/// round trip; use [`Xref::parse`] to build a normalized, well-formed identifier.
+///
+/// `Xref` dereferences to `str` and compares equal to string slices, so it can be passed to
+/// the `find_*` lookups and compared with literals directly.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Xref(String);
+
+impl Xref {
+    /// The reserved GEDCOM 7.0 identifier for a pointer to nothing.
+    pub const VOID: &'static str = "@VOID@";
+
+    /// Parses an identifier, adding the surrounding at-signs when they are missing.
+    ///
+    /// Both `I1` and `@I1@` yield `@I1@`. The identifier must be non-empty and must not
+    /// contain at-signs, whitespace or control characters; use [`Xref::validate`] for the
+    /// stricter rules of a specific version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::InvalidValueFormat` if the identifier is malformed.
+    pub fn parse(value: &str) -> Result<Xref, GedcomError> {
+        let id = strip_at_signs(value.trim());
+        if id.is_empty()
+            || id
+                .chars()
+                .any(|c| c == '@' || c.is_whitespace() || c.is_control())
+        {
+            return Err(invalid(value, "an identifier such as @I1@"));
+        }
+        Ok(Xref(format!("@{id}@")))
+    }
+
+    /// Checks the identifier against the rules of a GEDCOM version.
+    ///
+    /// - GEDCOM 7.0: `@`, one or more uppercase letters, digits or underscores, `@`.
+    /// - GEDCOM 5.5.1 and earlier: `@`, an alphanumeric character followed by any characters
+    ///   other than `@`, `@`, with at most 20 characters between the at-signs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GedcomError::InvalidValueFormat` describing the rule that was broken.
+    pub fn validate(&self, version: &GedcomVersion) -> Result<(), GedcomError> {
+        let Some(id) = self
+            .0
+            .strip_prefix('@')
+            .and_then(|rest| rest.strip_suffix('@'))
+            .filter(|id| !id.is_empty())
+        else {
+            return Err(invalid(&self.0, "an identifier enclosed in at-signs"));
+        };
+
+        if version.is_v7() {
+            if !id
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            {
+                return Err(invalid(
+                    &self.0,
+                    "uppercase letters, digits and underscores (GEDCOM 7.0)",
+                ));
+            }
+        } else {
+            if !id.chars().next().is_some_and(char::is_alphanumeric) || id.contains('@') {
+                return Err(invalid(
+                    &self.0,
+                    "an alphanumeric character followed by any characters except @ (GEDCOM 5.5.1)",
+                ));
+            }
+            if id.chars().count() > V5_MAX_ID_LENGTH {
+                return Err(invalid(&self.0, "at most 20 characters (GEDCOM 5.5.1)"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the identifier satisfies the rules of the given GEDCOM version.
+    #[must_use]
+    pub fn is_valid_for(&self, version: &GedcomVersion) -> bool {
+        self.validate(version).is_ok()
+    }
+
+    /// Returns the identifier as written, normally with its at-signs (`@I1@`).
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the identifier without its surrounding at-signs (`I1`).
+    #[must_use]
+    pub fn id(&self) -> &str {
+        strip_at_signs(&self.0)
+    }
+
+    /// Returns true if this is the GEDCOM 7.0 `@VOID@` pointer.
+    #[must_use]
+    pub fn is_void(&self) -> bool {
+        self.0 == Self::VOID
+    }
+
+    /// Returns true if `other` names the same identifier, with or without at-signs.
+    #[must_use]
+    pub fn matches(&self, other: &str) -> bool {
+        self.id() == strip_at_signs(other)
+    }
+
+    /// Consumes the identifier, returning the string as written.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// Removes one pair of surrounding at-signs, if present.
+pub(crate) fn strip_at_signs(value: &str) -> &str {
+    value
+        .strip_prefix('@')
+        .and_then(|rest| rest.strip_suffix('@'))
+        .unwrap_or(value)
+}
+
+fn invalid(value: &str, expected_format: &str) -> GedcomError {
+    GedcomError::InvalidValueFormat {
+        line: 0,
+        value: value.to_string(),
+        expected_format: expected_format.to_string(),
+    }
+}
+
+impl fmt::Display for Xref {
+    /// Writes the identifier with its at-signs, or without them in the alternate form
+    /// (`{:#}`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.id())
+        } else {
+            f.write_str(&self.0)
+        }
+    }
+}
+
+impl FromStr for Xref {
+    type Err = GedcomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Xref::parse(s)
+    }
+}
+
+impl From<String> for Xref {
+    fn from(value: String) -> Self {
+        Xref(value)
+    }
+}
+
+impl From<&str> for Xref {
+    fn from(value: &str) -> Self {
+        Xref(value.to_string())
+    }
+}
+
+impl From<&String> for Xref {
+    fn from(value: &String) -> Self {
+        Xref(value.clone())
+    }
+}
+
+impl From<Xref> for String {
+    fn from(value: Xref) -> Self {
+        value.0
+    }
+}
+
+impl Deref for Xref {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Xref {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Xref {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Xref {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Xref {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Xref {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Xref> for str {
+    fn eq(&self, other: &Xref) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<Xref> for &str {
+    fn eq(&self, other: &Xref) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<Xref> for String {
+    fn eq(&self, other: &Xref) -> bool {
+        *self == other.0
+    }
+}
+
+/// Mints cross-reference identifiers that are not yet used in a dataset.
+///
+/// Obtain one from [`GedcomData::xref_allocator`](crate::types::GedcomData::xref_allocator),
+/// which reserves every identifier already present. Identifiers are numbered per prefix
+/// (`@I1@`, `@I2@`, ... for individuals), skipping any that are taken, and are valid for both
+/// GEDCOM 5.5.1 and 7.0.
+///
+/// # Example
+///
+/// ```rust
+/// use ged_io::{record_index::RecordKind, Gedcom};
+///
+/// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n0 @I3@ INDI\n0 TRLR";
+/// let data = Gedcom::new(source.chars()).unwrap().parse_data().unwrap();
+///
+/// let mut xrefs = data.xref_allocator();
+/// assert_eq!(xrefs.allocate(&RecordKind::Individual), "@I2@");
+/// assert_eq!(xrefs.allocate(&RecordKind::Individual), "@I4@");
+/// assert_eq!(xrefs.allocate(&RecordKind::Family), "@F1@");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct XrefAllocator {
+    /// Identifiers in use, without at-signs
+    used: HashSet<String>,
+    /// The last number tried for each prefix
+    counters: HashMap<String, u32>,
+}
+
+impl XrefAllocator {
+    /// Creates an allocator with no identifiers reserved.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks an identifier as used so it is never minted.
+    pub fn reserve(&mut self, xref: &str) {
+        self.used.insert(strip_at_signs(xref).to_string());
+    }
+
+    /// Returns true if the identifier is already used or minted.
+    #[must_use]
+    pub fn is_used(&self, xref: &str) -> bool {
+        self.used.contains(strip_at_signs(xref))
+    }
+
+    /// Mints an unused identifier for a record of the given kind.
+    ///
+    /// Prefixes are `I` (individuals), `F` (families), `S` (sources), `R` (repositories),
+    /// `U` (submitters), `SUBN` (submissions), `O` (multimedia), `N` (shared notes) and `H`
+    /// (headers). Custom records use their tag without the leading underscore.
+    pub fn allocate(&mut self, kind: &RecordKind) -> Xref {
+        let prefix = match kind {
+            RecordKind::Header => "H".to_string(),
+            RecordKind::Individual => "I".to_string(),
+            RecordKind::Family => "F".to_string(),
+            RecordKind::Source => "S".to_string(),
+            RecordKind::Repository => "R".to_string(),
+            RecordKind::Submitter => "U".to_string(),
+            RecordKind::Submission => "SUBN".to_string(),
+            RecordKind::Multimedia => "O".to_string(),
+            RecordKind::SharedNote => "N".to_string(),
+            RecordKind::Custom(tag) => tag.trim_start_matches('_').to_ascii_uppercase(),
+        };
+        self.allocate_with_prefix(&prefix)
+    }
+
+    /// Mints an unused identifier made of `prefix` followed by a number.
+    pub fn allocate_with_prefix(&mut self, prefix: &str) -> Xref {
+        let counter = self.counters.entry(prefix.to_string()).or_insert(0);
+        loop {
+            *counter += 1;
+            let id = format!("{prefix}{counter}");
+            if self.used.insert(id.clone()) {
+                return Xref(format!("@{id}@"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_normalizes_at_signs() {
+        assert_eq!(Xref::parse("I1").unwrap(), "@I1@");
+        assert_eq!(Xref::parse("@I1@").unwrap(), "@I1@");
+        assert_eq!(Xref::parse(" @F12@ ").unwrap(), "@F12@");
+        assert!(Xref::parse("").is_err());
+        assert!(Xref::parse("@@").is_err());
+        assert!(Xref::parse("@I 1@").is_err());
+        assert!(Xref::parse("@I@1@").is_err());
+    }
+
+    #[test]
+    fn test_validate_by_version() {
+        let v5 = GedcomVersion::V5_5_1;
+        let v7 = GedcomVersion::V7_0;
+
+        let xref = Xref::from("@I_1@");
+        assert!(xref.is_valid_for(&v5));
+        assert!(xref.is_valid_for(&v7));
+
+        let xref = Xref::from("@i1@");
+        assert!(xref.is_valid_for(&v5));
+        assert!(!xref.is_valid_for(&v7));
+
+        let xref = Xref::from("@ABCDEFGHIJKLMNOPQRSTU@");
+        assert!(!xref.is_valid_for(&v5));
+        assert!(xref.is_valid_for(&v7));
+
+        let xref = Xref::from("@_I1@");
+        assert!(!xref.is_valid_for(&v5));
+        assert!(xref.is_valid_for(&v7));
+
+        assert!(!Xref::from("I1").is_valid_for(&v7));
+        assert!(Xref::from(Xref::VOID).is_void());
+    }
+
+    #[test]
+    fn test_display_with_and_without_at_signs() {
+        let xref = Xref::from("@N7@");
+        assert_eq!(xref.to_string(), "@N7@");
+        assert_eq!(format!("{xref:#}"), "N7");
+        assert_eq!(xref.id(), "N7");
+        assert!(xref.matches("N7"));
+        assert!(xref.matches("@N7@"));
+        assert!(!xref.matches("N70"));
+    }
+
+    #[test]
+    fn test_allocator_skips_used_identifiers() {
+        let mut allocator = XrefAllocator::new();
+        allocator.reserve("@I1@");
+        allocator.reserve("I2");
+        allocator.reserve("@S1@");
+
+        assert_eq!(allocator.allocate(&RecordKind::Individual), "@I3@");
+        assert_eq!(allocator.allocate(&RecordKind::Source), "@S2@");
+        assert_eq!(allocator.allocate_with_prefix("X"), "@X1@");
+        assert_eq!(
+            allocator.allocate(&RecordKind::Custom("_PLAC".to_string())),
+            "@PLAC1@"
+        );
+        assert!(allocator.is_used("@I3@"));
+    }
+}
@@ -590,6 +590,17 @@ pub fn needs_at_escaping(value: &str, is_gedcom_7: bool) -> bool {
     }
 }
 
+/// Returns true if a payload is a pointer such as `@N1@` rather than text.
+///
+/// Text starting with an escaped `@@` is never a pointer.
+pub(crate) fn is_pointer(value: &str) -> bool {
+    value.len() > 2
+        && value.starts_with('@')
+        && value.ends_with('@')
+        && !value.starts_with("@@")
+        && !value.contains(char::is_whitespace)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
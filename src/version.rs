@@ -171,6 +171,126 @@ impl GedcomVersion {
         !self.is_v7()
     }
 
+    /// Returns whether this version supports the `EXID` (external identifier) structure.
+    ///
+    /// External identifiers were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_external_ids(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `UID` (unique identifier) structure.
+    ///
+    /// Unique identifiers are a standard structure only in GEDCOM 7.0+.
+    #[must_use]
+    pub fn supports_uid(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `RIN` (automated record ID) structure.
+    ///
+    /// GEDCOM 7.0 replaced `RIN` with `EXID` and `UID`.
+    #[must_use]
+    pub fn supports_automated_record_id(&self) -> bool {
+        !self.is_v7()
+    }
+
+    /// Returns whether this version supports the `CREA` (creation date) structure.
+    ///
+    /// Creation dates were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_creation_date(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `PHRASE` substructure.
+    ///
+    /// Phrases for dates, ages, pointers and enumerations were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_phrase(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `SDATE` (sort date) structure.
+    ///
+    /// Sort dates were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_sort_date(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `NO` (non-event) structure.
+    ///
+    /// Non-events were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_non_events(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `INIL` (initiatory) LDS ordinance.
+    ///
+    /// The initiatory ordinance was added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_initiatory(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `RESN` (restriction notice) on multimedia records.
+    ///
+    /// GEDCOM 5.5.1 only allows restrictions on individuals, families and events.
+    #[must_use]
+    pub fn supports_multimedia_restriction(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `ASSO` (association) under events.
+    ///
+    /// GEDCOM 5.5.1 only allows associations on individuals.
+    #[must_use]
+    pub fn supports_event_associations(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `ROLE` of an association.
+    ///
+    /// GEDCOM 5.5.1 describes associations with a free-text `RELA` instead.
+    #[must_use]
+    pub fn supports_association_roles(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `MIME`, `LANG` and `TRAN` of note and text payloads and multimedia files.
+    ///
+    /// Text formats and translations were added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_translations(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `CROP` substructure of multimedia links.
+    ///
+    /// Cropping was added in GEDCOM 7.0.
+    #[must_use]
+    pub fn supports_crop(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether this version supports the `MEDI` tag for the medium of a multimedia format.
+    ///
+    /// GEDCOM 5.5.1 tags the medium of a file format `TYPE`.
+    #[must_use]
+    pub fn supports_medium_tag(&self) -> bool {
+        self.is_v7()
+    }
+
+    /// Returns whether name types are written in upper case.
+    ///
+    /// GEDCOM 5.5.1 spells name types such as `birth` in lower case.
+    #[must_use]
+    pub fn uses_uppercase_name_types(&self) -> bool {
+        self.is_v7()
+    }
+
     /// Returns the major version number.
     #[must_use]
     pub fn major(&self) -> u8 {
@@ -313,6 +433,36 @@ pub struct VersionFeatures {
     pub double_all_at_signs: bool,
     /// Whether `CHAR` encoding tag is supported
     pub char_encoding_supported: bool,
+    /// Whether `EXID` (external identifier) is supported
+    pub external_ids_supported: bool,
+    /// Whether `UID` (unique identifier) is supported
+    pub uid_supported: bool,
+    /// Whether `RIN` (automated record ID) is supported
+    pub automated_record_id_supported: bool,
+    /// Whether `CREA` (creation date) is supported
+    pub creation_date_supported: bool,
+    /// Whether `PHRASE` is supported
+    pub phrase_supported: bool,
+    /// Whether `SDATE` (sort date) is supported
+    pub sort_date_supported: bool,
+    /// Whether `NO` (non-event) is supported
+    pub non_events_supported: bool,
+    /// Whether the `INIL` (initiatory) ordinance is supported
+    pub initiatory_supported: bool,
+    /// Whether `RESN` (restriction notice) is supported on multimedia records
+    pub multimedia_restriction_supported: bool,
+    /// Whether `ASSO` (association) is supported under events
+    pub event_associations_supported: bool,
+    /// Whether associations have a `ROLE` (vs a `RELA` description)
+    pub association_roles_supported: bool,
+    /// Whether `MIME`, `LANG` and `TRAN` are supported on text payloads and files
+    pub translations_supported: bool,
+    /// Whether `CROP` is supported
+    pub crop_supported: bool,
+    /// Whether the medium of a format is tagged `MEDI` (vs `TYPE`)
+    pub medium_tag_supported: bool,
+    /// Whether name types are upper case
+    pub uppercase_name_types: bool,
 }
 
 impl From<GedcomVersion> for VersionFeatures {
@@ -325,6 +475,21 @@ impl From<GedcomVersion> for VersionFeatures {
             utf8_required: version.requires_utf8(),
             double_all_at_signs: version.doubles_all_at_signs(),
             char_encoding_supported: version.supports_char_encoding(),
+            external_ids_supported: version.supports_external_ids(),
+            uid_supported: version.supports_uid(),
+            automated_record_id_supported: version.supports_automated_record_id(),
+            creation_date_supported: version.supports_creation_date(),
+            phrase_supported: version.supports_phrase(),
+            sort_date_supported: version.supports_sort_date(),
+            non_events_supported: version.supports_non_events(),
+            initiatory_supported: version.supports_initiatory(),
+            multimedia_restriction_supported: version.supports_multimedia_restriction(),
+            event_associations_supported: version.supports_event_associations(),
+            association_roles_supported: version.supports_association_roles(),
+            translations_supported: version.supports_translations(),
+            crop_supported: version.supports_crop(),
+            medium_tag_supported: version.supports_medium_tag(),
+            uppercase_name_types: version.uses_uppercase_name_types(),
         }
     }
 }
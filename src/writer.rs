@@ -18,23 +18,31 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! To write to a file, socket or compressor without building the whole text in memory, use
+//! [`GedcomWriter::write_to_io`], or [`GedcomStreamWriter`] to write records one at a time.
 
+use crate::encoding::{encode_into, GedcomEncoding, UnmappableCharacters};
+use crate::stream::GedcomRecord;
 use crate::types::{
     address::Address,
     age::Age,
-    date::Date,
+    custom::UserDefinedTag,
+    date::{change_date::ChangeDate, Date},
     event::{detail::Detail as EventDetail, spouse::Spouse, Event},
-    family::Family,
+    external_id::ExternalId,
+    family::{Family, FamilyMember},
     gedcom7::{NonEvent, SortDate},
-    header::{meta::HeadMeta, schema::Schema, source::HeadSour},
+    header::{meta::HeadMeta, schema::Schema, source::HeadSour, Header},
     individual::{
+        association::{Association, Role},
         attribute::detail::AttributeDetail,
         gender::{Gender, GenderType},
-        name::Name,
+        name::{Name, NameType},
         Individual,
     },
     lds::LdsOrdinance,
-    multimedia::Multimedia,
+    multimedia::{file::Reference, format::Format, link::Link, Multimedia},
     note::Note,
     repository::Repository,
     shared_note::SharedNote,
@@ -43,20 +51,57 @@ use crate::types::{
     submitter::Submitter,
     GedcomData,
 };
+use crate::util::{escape_at_signs, is_pointer, unescape_at_signs};
+use crate::version::{GedcomVersion, VersionFeatures};
+use crate::GedcomError;
+use std::borrow::{Borrow, Cow};
 use std::fmt::Write;
-use std::io;
+use std::io::{self, BufWriter};
 
 /// Configuration options for GEDCOM writing.
 #[derive(Debug, Clone)]
 pub struct WriterConfig {
     /// Line ending to use (default: "\n")
     pub line_ending: String,
-    /// Maximum line length before CONC/CONT wrapping (default: 255, GEDCOM spec max)
+    /// Maximum length of a line before CONC wrapping (default: 255, GEDCOM spec max)
+    ///
+    /// The length covers the whole line (level, xref, tag and value, without the terminator)
+    /// and is measured in characters, or in the units of the output encoding: UTF-16 code
+    /// units and ANSEL or single-byte bytes. GEDCOM 7.0 has no `CONC` and no line length
+    /// limit, so long lines are kept whole.
     pub max_line_length: usize,
     /// Whether to include empty optional fields (default: false)
     pub include_empty_fields: bool,
     /// GEDCOM version to write (default: "5.5.1")
+    ///
+    /// Structures the version does not define are left out, and line continuation, at-sign
+    /// escaping and the header follow its rules.
     pub gedcom_version: String,
+    /// Character encoding of the bytes written by `write_to_io`, `write_to_bytes` and
+    /// stream writers (default: `None`, which writes UTF-8)
+    ///
+    /// The header `CHAR` line always declares the encoding actually written, whatever the
+    /// parsed header declared: this encoding for byte output, and UTF-8 for string output
+    /// or when no encoding is set.
+    pub encoding: Option<GedcomEncoding>,
+    /// Whether to start byte output with a byte order mark (default: false)
+    pub byte_order_mark: bool,
+    /// What to do with characters the encoding cannot represent (default: report an error)
+    pub unmappable_characters: UnmappableCharacters,
+}
+
+impl WriterConfig {
+    /// Returns the GEDCOM version being written.
+    #[must_use]
+    pub fn version(&self) -> GedcomVersion {
+        GedcomVersion::from_version_str(&self.gedcom_version)
+    }
+
+    /// Returns the capabilities of the GEDCOM version being written.
+    #[must_use]
+    pub fn features(&self) -> VersionFeatures {
+        self.version().into()
+    }
 }
 
 impl Default for WriterConfig {
@@ -66,6 +111,9 @@ impl Default for WriterConfig {
             max_line_length: 255,
             include_empty_fields: false,
             gedcom_version: "5.5.1".to_string(),
+            encoding: None,
+            byte_order_mark: false,
+            unmappable_characters: UnmappableCharacters::Error,
         }
     }
 }
@@ -89,6 +137,12 @@ impl Default for WriterConfig {
 #[derive(Debug, Clone, Default)]
 pub struct GedcomWriter {
     config: WriterConfig,
+    /// Version of the file the written data was parsed from, whose at-sign escapes the
+    /// text payloads still carry. `None` when it matches the output version.
+    source_version: Option<GedcomVersion>,
+    /// Whether text is encoded to bytes with the configured encoding, which the header's
+    /// `CHAR` then declares. String output is always UTF-8.
+    byte_output: bool,
 }
 
 impl GedcomWriter {
@@ -97,6 +151,8 @@ impl GedcomWriter {
     pub fn new() -> Self {
         Self {
             config: WriterConfig::default(),
+            source_version: None,
+            byte_output: false,
         }
     }
 
@@ -115,7 +171,9 @@ impl GedcomWriter {
         self
     }
 
-    /// Sets the maximum line length before wrapping with CONC/CONT.
+    /// Sets the maximum length of a line before wrapping its value with CONC.
+    ///
+    /// See [`WriterConfig::max_line_length`] for how the length is measured.
     #[must_use]
     pub fn max_line_length(mut self, length: usize) -> Self {
         self.config.max_line_length = length;
@@ -130,12 +188,87 @@ impl GedcomWriter {
     }
 
     /// Sets the GEDCOM version to write.
+    ///
+    /// The version decides which structures are written and how text is continued and
+    /// escaped. Writing `"7.0"` leaves out `CHAR`, `SUBN` and `CONC` and doubles only a
+    /// leading at-sign.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{GedcomBuilder, GedcomWriter};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n1 CHAR UTF-8\n\
+    ///     0 @I1@ INDI\n1 NOTE Write to me@@example.com\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_from_str(source)?;
+    ///
+    /// let output = GedcomWriter::new().gedcom_version("7.0").write_to_string(&data)?;
+    /// assert!(output.contains("2 VERS 7.0\n"));
+    /// assert!(!output.contains("CHAR"));
+    /// assert!(output.contains("1 NOTE Write to me@example.com\n"));
+    /// # Ok(())
+    /// # }
+    /// ```
     #[must_use]
     pub fn gedcom_version(mut self, version: &str) -> Self {
         self.config.gedcom_version = version.to_string();
         self
     }
 
+    /// Sets the character encoding of byte output and of the header `CHAR` line.
+    ///
+    /// This also turns the byte order mark on for UTF-16 and off otherwise; call
+    /// [`byte_order_mark`](Self::byte_order_mark) afterwards to override it. The encoding
+    /// applies to [`write_to_io`](Self::write_to_io), [`write_to_bytes`](Self::write_to_bytes)
+    /// and stream writers; string output is always UTF-8 and keeps the header's `CHAR`, or
+    /// declares `UTF-8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{GedcomBuilder, GedcomEncoding, GedcomWriter};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n1 CHAR UTF-8\n0 @I1@ INDI\n1 NAME José /Pérez/\n0 TRLR";
+    /// let data = GedcomBuilder::new().build_from_str(source)?;
+    ///
+    /// let bytes = GedcomWriter::new()
+    ///     .encoding(GedcomEncoding::Ansel)
+    ///     .write_to_bytes(&data)?;
+    /// let text = String::from_utf8_lossy(&bytes);
+    /// assert!(text.contains("1 CHAR ANSEL"));
+    /// assert!(bytes.windows(5).any(|w| w == b"Jos\xE2e"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn encoding(mut self, encoding: GedcomEncoding) -> Self {
+        self.config.encoding = Some(encoding);
+        self.config.byte_order_mark =
+            matches!(encoding, GedcomEncoding::Utf16Le | GedcomEncoding::Utf16Be);
+        self
+    }
+
+    /// Sets whether byte output starts with a byte order mark.
+    ///
+    /// Only UTF-8 and UTF-16 have one; the setting is ignored for other encodings.
+    #[must_use]
+    pub fn byte_order_mark(mut self, byte_order_mark: bool) -> Self {
+        self.config.byte_order_mark = byte_order_mark;
+        self
+    }
+
+    /// Sets what to do with characters the output encoding cannot represent.
+    ///
+    /// By default writing fails with an `InvalidData` error that wraps a
+    /// `GedcomError::EncodingError` naming the character and output line.
+    #[must_use]
+    pub fn unmappable_characters(mut self, unmappable: UnmappableCharacters) -> Self {
+        self.config.unmappable_characters = unmappable;
+        self
+    }
+
     /// Returns the current writer configuration.
     #[must_use]
     pub fn config(&self) -> &WriterConfig {
@@ -159,17 +292,24 @@ impl GedcomWriter {
     ///
     /// Returns an error if writing fails.
     pub fn write_to<W: Write>(&self, writer: &mut W, data: &GedcomData) -> Result<(), io::Error> {
+        let source = data.header.as_ref().and_then(Header::version);
+        if source.is_some() && self.source_version.is_none() {
+            return self.reading_version(source).write_to(writer, data);
+        }
+
         // Write header
-        self.write_header(writer, data)?;
+        self.write_header(writer, data.header.as_ref())?;
 
         // Write submitters
         for submitter in &data.submitters {
             self.write_submitter(writer, submitter)?;
         }
 
-        // Write submissions
-        for submission in &data.submissions {
-            self.write_submission(writer, submission)?;
+        // Write submissions (GEDCOM 5.5.1)
+        if self.config.features().submission_supported {
+            for submission in &data.submissions {
+                self.write_submission(writer, submission)?;
+            }
         }
 
         // Write individuals
@@ -202,31 +342,219 @@ impl GedcomWriter {
             self.write_shared_note(writer, shared_note)?;
         }
 
+        // Write top-level user-defined records
+        self.write_custom_data(writer, 0, &data.custom_data)?;
+
         // Write trailer (final line; do not add a line terminator after TRLR)
         self.write_trailer(writer)?;
 
         Ok(())
     }
 
+    /// Writes GEDCOM data to any type implementing `io::Write`, such as a `File`.
+    ///
+    /// Output is buffered and streamed as it is produced, so the full text is never held
+    /// in memory. The buffer is flushed before returning. Text is encoded with the
+    /// configured [`encoding`](Self::encoding).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `output`, or an `InvalidData` error if a
+    /// character cannot be encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{GedcomBuilder, GedcomWriter};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data = GedcomBuilder::new().build_from_str("0 HEAD\n1 GEDC\n2 VERS 5.5\n0 TRLR")?;
+    ///
+    /// let mut output = Vec::new();
+    /// GedcomWriter::new().write_to_io(&mut output, &data)?;
+    /// assert!(output.ends_with(b"0 TRLR"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to_io<W: io::Write>(&self, output: W, data: &GedcomData) -> io::Result<()> {
+        let mut output = IoWriter::new(BufWriter::new(output), &self.config);
+        let result = self.clone().encoding_bytes().write_to(&mut output, data);
+        output.finish(result)?;
+        Ok(())
+    }
+
+    /// Writes GEDCOM data to bytes in the configured [`encoding`](Self::encoding).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if a character cannot be encoded.
+    pub fn write_to_bytes(&self, data: &GedcomData) -> io::Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.write_to_io(&mut output, data)?;
+        Ok(output)
+    }
+
+    /// Writes a sequence of records to any type implementing `io::Write`, followed by the
+    /// trailer.
+    ///
+    /// A default header is written first unless the first record is a header. This is the
+    /// writing counterpart of [`GedcomStreamParser`](crate::GedcomStreamParser): records can
+    /// be read, transformed and written without building a [`GedcomData`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by `output`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{GedcomStreamParser, GedcomWriter};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let input = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n0 @F1@ FAM\n0 TRLR\n";
+    ///
+    /// // Keep only the individuals
+    /// let records = GedcomStreamParser::new(input.as_bytes())?
+    ///     .collect::<Result<Vec<_>, _>>()?
+    ///     .into_iter()
+    ///     .filter(|record| record.is_header() || record.is_individual());
+    ///
+    /// let mut output = Vec::new();
+    /// GedcomWriter::new().write_records(&mut output, records)?;
+    /// let output = String::from_utf8(output)?;
+    /// assert!(output.contains("0 @I1@ INDI"));
+    /// assert!(!output.contains("FAM"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_records<W, I>(&self, output: W, records: I) -> io::Result<()>
+    where
+        W: io::Write,
+        I: IntoIterator,
+        I::Item: Borrow<GedcomRecord>,
+    {
+        let mut stream = self.stream_to(output);
+        for record in records {
+            stream.write_record(record.borrow())?;
+        }
+        stream.finish()?;
+        Ok(())
+    }
+
+    /// Creates a [`GedcomStreamWriter`] that writes records to `output` as they are
+    /// produced.
+    #[must_use]
+    pub fn stream_to<W: io::Write>(&self, output: W) -> GedcomStreamWriter<W> {
+        GedcomStreamWriter::new(self.clone(), output)
+    }
+
+    /// Writes a single top-level record, without the trailer.
+    ///
+    /// This serializes records one at a time, as yielded by
+    /// [`GedcomStreamParser`](crate::GedcomStreamParser).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ged_io::{GedcomRecord, GedcomWriter};
+    /// use ged_io::types::individual::Individual;
+    ///
+    /// let mut individual = Individual::default();
+    /// individual.xref = Some("@I1@".into());
+    ///
+    /// let mut output = String::new();
+    /// GedcomWriter::new()
+    ///     .write_record(&mut output, &GedcomRecord::Individual(individual))
+    ///     .unwrap();
+    /// assert_eq!(output, "0 @I1@ INDI\n");
+    /// ```
+    pub fn write_record<W: Write>(
+        &self,
+        writer: &mut W,
+        record: &GedcomRecord,
+    ) -> Result<(), io::Error> {
+        match record {
+            GedcomRecord::Header(header) => self.write_header(writer, Some(header)),
+            GedcomRecord::Submission(_) if !self.config.features().submission_supported => Ok(()),
+            GedcomRecord::Individual(individual) => self.write_individual(writer, individual),
+            GedcomRecord::Family(family) => self.write_family(writer, family),
+            GedcomRecord::Source(source) => self.write_source(writer, source),
+            GedcomRecord::Repository(repo) => self.write_repository(writer, repo),
+            GedcomRecord::Submitter(submitter) => self.write_submitter(writer, submitter),
+            GedcomRecord::Submission(submission) => self.write_submission(writer, submission),
+            GedcomRecord::Multimedia(media) => self.write_multimedia(writer, media),
+            GedcomRecord::SharedNote(note) => self.write_shared_note(writer, note),
+            GedcomRecord::CustomData(custom) => self.write_custom_tag(writer, 0, custom),
+        }
+    }
+
+    /// Returns a copy of this writer that reads the at-sign escapes of text payloads by the
+    /// rules of `version`, the version of the file they were parsed from.
+    fn reading_version(&self, version: Option<&str>) -> Self {
+        Self {
+            config: self.config.clone(),
+            source_version: version.map(GedcomVersion::from_version_str),
+            byte_output: self.byte_output,
+        }
+    }
+
+    /// Returns a copy of this writer that declares the configured encoding in the header,
+    /// for output encoded to bytes.
+    fn encoding_bytes(mut self) -> Self {
+        self.byte_output = true;
+        self
+    }
+
+    /// Returns the header `CHAR` value of the encoding the output is written in.
+    fn output_char(&self) -> &'static str {
+        self.config
+            .encoding
+            .filter(|_| self.byte_output)
+            .map_or("UTF-8", GedcomEncoding::char_value)
+    }
+
     /// Writes the GEDCOM header.
-    fn write_header<W: Write>(&self, writer: &mut W, data: &GedcomData) -> Result<(), io::Error> {
+    fn write_header<W: Write>(
+        &self,
+        writer: &mut W,
+        header: Option<&Header>,
+    ) -> Result<(), io::Error> {
+        let features = self.config.features();
+        if features.utf8_required
+            && self
+                .config
+                .encoding
+                .is_some_and(|encoding| encoding != GedcomEncoding::Utf8)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "GEDCOM {} files must be encoded as UTF-8",
+                    self.config.gedcom_version
+                ),
+            ));
+        }
+
         self.write_line(writer, 0, "HEAD", None)?;
 
-        if let Some(ref header) = data.header {
+        if let Some(header) = header {
             // GEDC block
             if let Some(ref gedc) = header.gedcom {
                 self.write_gedcom_header(writer, gedc)?;
             } else {
                 // Write default GEDC if none exists
-                self.write_line(writer, 1, "GEDC", None)?;
-                self.write_line(writer, 2, "VERS", Some(&self.config.gedcom_version))?;
-                self.write_line(writer, 2, "FORM", Some("LINEAGE-LINKED"))?;
+                self.write_gedcom_header(writer, &HeadMeta::default())?;
             }
 
-            // Character encoding
-            if let Some(ref encoding) = header.encoding {
-                if let Some(ref value) = encoding.value {
-                    self.write_value_or_wrap(writer, 1, "CHAR", Some(value))?;
+            // Character encoding (GEDCOM 5.5.1), declaring the encoding of the output
+            if features.char_encoding_supported {
+                self.write_value_or_wrap(writer, 1, "CHAR", Some(self.output_char()))?;
+                if let Some(ref encoding) = header.encoding {
+                    self.write_custom_data(writer, 2, &encoding.custom_data)?;
                 }
             }
 
@@ -250,9 +578,16 @@ impl GedcomWriter {
                 self.write_line(writer, 1, "SUBM", Some(subm))?;
             }
 
-            // File name
-            if let Some(ref file) = header.filename {
-                self.write_value_or_wrap(writer, 1, "FILE", Some(file))?;
+            if features.submission_supported {
+                // Submission reference (GEDCOM 5.5.1)
+                if let Some(ref subn) = header.submission_tag {
+                    self.write_line(writer, 1, "SUBN", Some(subn))?;
+                }
+
+                // File name (GEDCOM 5.5.1)
+                if let Some(ref file) = header.filename {
+                    self.write_value_or_wrap(writer, 1, "FILE", Some(file))?;
+                }
             }
 
             // Copyright
@@ -265,6 +600,15 @@ impl GedcomWriter {
                 self.write_value_or_wrap(writer, 1, "LANG", Some(lang))?;
             }
 
+            // Default place form
+            if let Some(ref place) = header.place {
+                self.write_line(writer, 1, "PLAC", None)?;
+                if !place.form.is_empty() {
+                    self.write_value_or_wrap(writer, 2, "FORM", Some(&place.form.join(", ")))?;
+                }
+                self.write_custom_data(writer, 2, &place.custom_data)?;
+            }
+
             // Note
             if let Some(ref note) = header.note {
                 self.write_note(writer, 1, note)?;
@@ -272,20 +616,27 @@ impl GedcomWriter {
 
             // Schema (GEDCOM 7.0)
             if let Some(ref schema) = header.schema {
-                self.write_schema(writer, schema)?;
+                if features.schema_supported {
+                    self.write_schema(writer, schema)?;
+                }
             }
+
+            self.write_custom_data(writer, 1, &header.custom_data)?;
         } else {
             // Write minimal required header
-            self.write_line(writer, 1, "GEDC", None)?;
-            self.write_line(writer, 2, "VERS", Some(&self.config.gedcom_version))?;
-            self.write_line(writer, 2, "FORM", Some("LINEAGE-LINKED"))?;
-            self.write_value_or_wrap(writer, 1, "CHAR", Some("UTF-8"))?;
+            self.write_gedcom_header(writer, &HeadMeta::default())?;
+            if features.char_encoding_supported {
+                self.write_value_or_wrap(writer, 1, "CHAR", Some(self.output_char()))?;
+            }
         }
 
         Ok(())
     }
 
     /// Writes the GEDC header block.
+    ///
+    /// The parsed version is kept when it has the same major version as the output, so
+    /// `5.5` stays `5.5`; otherwise the configured version is declared.
     fn write_gedcom_header<W: Write>(
         &self,
         writer: &mut W,
@@ -293,18 +644,22 @@ impl GedcomWriter {
     ) -> Result<(), io::Error> {
         self.write_line(writer, 1, "GEDC", None)?;
 
-        if let Some(ref version) = gedc.version {
-            self.write_line(writer, 2, "VERS", Some(version))?;
-        } else {
-            self.write_line(writer, 2, "VERS", Some(&self.config.gedcom_version))?;
-        }
-
-        if let Some(ref form) = gedc.form {
+        let target = self.config.version();
+        let version = gedc
+            .version
+            .as_deref()
+            .filter(|v| GedcomVersion::from_version_str(v).major() == target.major())
+            .unwrap_or(&self.config.gedcom_version);
+        self.write_line(writer, 2, "VERS", Some(version))?;
+
+        // GEDCOM 7.0 has a single form and no FORM line
+        if !target.is_v7() {
+            let form = gedc.form.as_deref().unwrap_or("LINEAGE-LINKED");
             self.write_line(writer, 2, "FORM", Some(form))?;
-        } else {
-            self.write_line(writer, 2, "FORM", Some("LINEAGE-LINKED"))?;
         }
 
+        self.write_custom_data(writer, 2, &gedc.custom_data)?;
+
         Ok(())
     }
 
@@ -331,6 +686,7 @@ impl GedcomWriter {
             if let Some(ref addr) = corp.address {
                 self.write_address(writer, 3, addr)?;
             }
+            self.write_custom_data(writer, 3, &corp.custom_data)?;
         }
 
         if let Some(ref data) = source.data {
@@ -342,8 +698,11 @@ impl GedcomWriter {
             if let Some(ref copyright) = data.copyright {
                 self.write_value_or_wrap(writer, 3, "COPR", Some(copyright))?;
             }
+            self.write_custom_data(writer, 3, &data.custom_data)?;
         }
 
+        self.write_custom_data(writer, 2, &source.custom_data)?;
+
         Ok(())
     }
 
@@ -355,7 +714,7 @@ impl GedcomWriter {
     ) -> Result<(), io::Error> {
         self.write_line_with_xref(writer, 0, individual.xref.as_deref(), "INDI", None)?;
 
-        if let Some(ref name) = individual.name {
+        for name in &individual.names {
             self.write_name(writer, name)?;
         }
 
@@ -384,6 +743,14 @@ impl GedcomWriter {
         for family_link in &individual.families {
             let tag = family_link.family_link_type.to_tag();
             self.write_line(writer, 1, tag, Some(&family_link.xref))?;
+            for note in &family_link.notes {
+                self.write_note(writer, 2, note)?;
+            }
+            self.write_custom_data(writer, 2, &family_link.custom_data)?;
+        }
+
+        for association in &individual.associations {
+            self.write_association(writer, 1, association)?;
         }
 
         for citation in &individual.source {
@@ -394,17 +761,35 @@ impl GedcomWriter {
             self.write_multimedia_link(writer, 1, media)?;
         }
 
-        if let Some(ref note) = individual.note {
+        for note in &individual.notes {
             self.write_note(writer, 1, note)?;
         }
 
+        self.write_external_ids(writer, 1, &individual.external_ids)?;
+
         if let Some(ref change_date) = individual.change_date {
-            self.write_line(writer, 1, "CHAN", None)?;
-            if let Some(ref date) = change_date.date {
-                self.write_date(writer, 2, date)?;
-            }
+            self.write_change_date(writer, change_date)?;
         }
 
+        self.write_custom_data(writer, 1, &individual.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes the change date (CHAN) of a record.
+    fn write_change_date<W: Write>(
+        &self,
+        writer: &mut W,
+        change_date: &ChangeDate,
+    ) -> Result<(), io::Error> {
+        self.write_line(writer, 1, "CHAN", None)?;
+        if let Some(ref date) = change_date.date {
+            self.write_date(writer, 2, date)?;
+        }
+        for note in &change_date.notes {
+            self.write_note(writer, 2, note)?;
+        }
+        self.write_custom_data(writer, 2, &change_date.custom_data)?;
         Ok(())
     }
 
@@ -412,6 +797,18 @@ impl GedcomWriter {
     fn write_name<W: Write>(&self, writer: &mut W, name: &Name) -> Result<(), io::Error> {
         self.write_value_or_wrap(writer, 1, "NAME", name.value.as_deref())?;
 
+        if let Some(ref name_type) = name.name_type {
+            // GEDCOM 5.5.1 spells the name types in lower case
+            let value = match name_type {
+                NameType::Other(value) => value.clone(),
+                _ if !self.config.features().uppercase_name_types => {
+                    name_type.as_str().to_lowercase()
+                }
+                _ => name_type.as_str().to_string(),
+            };
+            self.write_value_or_wrap(writer, 2, "TYPE", Some(&value))?;
+        }
+
         if let Some(ref given) = name.given {
             self.write_value_or_wrap(writer, 2, "GIVN", Some(given))?;
         }
@@ -438,10 +835,12 @@ impl GedcomWriter {
         }
 
         // Note
-        if let Some(ref note) = name.note {
+        for note in &name.notes {
             self.write_note(writer, 2, note)?;
         }
 
+        self.write_custom_data(writer, 2, &name.custom_data)?;
+
         Ok(())
     }
 
@@ -463,6 +862,8 @@ impl GedcomWriter {
             self.write_citation(writer, 2, citation)?;
         }
 
+        self.write_custom_data(writer, 2, &gender.custom_data)?;
+
         Ok(())
     }
 
@@ -486,31 +887,7 @@ impl GedcomWriter {
         }
 
         if let Some(ref place) = event.place {
-            self.write_value_or_wrap(writer, level + 1, "PLAC", place.value.as_deref())?;
-            if let Some(ref form) = place.form {
-                self.write_value_or_wrap(writer, level + 2, "FORM", Some(form))?;
-            }
-            if let Some(ref map) = place.map {
-                self.write_line(writer, level + 2, "MAP", None)?;
-                if let Some(ref lat) = map.latitude {
-                    self.write_value_or_wrap(writer, level + 3, "LATI", Some(lat))?;
-                }
-                if let Some(ref lon) = map.longitude {
-                    self.write_value_or_wrap(writer, level + 3, "LONG", Some(lon))?;
-                }
-            }
-            for phonetic in &place.phonetic {
-                self.write_value_or_wrap(writer, level + 2, "FONE", Some(&phonetic.value))?;
-                if let Some(ref vtype) = phonetic.variation_type {
-                    self.write_value_or_wrap(writer, level + 3, "TYPE", Some(vtype))?;
-                }
-            }
-            for romanized in &place.romanized {
-                self.write_value_or_wrap(writer, level + 2, "ROMN", Some(&romanized.value))?;
-                if let Some(ref vtype) = romanized.variation_type {
-                    self.write_value_or_wrap(writer, level + 3, "TYPE", Some(vtype))?;
-                }
-            }
+            self.write_place(writer, level + 1, place)?;
         }
 
         if let Some(ref event_type) = event.event_type {
@@ -521,10 +898,17 @@ impl GedcomWriter {
             self.write_citation(writer, level + 1, citation)?;
         }
 
-        if let Some(ref note) = event.note {
+        for note in &event.notes {
             self.write_note(writer, level + 1, note)?;
         }
 
+        // GEDCOM 7.0: associations with the event
+        if self.config.features().event_associations_supported {
+            for association in &event.associations {
+                self.write_association(writer, level + 1, association)?;
+            }
+        }
+
         // New fields: CAUS, RESN, AGE, AGNC, RELI
         if let Some(ref cause) = event.cause {
             self.write_long_text(writer, level + 1, "CAUS", cause)?;
@@ -535,14 +919,7 @@ impl GedcomWriter {
         }
 
         if let Some(ref age) = event.age {
-            self.write_value_or_wrap(writer, level + 1, "AGE", Some(&age.to_string()))?;
-            if let Age::Numeric {
-                phrase: Some(ref phrase),
-                ..
-            } = age
-            {
-                self.write_value_or_wrap(writer, level + 2, "PHRASE", Some(phrase))?;
-            }
+            self.write_age(writer, level + 1, age)?;
         }
 
         if let Some(ref agency) = event.agency {
@@ -561,17 +938,28 @@ impl GedcomWriter {
             };
             self.write_line(writer, level + 1, tag, None)?;
             if let Some(ref age) = detail.age {
-                self.write_value_or_wrap(writer, level + 2, "AGE", Some(&age.to_string()))?;
-                if let Age::Numeric {
-                    phrase: Some(phrase),
-                    ..
-                } = age
-                {
-                    self.write_value_or_wrap(writer, level + 3, "PHRASE", Some(phrase))?;
-                }
+                self.write_age(writer, level + 2, age)?;
             }
+            self.write_custom_data(writer, level + 2, &detail.custom_data)?;
         }
 
+        self.write_custom_data(writer, level + 1, &event.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes an age, with its GEDCOM 7.0 phrase.
+    fn write_age<W: Write>(&self, writer: &mut W, level: u8, age: &Age) -> Result<(), io::Error> {
+        self.write_value_or_wrap(writer, level, "AGE", Some(&age.to_string()))?;
+        if self.config.features().phrase_supported {
+            if let Age::Numeric {
+                phrase: Some(phrase),
+                ..
+            } = age
+            {
+                self.write_value_or_wrap(writer, level + 1, "PHRASE", Some(phrase))?;
+            }
+        }
         Ok(())
     }
 
@@ -594,19 +982,24 @@ impl GedcomWriter {
             if let Some(ref lon) = map.longitude {
                 self.write_value_or_wrap(writer, level + 2, "LONG", Some(lon))?;
             }
+            self.write_custom_data(writer, level + 2, &map.custom_data)?;
         }
         for phonetic in &place.phonetic {
             self.write_value_or_wrap(writer, level + 1, "FONE", Some(&phonetic.value))?;
             if let Some(ref vtype) = phonetic.variation_type {
                 self.write_value_or_wrap(writer, level + 2, "TYPE", Some(vtype))?;
             }
+            self.write_custom_data(writer, level + 2, &phonetic.custom_data)?;
         }
         for romanized in &place.romanized {
             self.write_value_or_wrap(writer, level + 1, "ROMN", Some(&romanized.value))?;
             if let Some(ref vtype) = romanized.variation_type {
                 self.write_value_or_wrap(writer, level + 2, "TYPE", Some(vtype))?;
             }
+            self.write_custom_data(writer, level + 2, &romanized.custom_data)?;
         }
+        self.write_external_ids(writer, level + 1, &place.external_ids)?;
+        self.write_custom_data(writer, level + 1, &place.custom_data)?;
         Ok(())
     }
 
@@ -631,10 +1024,29 @@ impl GedcomWriter {
             self.write_citation(writer, 2, citation)?;
         }
 
-        if let Some(ref note) = attr.note {
+        for note in &attr.notes {
             self.write_note(writer, 2, note)?;
         }
 
+        self.write_custom_data(writer, 2, &attr.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes a `HUSB`, `WIFE` or `CHIL` pointer of a family record.
+    fn write_family_member<W: Write>(
+        &self,
+        writer: &mut W,
+        tag: &str,
+        member: &FamilyMember,
+    ) -> Result<(), io::Error> {
+        self.write_line(writer, 1, tag, Some(&member.xref))?;
+        if self.config.features().phrase_supported {
+            if let Some(ref phrase) = member.phrase {
+                self.write_value_or_wrap(writer, 2, "PHRASE", Some(phrase))?;
+            }
+        }
+        self.write_custom_data(writer, 2, &member.custom_data)?;
         Ok(())
     }
 
@@ -642,16 +1054,15 @@ impl GedcomWriter {
     fn write_family<W: Write>(&self, writer: &mut W, family: &Family) -> Result<(), io::Error> {
         self.write_line_with_xref(writer, 0, family.xref.as_deref(), "FAM", None)?;
 
-        if let Some(ref husb) = family.individual1 {
-            self.write_line(writer, 1, "HUSB", Some(husb))?;
+        for husb in family.individuals1() {
+            self.write_family_member(writer, "HUSB", husb)?;
         }
-
-        if let Some(ref wife) = family.individual2 {
-            self.write_line(writer, 1, "WIFE", Some(wife))?;
+        for wife in family.individuals2() {
+            self.write_family_member(writer, "WIFE", wife)?;
         }
 
         for child in &family.children {
-            self.write_line(writer, 1, "CHIL", Some(child))?;
+            self.write_family_member(writer, "CHIL", child)?;
         }
 
         for event in &family.events {
@@ -680,13 +1091,14 @@ impl GedcomWriter {
             self.write_note(writer, 1, note)?;
         }
 
+        self.write_external_ids(writer, 1, &family.external_ids)?;
+
         if let Some(ref change_date) = family.change_date {
-            self.write_line(writer, 1, "CHAN", None)?;
-            if let Some(ref date) = change_date.date {
-                self.write_date(writer, 2, date)?;
-            }
+            self.write_change_date(writer, change_date)?;
         }
 
+        self.write_custom_data(writer, 1, &family.custom_data)?;
+
         Ok(())
     }
 
@@ -709,6 +1121,7 @@ impl GedcomWriter {
         // Repository citations
         for repo in &source.repo_citations {
             self.write_line(writer, 1, "REPO", Some(&repo.xref))?;
+            self.write_custom_data(writer, 2, &repo.custom_data)?;
         }
 
         // Notes
@@ -716,14 +1129,15 @@ impl GedcomWriter {
             self.write_note(writer, 1, note)?;
         }
 
+        self.write_external_ids(writer, 1, &source.external_ids)?;
+
         // Change date
         if let Some(ref change_date) = source.change_date {
-            self.write_line(writer, 1, "CHAN", None)?;
-            if let Some(ref date) = change_date.date {
-                self.write_date(writer, 2, date)?;
-            }
+            self.write_change_date(writer, change_date)?;
         }
 
+        self.write_custom_data(writer, 1, &source.custom_data)?;
+
         Ok(())
     }
 
@@ -743,6 +1157,10 @@ impl GedcomWriter {
             self.write_address(writer, 1, address)?;
         }
 
+        self.write_external_ids(writer, 1, &repo.external_ids)?;
+
+        self.write_custom_data(writer, 1, &repo.custom_data)?;
+
         Ok(())
     }
 
@@ -766,19 +1184,29 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, 1, "LANG", Some(lang))?;
         }
 
+        for link in &submitter.multimedia {
+            self.write_link(writer, 1, link)?;
+        }
+
         // Note
-        if let Some(ref note) = submitter.note {
+        for note in &submitter.notes {
             self.write_note(writer, 1, note)?;
         }
 
+        if self.config.features().automated_record_id_supported {
+            if let Some(ref rin) = submitter.automated_record_id {
+                self.write_value_or_wrap(writer, 1, "RIN", Some(rin))?;
+            }
+        }
+        self.write_external_ids(writer, 1, &submitter.external_ids)?;
+
         // Change date
         if let Some(ref change_date) = submitter.change_date {
-            self.write_line(writer, 1, "CHAN", None)?;
-            if let Some(ref date) = change_date.date {
-                self.write_date(writer, 2, date)?;
-            }
+            self.write_change_date(writer, change_date)?;
         }
 
+        self.write_custom_data(writer, 1, &submitter.custom_data)?;
+
         Ok(())
     }
 
@@ -810,6 +1238,12 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, 1, "DESC", Some(descendants))?;
         }
 
+        for note in &submission.notes {
+            self.write_note(writer, 1, note)?;
+        }
+
+        self.write_custom_data(writer, 1, &submission.custom)?;
+
         Ok(())
     }
 
@@ -819,28 +1253,187 @@ impl GedcomWriter {
         writer: &mut W,
         media: &Multimedia,
     ) -> Result<(), io::Error> {
+        let features = self.config.features();
         self.write_line_with_xref(writer, 0, media.xref.as_deref(), "OBJE", None)?;
 
-        if let Some(ref file) = media.file {
-            self.write_value_or_wrap(writer, 1, "FILE", file.value.as_deref())?;
-            if let Some(ref format) = file.form {
-                self.write_value_or_wrap(writer, 2, "FORM", format.value.as_deref())?;
+        if features.multimedia_restriction_supported {
+            if let Some(ref restriction) = media.restriction {
+                self.write_line(writer, 1, "RESN", Some(restriction))?;
             }
         }
 
+        for file in &media.files {
+            self.write_file_reference(writer, 1, file)?;
+        }
+
         if let Some(ref form) = media.form {
-            self.write_line(writer, 1, "FORM", form.value.as_deref())?;
+            self.write_format(writer, 1, form)?;
         }
 
         if let Some(ref title) = media.title {
             self.write_value_or_wrap(writer, 1, "TITL", Some(title))?;
         }
 
-        // Note
-        if let Some(ref note) = media.note_structure {
+        for refn in &media.user_reference_numbers {
+            self.write_value_or_wrap(writer, 1, "REFN", refn.value.as_deref())?;
+            if let Some(ref reference_type) = refn.user_reference_type {
+                self.write_value_or_wrap(writer, 2, "TYPE", Some(reference_type))?;
+            }
+            self.write_custom_data(writer, 2, &refn.custom_data)?;
+        }
+
+        if features.automated_record_id_supported {
+            if let Some(ref rin) = media.automated_record_id {
+                self.write_value_or_wrap(writer, 1, "RIN", Some(rin))?;
+            }
+        }
+        if features.uid_supported {
+            for uid in &media.uids {
+                self.write_line(writer, 1, "UID", Some(uid))?;
+            }
+        }
+        self.write_external_ids(writer, 1, &media.external_ids)?;
+
+        for note in &media.notes {
             self.write_note(writer, 1, note)?;
         }
 
+        for citation in &media.source_citations {
+            self.write_citation(writer, 1, citation)?;
+        }
+
+        if let Some(ref change_date) = media.change_date {
+            self.write_change_date(writer, change_date)?;
+        }
+
+        if features.creation_date_supported {
+            if let Some(ref creation_date) = media.creation_date {
+                self.write_line(writer, 1, "CREA", None)?;
+                if let Some(ref date) = creation_date.date {
+                    self.write_date(writer, 2, date)?;
+                }
+                self.write_custom_data(writer, 2, &creation_date.custom_data)?;
+            }
+        }
+
+        self.write_custom_data(writer, 1, &media.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes external identifiers (EXID), which only exist in GEDCOM 7.0.
+    fn write_external_ids<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        external_ids: &[ExternalId],
+    ) -> Result<(), io::Error> {
+        if !self.config.features().external_ids_supported {
+            return Ok(());
+        }
+
+        for exid in external_ids {
+            self.write_value_or_wrap(writer, level, "EXID", Some(&exid.id))?;
+            if let Some(ref type_uri) = exid.type_uri {
+                self.write_value_or_wrap(writer, level + 1, "TYPE", Some(type_uri))?;
+            }
+            self.write_custom_data(writer, level + 1, &exid.custom_data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a multimedia file reference (FILE) with its format, title and alternates.
+    fn write_file_reference<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        file: &Reference,
+    ) -> Result<(), io::Error> {
+        self.write_value_or_wrap(writer, level, "FILE", file.value.as_deref())?;
+
+        if let Some(ref form) = file.form {
+            self.write_format(writer, level + 1, form)?;
+        }
+
+        if let Some(ref title) = file.title {
+            self.write_value_or_wrap(writer, level + 1, "TITL", Some(title))?;
+        }
+
+        self.write_file_additions(writer, level, file)?;
+
+        self.write_custom_data(writer, level + 1, &file.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes the GEDCOM 7.0 substructures of a multimedia file reference: its crop and
+    /// translations.
+    fn write_file_additions<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        file: &Reference,
+    ) -> Result<(), io::Error> {
+        let features = self.config.features();
+        if let Some(crop) = file.crop.as_ref().filter(|_| features.crop_supported) {
+            self.write_line(writer, level + 1, "CROP", None)?;
+            for (tag, value) in [
+                ("TOP", crop.top),
+                ("LEFT", crop.left),
+                ("HEIGHT", crop.height),
+                ("WIDTH", crop.width),
+            ] {
+                if let Some(value) = value {
+                    self.write_line(writer, level + 2, tag, Some(&value.to_string()))?;
+                }
+            }
+            self.write_custom_data(writer, level + 2, &crop.custom_data)?;
+        }
+
+        if !features.translations_supported {
+            return Ok(());
+        }
+        for translation in &file.translations {
+            self.write_value_or_wrap(writer, level + 1, "TRAN", translation.value.as_deref())?;
+            if let Some(ref form) = translation.form {
+                self.write_line(writer, level + 2, "FORM", form.value.as_deref())?;
+                self.write_custom_data(writer, level + 3, &form.custom_data)?;
+            }
+            self.write_custom_data(writer, level + 2, &translation.custom_data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a multimedia format (FORM) and its source medium.
+    fn write_format<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        form: &Format,
+    ) -> Result<(), io::Error> {
+        self.write_line(writer, level, "FORM", form.value.as_deref())?;
+
+        if let Some(ref medium) = form.source_media_type {
+            // 5.5.1 names the medium of a file format TYPE; 7.0 renamed it to MEDI
+            let features = self.config.features();
+            let tag = if features.medium_tag_supported {
+                "MEDI"
+            } else {
+                "TYPE"
+            };
+            self.write_line(writer, level + 1, tag, Some(medium))?;
+            if features.phrase_supported {
+                if let Some(ref phrase) = form.source_media_phrase {
+                    self.write_value_or_wrap(writer, level + 2, "PHRASE", Some(phrase))?;
+                }
+            }
+            self.write_custom_data(writer, level + 2, &form.source_media_custom_data)?;
+        }
+
+        self.write_custom_data(writer, level + 1, &form.custom_data)?;
+
         Ok(())
     }
 
@@ -855,8 +1448,8 @@ impl GedcomWriter {
             self.write_line(writer, level, "OBJE", Some(xref))?;
         } else {
             self.write_line(writer, level, "OBJE", None)?;
-            if let Some(ref file) = media.file {
-                self.write_value_or_wrap(writer, level + 1, "FILE", file.value.as_deref())?;
+            for file in &media.files {
+                self.write_file_reference(writer, level + 1, file)?;
             }
             if let Some(ref title) = media.title {
                 self.write_value_or_wrap(writer, level + 1, "TITL", Some(title))?;
@@ -865,6 +1458,82 @@ impl GedcomWriter {
         Ok(())
     }
 
+    /// Writes a multimedia link of a submitter record.
+    fn write_link<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        link: &Link,
+    ) -> Result<(), io::Error> {
+        self.write_line(writer, level, "OBJE", link.xref.as_deref())?;
+        if let Some(ref file) = link.file {
+            self.write_file_reference(writer, level + 1, file)?;
+        }
+        if let Some(ref form) = link.form {
+            self.write_format(writer, level + 1, form)?;
+        }
+        if let Some(ref title) = link.title {
+            self.write_value_or_wrap(writer, level + 1, "TITL", Some(title))?;
+        }
+        self.write_custom_data(writer, level + 1, &link.custom_data)?;
+        Ok(())
+    }
+
+    /// Writes an association, as `RELA` for 5.x and as `ROLE` with its `PHRASE` for 7.x.
+    ///
+    /// A `RELA` description is mapped to a role when writing 7.x, and a role is described in
+    /// words when writing 5.x.
+    fn write_association<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        association: &Association,
+    ) -> Result<(), io::Error> {
+        let features = self.config.features();
+        self.write_line(writer, level, "ASSO", Some(&association.xref))?;
+
+        if features.association_roles_supported {
+            if let Some(ref phrase) = association.phrase {
+                self.write_value_or_wrap(writer, level + 1, "PHRASE", Some(phrase))?;
+            }
+            let role = association.resolved_role().unwrap_or(Role::Other);
+            self.write_line(writer, level + 1, "ROLE", Some(role.as_str()))?;
+            // Keep RELA text that the role alone does not convey
+            let phrase = association.role_phrase.as_deref().or_else(|| {
+                association
+                    .relationship
+                    .as_deref()
+                    .filter(|text| !text.trim().eq_ignore_ascii_case(role.description()))
+            });
+            if let Some(phrase) = phrase {
+                self.write_value_or_wrap(writer, level + 2, "PHRASE", Some(phrase))?;
+            }
+            self.write_custom_data(writer, level + 2, &association.role_custom_data)?;
+        } else {
+            let relationship = association
+                .role_description()
+                .or_else(|| association.role.as_ref().map(Role::description));
+            if let Some(relationship) = relationship {
+                self.write_value_or_wrap(writer, level + 1, "RELA", Some(relationship))?;
+            }
+            if let Some(ref association_type) = association.association_type {
+                self.write_value_or_wrap(writer, level + 1, "TYPE", Some(association_type))?;
+            }
+        }
+
+        for citation in &association.source_citations {
+            self.write_citation(writer, level + 1, citation)?;
+        }
+
+        for note in &association.notes {
+            self.write_note(writer, level + 1, note)?;
+        }
+
+        self.write_custom_data(writer, level + 1, &association.custom_data)?;
+
+        Ok(())
+    }
+
     /// Writes a source citation.
     fn write_citation<W: Write>(
         &self,
@@ -878,16 +1547,51 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, level + 1, "PAGE", Some(page))?;
         }
 
+        let features = self.config.features();
+
         if let Some(ref data) = citation.data {
             self.write_line(writer, level + 1, "DATA", None)?;
             if let Some(ref date) = data.date {
                 self.write_date(writer, level + 2, date)?;
             }
-            if let Some(ref text) = data.text {
+            for text in &data.texts {
                 if let Some(ref text_value) = text.value {
                     self.write_long_text(writer, level + 2, "TEXT", text_value)?;
+                    if features.translations_supported {
+                        if let Some(ref mime) = text.mime {
+                            self.write_value_or_wrap(writer, level + 3, "MIME", Some(mime))?;
+                        }
+                        if let Some(ref lang) = text.language {
+                            self.write_value_or_wrap(writer, level + 3, "LANG", Some(lang))?;
+                        }
+                    }
+                    self.write_custom_data(writer, level + 3, &text.custom_data)?;
+                }
+            }
+            self.write_custom_data(writer, level + 2, &data.custom_data)?;
+        }
+
+        if let Some(ref event_type) = citation.event_type {
+            self.write_line(writer, level + 1, "EVEN", Some(event_type))?;
+            if features.phrase_supported {
+                if let Some(ref phrase) = citation.event_phrase {
+                    self.write_value_or_wrap(writer, level + 2, "PHRASE", Some(phrase))?;
                 }
             }
+            if let Some(ref role) = citation.role {
+                self.write_line(writer, level + 2, "ROLE", Some(role))?;
+                if features.phrase_supported {
+                    if let Some(ref phrase) = citation.role_phrase {
+                        self.write_value_or_wrap(writer, level + 3, "PHRASE", Some(phrase))?;
+                    }
+                }
+                self.write_custom_data(writer, level + 3, &citation.role_custom_data)?;
+            }
+            self.write_custom_data(writer, level + 2, &citation.event_custom_data)?;
+        } else if let Some(ref role) = citation.role {
+            // A ROLE without EVEN is kept where it was found
+            self.write_line(writer, level + 1, "ROLE", Some(role))?;
+            self.write_custom_data(writer, level + 2, &citation.role_custom_data)?;
         }
 
         if let Some(ref certainty) = citation.certainty_assessment {
@@ -896,10 +1600,16 @@ impl GedcomWriter {
             }
         }
 
-        if let Some(ref note) = citation.note {
+        for media in &citation.multimedia {
+            self.write_multimedia_link(writer, level + 1, media)?;
+        }
+
+        for note in &citation.notes {
             self.write_note(writer, level + 1, note)?;
         }
 
+        self.write_custom_data(writer, level + 1, &citation.custom_data)?;
+
         Ok(())
     }
 
@@ -919,10 +1629,14 @@ impl GedcomWriter {
         }
 
         // GEDCOM 7.0: PHRASE substructure
-        if let Some(ref phrase) = date.phrase {
-            self.write_value_or_wrap(writer, level + 1, "PHRASE", Some(phrase))?;
+        if self.config.features().phrase_supported {
+            if let Some(ref phrase) = date.phrase {
+                self.write_value_or_wrap(writer, level + 1, "PHRASE", Some(phrase))?;
+            }
         }
 
+        self.write_custom_data(writer, level + 1, &date.custom_data)?;
+
         Ok(())
     }
 
@@ -935,6 +1649,8 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, 2, "TAG", Some(&payload))?;
         }
 
+        self.write_custom_data(writer, 2, &schema.custom_data)?;
+
         Ok(())
     }
 
@@ -944,41 +1660,72 @@ impl GedcomWriter {
         writer: &mut W,
         note: &SharedNote,
     ) -> Result<(), io::Error> {
-        if self.config.gedcom_version.starts_with('5') {
-            self.write_line_with_xref(writer, 0, note.xref.as_deref(), "NOTE", Some(&note.text))?;
+        let features = self.config.features();
+        let tag = if features.shared_notes_supported {
+            "SNOTE"
         } else {
-            self.write_line_with_xref(writer, 0, note.xref.as_deref(), "SNOTE", Some(&note.text))?;
-        }
+            "NOTE"
+        };
 
-        if let Some(ref mime) = note.mime {
-            self.write_value_or_wrap(writer, 1, "MIME", Some(mime))?;
+        let xref = note.xref.as_deref().unwrap_or("@X0@");
+        self.write_long_text_with_xref(writer, 0, Some(xref), tag, &note.text)?;
+
+        if features.translations_supported {
+            if let Some(ref mime) = note.mime {
+                self.write_value_or_wrap(writer, 1, "MIME", Some(mime))?;
+            }
+
+            if let Some(ref lang) = note.language {
+                self.write_value_or_wrap(writer, 1, "LANG", Some(lang))?;
+            }
+
+            for translation in &note.translations {
+                self.write_value_or_wrap(writer, 1, "TRAN", Some(&translation.text))?;
+                if let Some(ref mime) = translation.mime {
+                    self.write_value_or_wrap(writer, 2, "MIME", Some(mime))?;
+                }
+                if let Some(ref lang) = translation.language {
+                    self.write_value_or_wrap(writer, 2, "LANG", Some(lang))?;
+                }
+            }
         }
 
-        if let Some(ref lang) = note.language {
-            self.write_value_or_wrap(writer, 1, "LANG", Some(lang))?;
+        for citation in &note.source_citations {
+            self.write_citation(writer, 1, citation)?;
         }
 
-        for translation in &note.translations {
-            self.write_value_or_wrap(writer, 1, "TRAN", Some(&translation.text))?;
-            if let Some(ref mime) = translation.mime {
-                self.write_value_or_wrap(writer, 2, "MIME", Some(mime))?;
-            }
-            if let Some(ref lang) = translation.language {
-                self.write_value_or_wrap(writer, 2, "LANG", Some(lang))?;
+        self.write_external_ids(writer, 1, &note.external_ids)?;
+
+        for refn in &note.user_reference_numbers {
+            self.write_value_or_wrap(writer, 1, "REFN", refn.value.as_deref())?;
+            if let Some(ref reference_type) = refn.user_reference_type {
+                self.write_value_or_wrap(writer, 2, "TYPE", Some(reference_type))?;
             }
+            self.write_custom_data(writer, 2, &refn.custom_data)?;
         }
 
-        for exid in &note.external_ids {
-            self.write_value_or_wrap(writer, 1, "EXID", Some(&exid.id))?;
-            if let Some(ref type_uri) = exid.type_uri {
-                self.write_value_or_wrap(writer, 2, "TYPE", Some(type_uri))?;
+        if features.automated_record_id_supported {
+            if let Some(ref rin) = note.automated_record_id {
+                self.write_value_or_wrap(writer, 1, "RIN", Some(rin))?;
             }
         }
 
-        for citation in &note.source_citations {
-            self.write_citation(writer, 1, citation)?;
+        if let Some(ref change_date) = note.change_date {
+            self.write_change_date(writer, change_date)?;
         }
 
+        if features.creation_date_supported {
+            if let Some(ref creation_date) = note.creation_date {
+                self.write_line(writer, 1, "CREA", None)?;
+                if let Some(ref date) = creation_date.date {
+                    self.write_date(writer, 2, date)?;
+                }
+                self.write_custom_data(writer, 2, &creation_date.custom_data)?;
+            }
+        }
+
+        self.write_custom_data(writer, 1, &note.custom_data)?;
+
         Ok(())
     }
 
@@ -989,6 +1736,10 @@ impl GedcomWriter {
         level: u8,
         sort_date: &SortDate,
     ) -> Result<(), io::Error> {
+        if !self.config.features().sort_date_supported {
+            return Ok(());
+        }
+
         if let Some(ref value) = sort_date.value {
             self.write_value_or_wrap(writer, level, "SDATE", Some(value))?;
         }
@@ -1001,6 +1752,8 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, level + 1, "PHRASE", Some(phrase))?;
         }
 
+        self.write_custom_data(writer, level + 1, &sort_date.custom_data)?;
+
         Ok(())
     }
 
@@ -1011,13 +1764,17 @@ impl GedcomWriter {
         level: u8,
         non_event: &NonEvent,
     ) -> Result<(), io::Error> {
+        if !self.config.features().non_events_supported {
+            return Ok(());
+        }
+
         self.write_line(writer, level, "NO", Some(&non_event.event_type))?;
 
         if let Some(ref date) = non_event.date {
             self.write_date(writer, level + 1, date)?;
         }
 
-        if let Some(ref note) = non_event.note {
+        for note in &non_event.notes {
             self.write_note(writer, level + 1, note)?;
         }
 
@@ -1025,6 +1782,8 @@ impl GedcomWriter {
             self.write_citation(writer, level + 1, citation)?;
         }
 
+        self.write_custom_data(writer, level + 1, &non_event.custom_data)?;
+
         Ok(())
     }
 
@@ -1035,6 +1794,11 @@ impl GedcomWriter {
         level: u8,
         ordinance: &LdsOrdinance,
     ) -> Result<(), io::Error> {
+        // INIL only exists in GEDCOM 7.0
+        if !self.config.features().initiatory_supported && ordinance.is_gedcom_7_only() {
+            return Ok(());
+        }
+
         let tag = ordinance
             .ordinance_type
             .as_ref()
@@ -1058,7 +1822,7 @@ impl GedcomWriter {
             self.write_line(writer, level + 1, "FAMC", Some(famc))?;
         }
 
-        if let Some(ref note) = ordinance.note {
+        for note in &ordinance.notes {
             self.write_note(writer, level + 1, note)?;
         }
 
@@ -1066,6 +1830,8 @@ impl GedcomWriter {
             self.write_citation(writer, level + 1, citation)?;
         }
 
+        self.write_custom_data(writer, level + 1, &ordinance.custom_data)?;
+
         Ok(())
     }
 
@@ -1106,6 +1872,8 @@ impl GedcomWriter {
             self.write_value_or_wrap(writer, level + 1, "CTRY", Some(country))?;
         }
 
+        self.write_custom_data(writer, level + 1, &address.custom_data)?;
+
         Ok(())
     }
 
@@ -1116,16 +1884,72 @@ impl GedcomWriter {
         level: u8,
         note: &Note,
     ) -> Result<(), io::Error> {
-        if let Some(ref value) = note.value {
+        if let Some(ref xref) = note.shared_note {
+            // GEDCOM 7.0 points to shared notes with SNOTE, GEDCOM 5.5.1 with NOTE
+            let tag = if self.config.features().shared_notes_supported {
+                "SNOTE"
+            } else {
+                "NOTE"
+            };
+            self.write_line(writer, level, tag, Some(xref))?;
+        } else if let Some(ref value) = note.value {
             self.write_long_text(writer, level, "NOTE", value)?;
         } else {
             self.write_line(writer, level, "NOTE", None)?;
         }
 
+        self.write_custom_data(writer, level + 1, &note.custom_data)?;
+
+        Ok(())
+    }
+
+    /// Writes the user-defined tags (and preserved unknown structures) of a structure.
+    fn write_custom_data<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        custom_data: &[Box<UserDefinedTag>],
+    ) -> Result<(), io::Error> {
+        for custom in custom_data {
+            self.write_custom_tag(writer, level, custom)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a user-defined tag and its children.
+    fn write_custom_tag<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        custom: &UserDefinedTag,
+    ) -> Result<(), io::Error> {
+        let mut children = custom.children.as_slice();
+        if self.config.features().conc_supported {
+            self.write_value_or_wrap(writer, level, &custom.tag, custom.value.as_deref())?;
+        } else {
+            // Continuations are kept as children; fold them into the payload, since GEDCOM
+            // 7.0 has no CONC
+            let mut value = custom.value.clone();
+            while let Some((child, rest)) = children.split_first() {
+                let separator = match child.tag.as_str() {
+                    "CONC" => "",
+                    "CONT" => "\n",
+                    _ => break,
+                };
+                let text = value.get_or_insert_with(String::new);
+                text.push_str(separator);
+                text.push_str(child.value.as_deref().unwrap_or_default());
+                children = rest;
+            }
+            self.write_value_or_wrap(writer, level, &custom.tag, value.as_deref())?;
+        }
+        for child in children {
+            self.write_custom_tag(writer, level + 1, child)?;
+        }
         Ok(())
     }
 
-    /// Writes a single GEDCOM line.
+    /// Writes a single GEDCOM line, escaping at-signs unless the value is a pointer.
     fn write_line<W: Write>(
         &self,
         writer: &mut W,
@@ -1133,7 +1957,41 @@ impl GedcomWriter {
         tag: &str,
         value: Option<&str>,
     ) -> Result<(), io::Error> {
-        self.write_line_with_terminator(writer, level, tag, value, true)
+        let value = value.map(|v| self.escape_value(v));
+        self.write_line_with_terminator(writer, level, tag, value.as_deref(), true)
+    }
+
+    /// Escapes the at-signs of a line value for the output version, leaving pointers as
+    /// they are.
+    fn escape_value<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        if is_pointer(value) {
+            Cow::Borrowed(value)
+        } else {
+            self.escape_text(value)
+        }
+    }
+
+    /// Escapes the at-signs of one line of text for the output version.
+    ///
+    /// Parsed payloads keep the escapes of their source file, so those are undone first:
+    /// text is never escaped twice, and `@@` written by 5.5.1 becomes a single `@` in 7.0.
+    /// A leading escape sequence such as `@#DJULIAN@` is kept as it is.
+    fn escape_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !text.contains('@') {
+            return Cow::Borrowed(text);
+        }
+
+        let (escape, text) = match text.strip_prefix("@#").and_then(|rest| rest.find('@')) {
+            Some(end) => text.split_at(end + 3),
+            None => ("", text),
+        };
+        let is_gedcom_7 = !self.config.features().double_all_at_signs;
+        let source_is_gedcom_7 = self
+            .source_version
+            .as_ref()
+            .map_or(is_gedcom_7, |version| !version.doubles_all_at_signs());
+        let text = unescape_at_signs(text, source_is_gedcom_7);
+        Cow::Owned(format!("{escape}{}", escape_at_signs(&text, is_gedcom_7)))
     }
 
     fn write_value_or_wrap<W: Write>(
@@ -1145,7 +2003,7 @@ impl GedcomWriter {
     ) -> Result<(), io::Error> {
         match value {
             None => self.write_line(writer, level, tag, None),
-            Some(v) if v.contains('\n') || v.len() > self.config.max_line_length => {
+            Some(v) if v.contains('\n') || self.exceeds_line_length(level, tag, v) => {
                 self.write_long_text(writer, level, tag, v)
             }
             Some(v) => self.write_line(writer, level, tag, Some(v)),
@@ -1194,7 +2052,7 @@ impl GedcomWriter {
 
         if let Some(v) = value {
             if !v.is_empty() {
-                write!(writer, " {v}").map_err(io_error)?;
+                write!(writer, " {}", self.escape_value(v)).map_err(io_error)?;
             }
         }
 
@@ -1204,6 +2062,9 @@ impl GedcomWriter {
     }
 
     /// Writes long text with CONC/CONT continuation lines.
+    ///
+    /// Each line of text becomes a `CONT` line. Lines longer than the maximum line length
+    /// are split further with `CONC`, except in GEDCOM 7.0, which has no `CONC`.
     fn write_long_text<W: Write>(
         &self,
         writer: &mut W,
@@ -1211,49 +2072,101 @@ impl GedcomWriter {
         tag: &str,
         text: &str,
     ) -> Result<(), io::Error> {
+        self.write_long_text_with_xref(writer, level, None, tag, text)
+    }
+
+    /// Writes long text like [`Self::write_long_text`], with an xref before the tag of the
+    /// first line, as on a shared note record.
+    fn write_long_text_with_xref<W: Write>(
+        &self,
+        writer: &mut W,
+        level: u8,
+        xref: Option<&str>,
+        tag: &str,
+        text: &str,
+    ) -> Result<(), io::Error> {
+        let first_tag = match xref {
+            Some(xref) => Cow::Owned(format!("{xref} {tag}")),
+            None => Cow::Borrowed(tag),
+        };
+        let conc_supported = self.config.features().conc_supported;
+        let encoding = self.config.encoding.unwrap_or(GedcomEncoding::Utf8);
+        let max_len = self.config.max_line_length;
+
         for (i, line) in text.split('\n').enumerate() {
             // Empty continuation lines must still be represented explicitly with `CONT` + an empty value.
             // `CONT` means “new line”, so dropping them would merge lines.
-            let line_value = Some(line);
-            if i == 0 {
-                // First line uses the main tag
-                if line.len() <= self.config.max_line_length {
-                    self.write_line(writer, level, tag, Some(line))?;
-                } else {
-                    // Need to split with CONC
-                    let first_part = &line[..self.config.max_line_length];
-                    self.write_line(writer, level, tag, Some(first_part))?;
-
-                    let mut remaining = &line[self.config.max_line_length..];
-                    while !remaining.is_empty() {
-                        let chunk_len = std::cmp::min(remaining.len(), self.config.max_line_length);
-                        let chunk = &remaining[..chunk_len];
-                        self.write_line(writer, level + 1, "CONC", Some(chunk))?;
-                        remaining = &remaining[chunk_len..];
-                    }
-                }
-            } else {
-                // Subsequent lines use CONT
-                if line.len() <= self.config.max_line_length {
-                    self.write_line(writer, level + 1, "CONT", line_value)?;
+            let line = self.escape_text(line);
+            let mut remaining: &str = &line;
+            let mut first = true;
+            loop {
+                let (chunk_level, chunk_tag) = match (i, first) {
+                    // First line uses the main tag
+                    (0, true) => (level, &*first_tag),
+                    // Subsequent lines use CONT
+                    (_, true) => (level + 1, "CONT"),
+                    // Lines that are too long are split with CONC
+                    (_, false) => (level + 1, "CONC"),
+                };
+                let chunk_len = if conc_supported {
+                    let prefix = self.line_units(&format!("{chunk_level} {chunk_tag} "));
+                    split_point(remaining, max_len.saturating_sub(prefix), encoding)
                 } else {
-                    // Split with CONT first, then CONC
-                    let first_part = &line[..self.config.max_line_length];
-                    self.write_line(writer, level + 1, "CONT", Some(first_part))?;
-
-                    let mut remaining = &line[self.config.max_line_length..];
-                    while !remaining.is_empty() {
-                        let chunk_len = std::cmp::min(remaining.len(), self.config.max_line_length);
-                        let chunk = &remaining[..chunk_len];
-                        self.write_line(writer, level + 1, "CONC", Some(chunk))?;
-                        remaining = &remaining[chunk_len..];
-                    }
+                    remaining.len()
+                };
+                let (chunk, rest) = remaining.split_at(chunk_len);
+                self.write_line_with_terminator(writer, chunk_level, chunk_tag, Some(chunk), true)?;
+                if rest.is_empty() {
+                    break;
                 }
+                remaining = rest;
+                first = false;
             }
         }
 
         Ok(())
     }
+
+    /// Returns whether the line `{level} {tag} {value}` is longer than the maximum line length.
+    fn exceeds_line_length(&self, level: u8, tag: &str, value: &str) -> bool {
+        let prefix = format!("{level} {tag} ");
+        self.line_units(&prefix) + self.line_units(&self.escape_value(value))
+            > self.config.max_line_length
+    }
+
+    /// Returns the length of `text` in the line units of the output encoding.
+    fn line_units(&self, text: &str) -> usize {
+        let encoding = self.config.encoding.unwrap_or(GedcomEncoding::Utf8);
+        text.chars().map(|ch| encoding.line_units(ch)).sum()
+    }
+}
+
+/// Returns the byte offset at which to end a `CONC` chunk of at most `max_len` line units of
+/// `encoding`.
+///
+/// The split always falls on a character boundary. Where the text allows it, the split is not
+/// next to a space, which 5.5.1 readers may trim, and never inside an escaped `@@` pair. A chunk
+/// holds at least one character, or a whole `@@` pair.
+fn split_point(text: &str, max_len: usize, encoding: GedcomEncoding) -> usize {
+    let mut width = 0;
+    let Some((limit, _)) = text.char_indices().find(|&(_, ch)| {
+        width += encoding.line_units(ch);
+        width > max_len
+    }) else {
+        return text.len();
+    };
+
+    let bytes = text.as_bytes();
+    let splits_pair =
+        |i: usize| bytes[..i].iter().rev().take_while(|&&b| b == b'@').count() % 2 == 1;
+    let next_to_space = |i: usize| bytes[i - 1] == b' ' || bytes[i] == b' ';
+    let boundaries = || (1..=limit).rev().filter(|&i| text.is_char_boundary(i));
+
+    boundaries()
+        .find(|&i| !splits_pair(i) && !next_to_space(i))
+        .or_else(|| boundaries().find(|&i| !splits_pair(i)))
+        .or_else(|| (limit + 1..=text.len()).find(|&i| text.is_char_boundary(i) && !splits_pair(i)))
+        .unwrap_or_else(|| text.chars().next().map_or(0, char::len_utf8))
 }
 
 /// Converts a `std::fmt::Error` to an `io::Error`.
@@ -1261,6 +2174,185 @@ fn io_error(_: std::fmt::Error) -> io::Error {
     io::Error::other("formatting error")
 }
 
+/// Adapts an `io::Write` to the `fmt::Write` the record writers use, encoding the text and
+/// keeping the underlying I/O error that `fmt::Write` cannot carry.
+struct IoWriter<W: io::Write> {
+    inner: W,
+    error: Option<io::Error>,
+    encoding: GedcomEncoding,
+    unmappable: UnmappableCharacters,
+    /// The byte order mark still to be written before the first text
+    pending_bom: &'static [u8],
+    /// Reused buffer for encoded text
+    encoded: Vec<u8>,
+    /// Number of line feeds written, to locate encoding errors
+    lines: usize,
+}
+
+impl<W: io::Write> IoWriter<W> {
+    fn new(inner: W, config: &WriterConfig) -> Self {
+        let encoding = config.encoding.unwrap_or(GedcomEncoding::Utf8);
+        Self {
+            inner,
+            error: None,
+            encoding,
+            unmappable: config.unmappable_characters,
+            pending_bom: if config.byte_order_mark {
+                encoding.byte_order_mark()
+            } else {
+                &[]
+            },
+            encoded: Vec::new(),
+            lines: 0,
+        }
+    }
+
+    /// Replaces the generic formatting error in `result` with the error that caused it.
+    fn check(&mut self, result: io::Result<()>) -> io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => result,
+        }
+    }
+
+    /// Flushes the output after `result`, returning the underlying writer.
+    fn finish(mut self, result: io::Result<()>) -> io::Result<W> {
+        self.check(result)?;
+        self.inner.write_all(self.pending_bom)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_text(&mut self, s: &str) -> io::Result<()> {
+        self.inner.write_all(self.pending_bom)?;
+        self.pending_bom = &[];
+
+        if self.encoding == GedcomEncoding::Utf8 {
+            return self.inner.write_all(s.as_bytes());
+        }
+
+        self.encoded.clear();
+        if let Err(error) = encode_into(s, self.encoding, self.unmappable, &mut self.encoded) {
+            let error = match error {
+                GedcomError::EncodingError(message) => GedcomError::EncodingError(format!(
+                    "{message} on output line {}",
+                    self.lines + 1
+                )),
+                error => error,
+            };
+            return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+        }
+        self.lines += s.matches('\n').count();
+        self.inner.write_all(&self.encoded)
+    }
+}
+
+impl<W: io::Write> Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.write_text(s).map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
+    }
+}
+
+/// Writes GEDCOM records to an `io::Write` one at a time.
+///
+/// Created by [`GedcomWriter::stream_to`]. Output is buffered; call
+/// [`finish`](GedcomStreamWriter::finish) to write the trailer and flush. A default header is
+/// written before the first record unless that record is a header.
+///
+/// # Example
+///
+/// ```rust
+/// use ged_io::{GedcomRecord, GedcomStreamParser, GedcomWriter};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let input = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME John /Doe/\n1 NOTE Private\n0 TRLR\n";
+///
+/// // Strip notes from individuals on the way through
+/// let mut output = GedcomWriter::new().stream_to(Vec::new());
+/// for record in GedcomStreamParser::new(input.as_bytes())? {
+///     let mut record = record?;
+///     if let GedcomRecord::Individual(ref mut individual) = record {
+///         individual.notes.clear();
+///     }
+///     output.write_record(&record)?;
+/// }
+/// let output = String::from_utf8(output.finish()?)?;
+/// assert!(output.contains("0 @I1@ INDI\n1 NAME John /Doe/\n"));
+/// assert!(!output.contains("Private"));
+/// assert!(output.ends_with("0 TRLR"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct GedcomStreamWriter<W: io::Write> {
+    writer: GedcomWriter,
+    output: IoWriter<BufWriter<W>>,
+    wrote_header: bool,
+}
+
+impl<W: io::Write> GedcomStreamWriter<W> {
+    /// Creates a stream writer that serializes records with `writer`'s configuration.
+    #[must_use]
+    pub fn new(writer: GedcomWriter, output: W) -> Self {
+        Self {
+            output: IoWriter::new(BufWriter::new(output), &writer.config),
+            writer: writer.encoding_bytes(),
+            wrote_header: false,
+        }
+    }
+
+    /// Writes one top-level record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the output.
+    pub fn write_record(&mut self, record: &GedcomRecord) -> io::Result<()> {
+        let mut result = Ok(());
+        if let GedcomRecord::Header(header) = record {
+            self.writer = self.writer.reading_version(header.version());
+        } else if !self.wrote_header {
+            result = self.writer.write_header(&mut self.output, None);
+        }
+        self.wrote_header = true;
+        if result.is_ok() {
+            result = self.writer.write_record(&mut self.output, record);
+        }
+        self.output.check(result)
+    }
+
+    /// Writes the trailer, flushes the buffer and returns the underlying writer.
+    ///
+    /// A default header is written first if no record was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the output.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut result = Ok(());
+        if !self.wrote_header {
+            result = self.writer.write_header(&mut self.output, None);
+        }
+        if result.is_ok() {
+            result = self.writer.write_trailer(&mut self.output);
+        }
+        self.output
+            .finish(result)?
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)
+    }
+}
+
+impl<W: io::Write> std::fmt::Debug for GedcomStreamWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GedcomStreamWriter")
+            .field("writer", &self.writer)
+            .field("wrote_header", &self.wrote_header)
+            .finish_non_exhaustive()
+    }
+}
+
 // =============================================================================
 // Helper functions for tag conversion
 // =============================================================================
@@ -1374,6 +2466,21 @@ mod tests {
         assert!(output.contains("0 TRLR"));
     }
 
+    #[test]
+    fn test_write_header_declares_utf8_without_char() {
+        let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n1 SOUR App\n0 TRLR";
+        let data = GedcomBuilder::new().build_from_str(source).unwrap();
+
+        let output = GedcomWriter::new().write_to_string(&data).unwrap();
+        assert!(output.contains("1 CHAR UTF-8\n"));
+
+        let output = GedcomWriter::new()
+            .gedcom_version("7.0")
+            .write_to_string(&data)
+            .unwrap();
+        assert!(!output.contains("CHAR"));
+    }
+
     #[test]
     fn test_write_individual() {
         let source = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n1 SEX M\n0 TRLR";
@@ -1454,7 +2561,7 @@ mod tests {
         // Compare key data
         assert_eq!(data.individuals.len(), data2.individuals.len());
         assert_eq!(data.individuals[0].xref, data2.individuals[0].xref);
-        assert_eq!(data.individuals[0].name, data2.individuals[0].name);
+        assert_eq!(data.individuals[0].names, data2.individuals[0].names);
     }
 
     #[test]
@@ -1471,4 +2578,62 @@ mod tests {
         assert!(config.include_empty_fields);
         assert_eq!(config.gedcom_version, "5.5.1");
     }
+
+    #[test]
+    fn test_writer_config_features() {
+        let config = GedcomWriter::new().gedcom_version("7.0").config().clone();
+        assert_eq!(config.version(), GedcomVersion::V7_0);
+        assert_eq!(config.features(), VersionFeatures::v7_0());
+        assert_eq!(
+            WriterConfig::default().features(),
+            VersionFeatures::v5_5_1()
+        );
+    }
+
+    #[test]
+    fn test_escape_value() {
+        let v5 = GedcomWriter::new();
+        let v7 = GedcomWriter::new().gedcom_version("7.0");
+        let from_v5 = v7.reading_version(Some("5.5.1"));
+
+        assert_eq!(v5.escape_value("@I1@"), "@I1@");
+        assert_eq!(v7.escape_value("@VOID@"), "@VOID@");
+        assert_eq!(v5.escape_value("a@b"), "a@@b");
+        assert_eq!(v5.escape_value("a@@b"), "a@@b");
+        assert_eq!(v7.escape_value("a@@b"), "a@@b");
+        assert_eq!(v7.escape_value("@home"), "@@home");
+        assert_eq!(from_v5.escape_value("a@@b"), "a@b");
+        assert_eq!(from_v5.escape_value("@@home"), "@@home");
+        assert_eq!(
+            v5.escape_value("@#DJULIAN@ 1 JAN 1700"),
+            "@#DJULIAN@ 1 JAN 1700"
+        );
+    }
+
+    #[test]
+    fn test_split_point() {
+        let utf8 = GedcomEncoding::Utf8;
+        assert_eq!(split_point("abc", 5, utf8), 3);
+        assert_eq!(split_point("abcdef", 3, utf8), 3);
+
+        // Escaped at-sign pairs stay together
+        assert_eq!(split_point("ab@@cd", 3, utf8), 2);
+        assert_eq!(split_point("ab@@cd", 4, utf8), 4);
+        assert_eq!(split_point("@@cd", 0, utf8), 2);
+
+        // Splits avoid spaces where the text allows it
+        assert_eq!(split_point("ab cdef", 3, utf8), 1);
+        assert_eq!(split_point("abc def", 4, utf8), 2);
+        assert_eq!(split_point("a   b", 3, utf8), 3);
+
+        // Limits count characters, not bytes
+        assert_eq!(split_point("Привет", 3, utf8), 6);
+        assert_eq!(split_point("家族の歴史", 2, utf8), 6);
+        assert_eq!(split_point("家族", 0, utf8), 3);
+
+        // Or the units of the output encoding
+        assert_eq!(split_point("a😀bc", 3, GedcomEncoding::Utf16Le), 5);
+        assert_eq!(split_point("éab", 2, GedcomEncoding::Ansel), 2);
+        assert_eq!(split_point("ééé", 4, GedcomEncoding::Ansel), 4);
+    }
 }
@@ -0,0 +1,125 @@
+//! Tests for the asynchronous streaming parser with the `async` feature.
+
+#![cfg(feature = "async")]
+
+use futures::StreamExt;
+use ged_io::{
+    AsyncGedcomStreamParser, GedcomError, GedcomRecord, GedcomStreamParser, ParserConfig,
+};
+use std::{fs, io::BufReader};
+use tokio::io::{AsyncWriteExt, BufReader as AsyncBufReader};
+
+/// Writes `content` into one end of an in-memory pipe in small chunks and parses the
+/// other end.
+async fn parse_through_pipe(content: Vec<u8>) -> Vec<Result<GedcomRecord, GedcomError>> {
+    let (mut client, server) = tokio::io::duplex(64);
+
+    let writer = tokio::spawn(async move {
+        // The parser drops its end of the pipe after an error, so writes may fail
+        for chunk in content.chunks(37) {
+            if client.write_all(chunk).await.is_err() {
+                return;
+            }
+        }
+        let _ = client.shutdown().await;
+    });
+
+    let records = AsyncGedcomStreamParser::new(AsyncBufReader::new(server))
+        .collect::<Vec<_>>()
+        .await;
+    writer.await.unwrap();
+    records
+}
+
+#[tokio::test]
+async fn async_stream_matches_blocking_stream_on_fixtures() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/washington.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let content = fs::read(path).unwrap();
+        let blocking = GedcomStreamParser::new(BufReader::new(content.as_slice()))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let records = parse_through_pipe(content)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(records, blocking, "{path}");
+    }
+}
+
+#[tokio::test]
+async fn async_stream_handles_missing_trailer_and_final_newline() {
+    let gedcom = "\u{FEFF}0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/";
+    let records = parse_through_pipe(gedcom.as_bytes().to_vec()).await;
+
+    assert_eq!(records.len(), 2);
+    let individual = records[1].as_ref().unwrap().as_individual().unwrap();
+    assert_eq!(individual.full_name().as_deref(), Some("John Doe"));
+}
+
+#[tokio::test]
+async fn async_stream_stops_after_first_error() {
+    let gedcom = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\nbad line\n0 @I2@ INDI\n0 TRLR\n";
+    let records = parse_through_pipe(gedcom.as_bytes().to_vec()).await;
+
+    assert_eq!(records.len(), 2);
+    assert!(records[0].is_ok());
+    assert!(records[1].is_err());
+}
+
+#[tokio::test]
+async fn async_stream_parses_with_config_and_header_version() {
+    let strict = ParserConfig {
+        strict_mode: true,
+        ..ParserConfig::default()
+    };
+    let gedcom = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n0 @i2@ INDI\n0 TRLR\n";
+    let records = AsyncGedcomStreamParser::with_config(gedcom.as_bytes(), strict)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(records.len(), 3);
+    assert!(records[1].is_ok());
+    assert!(matches!(
+        records[2],
+        Err(GedcomError::InvalidValueFormat { line: 5, .. })
+    ));
+
+    let lenient = AsyncGedcomStreamParser::new(gedcom.as_bytes())
+        .collect::<Vec<_>>()
+        .await;
+    assert!(lenient.iter().all(Result::is_ok));
+}
+
+#[tokio::test]
+async fn async_stream_decodes_utf16_input() {
+    let mut content = vec![0xFF, 0xFE];
+    for unit in "0 HEAD\n0 @I1@ INDI\n1 NAME Zoë /Doe/\n0 TRLR\n".encode_utf16() {
+        content.extend_from_slice(&unit.to_le_bytes());
+    }
+    let records = parse_through_pipe(content).await;
+
+    assert_eq!(records.len(), 2);
+    let individual = records[1].as_ref().unwrap().as_individual().unwrap();
+    assert_eq!(individual.full_name().as_deref(), Some("Zoë Doe"));
+}
+
+#[tokio::test]
+async fn async_stream_decodes_ansel_input() {
+    let content = b"0 HEAD\n1 CHAR ANSEL\n0 @I1@ INDI\n1 NAME M\xB2ller /\xE8Ubel/\n0 TRLR\n";
+    let records = parse_through_pipe(content.to_vec()).await;
+
+    let individual = records[1].as_ref().unwrap().as_individual().unwrap();
+    assert_eq!(
+        individual.full_name().as_deref(),
+        Some("Møller U\u{308}bel")
+    );
+}
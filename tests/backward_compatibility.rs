@@ -68,7 +68,7 @@ fn test_default_parsing_behavior_unchanged() {
     let individual = &data.individuals[0];
     assert_eq!(individual.xref.as_ref().unwrap(), "@I1@");
     assert_eq!(
-        individual.name.as_ref().unwrap().value.as_ref().unwrap(),
+        individual.primary_name().unwrap().value.as_ref().unwrap(),
         "John /Doe/"
     );
 }
@@ -125,7 +125,7 @@ fn test_individual_access_pattern_unchanged() {
     let indi = &data.individuals[0];
     assert_eq!(indi.xref.as_ref().unwrap(), "@PERSON1@");
     assert_eq!(
-        indi.name.as_ref().unwrap().value.as_ref().unwrap(),
+        indi.primary_name().unwrap().value.as_ref().unwrap(),
         "John Doe"
     );
     assert_eq!(indi.sex.as_ref().unwrap().value.to_string(), "Male");
@@ -148,8 +148,8 @@ fn test_family_access_pattern_unchanged() {
 
     let family = &data.families[0];
     assert_eq!(family.xref.as_ref().unwrap(), "@F1@");
-    assert_eq!(family.individual1.as_ref().unwrap(), "@I1@");
-    assert_eq!(family.individual2.as_ref().unwrap(), "@I2@");
+    assert_eq!(family.individual1().unwrap(), "@I1@");
+    assert_eq!(family.individual2().unwrap(), "@I2@");
     assert_eq!(family.children.len(), 1);
     assert_eq!(family.children[0], "@I3@");
 }
@@ -223,7 +223,7 @@ fn test_both_apis_produce_same_results() {
     // Individual data should match
     for (old, new) in data_old.individuals.iter().zip(data_new.individuals.iter()) {
         assert_eq!(old.xref, new.xref);
-        assert_eq!(old.name, new.name);
+        assert_eq!(old.names, new.names);
         assert_eq!(old.sex, new.sex);
     }
 
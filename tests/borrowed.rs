@@ -0,0 +1,50 @@
+//! Tests comparing the borrowed, zero-copy parse path with the owned parser.
+
+use ged_io::{GedcomBuilder, Xref};
+use std::fs;
+
+#[test]
+fn borrowed_parse_matches_owned_parse_on_fixtures() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/washington.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let content = fs::read_to_string(path).unwrap();
+        let owned = GedcomBuilder::new().build_from_str(&content).unwrap();
+        let borrowed = GedcomBuilder::new().build_borrowed(&content).unwrap();
+
+        assert_eq!(
+            borrowed.individuals.len(),
+            owned.individuals.len(),
+            "{path}"
+        );
+        assert_eq!(borrowed.families.len(), owned.families.len(), "{path}");
+        assert_eq!(borrowed.sources.len(), owned.sources.len(), "{path}");
+        assert_eq!(
+            borrowed.repositories.len(),
+            owned.repositories.len(),
+            "{path}"
+        );
+        assert_eq!(borrowed.multimedia.len(), owned.multimedia.len(), "{path}");
+
+        for (borrowed, owned) in borrowed.individuals.iter().zip(&owned.individuals) {
+            assert_eq!(borrowed.xref(), owned.xref.as_deref());
+            assert_eq!(borrowed.to_individual().unwrap(), *owned);
+        }
+        for (borrowed, owned) in borrowed.families.iter().zip(&owned.families) {
+            assert_eq!(borrowed.husband(), owned.individual1().map(Xref::as_str));
+            assert_eq!(borrowed.to_family().unwrap(), *owned);
+        }
+    }
+}
+
+#[test]
+fn borrowed_parse_respects_max_file_size() {
+    let content = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 TRLR";
+    assert!(GedcomBuilder::new()
+        .max_file_size(10)
+        .build_borrowed(content)
+        .is_err());
+}
@@ -50,7 +50,7 @@ fn test_clone_individual() {
 
     assert_eq!(*individual, cloned);
     assert_eq!(individual.xref, cloned.xref);
-    assert_eq!(individual.name, cloned.name);
+    assert_eq!(individual.names, cloned.names);
     assert_eq!(individual.sex, cloned.sex);
 }
 
@@ -145,11 +145,11 @@ fn test_clone_for_modification() {
     assert_eq!(*original, modified);
 
     // Modify the clone
-    modified.xref = Some("@I2@".to_string());
+    modified.xref = Some("@I2@".into());
 
     // Original should be unchanged
-    assert_eq!(original.xref, Some("@I1@".to_string()));
-    assert_eq!(modified.xref, Some("@I2@".to_string()));
+    assert_eq!(original.xref, Some("@I1@".into()));
+    assert_eq!(modified.xref, Some("@I2@".into()));
 
     // They should now be different
     assert_ne!(*original, modified);
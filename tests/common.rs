@@ -35,8 +35,7 @@ mod tests {
         // names
         assert_eq!(
             data.individuals[0]
-                .name
-                .as_ref()
+                .primary_name()
                 .unwrap()
                 .value
                 .as_ref()
@@ -87,8 +86,7 @@ mod tests {
         // names
         assert_eq!(
             data.individuals[0]
-                .name
-                .as_ref()
+                .primary_name()
                 .unwrap()
                 .value
                 .as_ref()
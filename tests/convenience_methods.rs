@@ -1,6 +1,6 @@
 //! Tests for convenience methods on GEDCOM data structures (Issue #29)
 
-use ged_io::Gedcom;
+use ged_io::{indexed::IndexedGedcomData, record_index::RecordKind, Gedcom, GedcomVersion, Xref};
 
 // ============================================================================
 // GedcomData convenience method tests
@@ -46,7 +46,7 @@ fn test_find_family() {
 
     let found = data.find_family("@F1@");
     assert!(found.is_some());
-    assert_eq!(found.unwrap().individual1, Some("@I1@".to_string()));
+    assert_eq!(found.unwrap().individual1().unwrap(), "@I1@");
 
     let not_found = data.find_family("@F999@");
     assert!(not_found.is_none());
@@ -245,6 +245,31 @@ fn test_search_individuals_by_name() {
     assert_eq!(results.len(), 0);
 }
 
+#[test]
+fn test_search_individuals_by_any_name() {
+    let sample = "\
+        0 HEAD\n\
+        1 GEDC\n\
+        2 VERS 5.5\n\
+        0 @I1@ INDI\n\
+        1 NAME Jane /Doe/\n\
+        1 NAME Jane /Smith/\n\
+        2 TYPE married\n\
+        0 @I2@ INDI\n\
+        1 NAME Bob /Smith/\n\
+        0 TRLR";
+
+    let mut gedcom = Gedcom::new(sample.chars()).unwrap();
+    let data = gedcom.parse_data().unwrap();
+
+    let results = data.search_individuals_by_name("smith");
+    assert_eq!(results.len(), 2);
+
+    let results = data.search_individuals_by_name("doe");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].surname(), Some("Doe"));
+}
+
 #[test]
 fn test_total_records() {
     let sample = "\
@@ -460,3 +485,58 @@ fn test_complex_family_navigation() {
     let childs_families = data.get_families_as_child("@I3@");
     assert_eq!(childs_families.len(), 1);
 }
+
+#[test]
+fn test_lookups_accept_typed_and_bare_xrefs() {
+    let sample = "\
+        0 HEAD\n\
+        1 GEDC\n\
+        2 VERS 5.5.1\n\
+        0 @I1@ INDI\n\
+        1 NAME John /Doe/\n\
+        0 @F1@ FAM\n\
+        1 HUSB @I1@\n\
+        0 TRLR";
+
+    let mut gedcom = Gedcom::new(sample.chars()).unwrap();
+    let data = gedcom.parse_data().unwrap();
+
+    let family = data.find_family("F1").unwrap();
+    let husband = family.individual1().unwrap();
+    assert_eq!(husband, "@I1@");
+    assert_eq!(format!("{husband:#}"), "I1");
+    assert!(data.find_individual(husband).is_some());
+    assert!(data.find_individual("I1").is_some());
+    assert_eq!(data.get_families_as_spouse("I1").len(), 1);
+
+    let indexed = IndexedGedcomData::from(data.clone());
+    assert!(indexed.find_individual(husband).is_some());
+    assert!(indexed.find_family("F1").is_some());
+}
+
+#[test]
+fn test_xref_allocator_mints_unused_ids() {
+    let sample = "\
+        0 HEAD\n\
+        1 GEDC\n\
+        2 VERS 7.0\n\
+        0 @I1@ INDI\n\
+        0 @I2@ INDI\n\
+        0 @F1@ FAM\n\
+        0 @N1@ SNOTE Shared\n\
+        0 TRLR";
+
+    let mut gedcom = Gedcom::new(sample.chars()).unwrap();
+    let data = gedcom.parse_data().unwrap();
+
+    let mut allocator = data.xref_allocator();
+    let individual = allocator.allocate(&RecordKind::Individual);
+    assert_eq!(individual, "@I3@");
+    assert!(individual.is_valid_for(&GedcomVersion::V7_0));
+    assert!(individual.is_valid_for(&GedcomVersion::V5_5_1));
+    assert_eq!(allocator.allocate(&RecordKind::Family), "@F2@");
+    assert_eq!(allocator.allocate(&RecordKind::SharedNote), "@N2@");
+    assert_eq!(allocator.allocate(&RecordKind::Source), "@S1@");
+    assert_eq!(allocator.allocate(&RecordKind::Individual), "@I4@");
+    assert_eq!(Xref::parse("I5").unwrap(), "@I5@");
+}
@@ -0,0 +1,189 @@
+//! Tests for converting GEDCOM data between versions 5.5.1 and 7.0.
+
+use std::collections::HashMap;
+
+use ged_io::{ConversionKind, GedcomBuilder, GedcomVersion, GedcomWriter};
+
+#[test]
+fn converted_fixtures_write_strictly_valid_gedcom_7() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let original = std::fs::read_to_string(path).unwrap();
+        let data = GedcomBuilder::new().build_from_str(&original).unwrap();
+        let (converted, notes) = data.convert_to(GedcomVersion::V7_0);
+        assert!(converted.header.as_ref().unwrap().is_gedcom_7(), "{path}");
+        assert!(converted.submissions.is_empty(), "{path}");
+        if !data.submissions.is_empty() {
+            assert!(
+                notes
+                    .iter()
+                    .any(|note| note.tag == "SUBN" && note.is_lossy()),
+                "{path}"
+            );
+        }
+
+        let written = GedcomWriter::new()
+            .gedcom_version("7.0")
+            .write_to_string(&converted)
+            .unwrap();
+        for keyword in [
+            "AGE CHILD",
+            "AGE INFANT",
+            "AGE STILLBORN",
+            " RELA ",
+            " RIN ",
+        ] {
+            assert!(!written.contains(keyword), "{path} writes {keyword}");
+        }
+
+        let reparsed = GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(&written)
+            .unwrap_or_else(|e| panic!("{path}: {e}"));
+        assert_eq!(reparsed.individuals.len(), data.individuals.len(), "{path}");
+        assert_eq!(
+            reparsed.shared_notes.len(),
+            data.shared_notes.len(),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn five_to_seven_conversion_reports_every_lossy_step() {
+    let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n2 FORM LINEAGE-LINKED\n1 CHAR ANSEL\n\
+                  1 FILE archive.ged\n1 SUBM @U1@\n1 SUBN @SUB1@\n\
+                  0 @U1@ SUBM\n1 NAME Archivist\n1 RIN 4\n\
+                  0 @I1@ INDI\n1 NAME Anna /Berg/\n1 RIN 17\n1 DEAT\n2 AGE CHILD\n\
+                  1 ASSO @I2@\n2 TYPE FAM\n2 RELA Godmother\n1 NOTE @N1@\n\
+                  0 @N1@ NOTE Contact anna@@example.com\n1 RIN 9\n\
+                  0 @M1@ OBJE\n1 FILE scan.tif\n2 FORM tif\n3 MEDI photo\n\
+                  0 @SUB1@ SUBN\n1 TEMP SLAKE\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(source).unwrap();
+    let (converted, notes) = data.convert_to(GedcomVersion::V7_0);
+
+    let lost: Vec<String> = notes
+        .iter()
+        .filter(|note| note.is_lossy())
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        lost,
+        [
+            "lost SUBN: header pointer to submission @SUB1@ removed",
+            "lost FILE: file name archive.ged removed; GEDCOM 7.0 headers have no FILE",
+            "lost SUBN in record @SUB1@: submission record removed; GEDCOM 7.0 has no submission records",
+            "lost TYPE in record @I1@: association type FAM removed; GEDCOM 7.0 associations point to individuals",
+        ]
+    );
+    let converted_tags: Vec<&str> = notes
+        .iter()
+        .filter(|note| note.kind == ConversionKind::Converted)
+        .map(|note| note.tag.as_str())
+        .collect();
+    assert_eq!(
+        converted_tags,
+        ["FORM", "CHAR", "RIN", "AGE", "RELA", "RIN", "FORM", "NOTE", "RIN"]
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&converted)
+        .unwrap();
+    assert!(written.contains("0 @N1@ SNOTE Contact anna@example.com\n"));
+    assert!(written.contains(
+        "0 @U1@ SUBM\n1 NAME Archivist\n1 EXID 4\n2 TYPE https://gedcom.io/terms/v7/RIN\n"
+    ));
+    assert_eq!(
+        converted
+            .find_by_external_id("https://gedcom.io/terms/v7/RIN", "4")
+            .and_then(|record| record.xref()),
+        Some("@U1@")
+    );
+    assert!(written.contains("1 EXID 9\n2 TYPE https://gedcom.io/terms/v7/RIN\n"));
+    assert!(written.contains("2 AGE < 8y\n3 PHRASE Child\n"));
+    assert!(written.contains("2 ROLE GODP\n3 PHRASE Godmother\n"));
+    assert!(written.contains("2 FORM image/tiff\n3 MEDI PHOTO\n"));
+    GedcomBuilder::new()
+        .strict_mode(true)
+        .build_from_str(&written)
+        .unwrap();
+}
+
+#[test]
+fn five_to_seven_conversion_declares_supplied_extension_uris() {
+    let source = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n2 FORM LINEAGE-LINKED\n1 CHAR UTF-8\n\
+                  0 @I1@ INDI\n1 NAME Anna /Berg/\n1 _SKYPEID annaberg\n1 _MILT Navy\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(source).unwrap();
+    let uris = HashMap::from([(
+        "_SKYPEID".to_string(),
+        "http://xmlns.com/foaf/0.1/skypeID".to_string(),
+    )]);
+    let (converted, notes) = data.convert_to_with(GedcomVersion::V7_0, &uris);
+
+    let extension_notes: Vec<String> = notes
+        .iter()
+        .filter(|note| note.tag.starts_with('_'))
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        extension_notes,
+        [
+            "unmapped _MILT: extension tag _MILT has no URI to declare in HEAD.SCHMA",
+            "converted _SKYPEID: extension tag _SKYPEID declared in HEAD.SCHMA as http://xmlns.com/foaf/0.1/skypeID",
+        ]
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&converted)
+        .unwrap();
+    assert!(written.contains("1 SCHMA\n2 TAG _SKYPEID http://xmlns.com/foaf/0.1/skypeID\n"));
+    assert!(!written.contains("2 TAG _MILT"));
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(
+        reparsed.header.unwrap().find_extension_uri("_SKYPEID"),
+        Some("http://xmlns.com/foaf/0.1/skypeID")
+    );
+
+    // 5.5.1 has no schema, so the URIs are not used
+    let (converted, _) = data.convert_to_with(GedcomVersion::V5_5_1, &uris);
+    assert!(converted.header.unwrap().schema.is_none());
+}
+
+#[test]
+fn seven_to_five_conversion_reports_gedcom_7_structures() {
+    let source = "0 HEAD\n1 GEDC\n2 VERS 7.0\n1 SCHMA\n2 TAG _SKYPEID http://xmlns.com/foaf/0.1/skypeID\n\
+                  0 @I1@ INDI\n1 NAME Anna /Berg/\n1 _SKYPEID annaberg\n1 BIRT\n2 DATE 1 JAN 1900\n\
+                  3 PHRASE New Year's Day\n2 SDATE 1 JAN 1900\n1 NO DEAT\n1 EXID 123\n2 TYPE http://example.com/\n\
+                  0 @N1@ SNOTE <p>Born at home</p>\n1 MIME text/html\n1 TRAN Zu Hause geboren\n\
+                  0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(source).unwrap();
+    let (converted, notes) = data.convert_to(GedcomVersion::V5_5_1);
+
+    let lost: Vec<String> = notes
+        .iter()
+        .filter(|note| note.is_lossy())
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        lost,
+        [
+            "lost PHRASE in record @I1@: date phrase New Year's Day removed",
+            "lost SDATE in record @I1@: sort date 1 JAN 1900 removed",
+            "lost EXID in record @I1@: external identifier 123 removed",
+            "lost NO in record @I1@: non-event DEAT removed",
+            "lost MIME in record @N1@: media type text/html removed",
+            "lost TRAN in record @N1@: translation Zu Hause geboren removed",
+            "lost SCHMA: declaration of _SKYPEID as http://xmlns.com/foaf/0.1/skypeID removed; GEDCOM 5.5.1 has no schema",
+        ]
+    );
+
+    let written = GedcomWriter::new().write_to_string(&converted).unwrap();
+    assert!(written.contains("0 @N1@ NOTE <p>Born at home</p>\n"));
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(reparsed.header.unwrap().version(), Some("5.5.1"));
+}
@@ -7,7 +7,7 @@
 //! - ISO-8859-15 (Latin-9)
 
 use ged_io::encoding::{decode_gedcom_bytes, encode_to_bytes, GedcomEncoding};
-use ged_io::GedcomBuilder;
+use ged_io::{GedcomBuilder, GedcomRecord, GedcomWriter, UnmappableCharacters};
 
 /// Helper to create a minimal GEDCOM string with a name containing special characters.
 fn create_gedcom_with_name(name: &str, char_tag: &str) -> String {
@@ -34,7 +34,7 @@ fn test_parse_utf8_without_bom() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -47,7 +47,7 @@ fn test_parse_utf8_with_bom() {
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Müller /Schröder/");
 }
 
@@ -59,7 +59,7 @@ fn test_parse_utf8_chinese_characters() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "王 /伟/");
 }
 
@@ -71,7 +71,7 @@ fn test_parse_utf8_cyrillic_characters() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Иван /Петров/");
 }
 
@@ -112,7 +112,7 @@ fn test_parse_iso8859_1_accented_characters() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -130,7 +130,7 @@ fn test_parse_iso8859_1_german_umlauts() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Müller /Schröder/");
 }
 
@@ -148,7 +148,7 @@ fn test_parse_iso8859_1_french_accents() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "René /François/");
 }
 
@@ -166,7 +166,7 @@ fn test_parse_iso8859_1_nordic_characters() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Søren /Åberg/");
 }
 
@@ -184,7 +184,7 @@ fn test_parse_iso8859_1_with_latin1_tag() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -207,7 +207,7 @@ fn test_parse_iso8859_15_euro_sign() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let note = data.individuals[0].note.as_ref().unwrap();
+    let note = &data.individuals[0].notes[0];
     assert!(note.value.as_ref().unwrap().contains("100€"));
 }
 
@@ -225,7 +225,7 @@ fn test_parse_iso8859_15_oe_ligatures() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Test /Bœuf/");
 }
 
@@ -244,7 +244,7 @@ fn test_parse_iso8859_15_with_latin9_tag() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let note = data.individuals[0].note.as_ref().unwrap();
+    let note = &data.individuals[0].notes[0];
     assert!(note.value.as_ref().unwrap().contains("50€"));
 }
 
@@ -260,7 +260,7 @@ fn test_parse_utf16_le_with_bom() {
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -272,7 +272,7 @@ fn test_parse_utf16_be_with_bom() {
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Müller /Schröder/");
 }
 
@@ -284,7 +284,7 @@ fn test_parse_utf16_le_chinese_characters() {
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "王 /伟/");
 }
 
@@ -296,7 +296,7 @@ fn test_parse_utf16_be_cyrillic_characters() {
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Иван /Петров/");
 }
 
@@ -377,7 +377,7 @@ fn test_build_with_explicit_utf8_encoding() {
         .unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -395,7 +395,7 @@ fn test_build_with_explicit_iso8859_1_encoding() {
         .unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -409,7 +409,7 @@ fn test_build_with_explicit_utf16_le_encoding() {
         .unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /García/");
 }
 
@@ -426,7 +426,7 @@ fn test_roundtrip_utf8_special_characters() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     // Verify
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José María /García López/");
 }
 
@@ -443,7 +443,7 @@ fn test_roundtrip_utf16_le_special_characters() {
 
     // Parse
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "日本語 /テスト/");
 }
 
@@ -460,7 +460,7 @@ fn test_roundtrip_utf16_be_special_characters() {
 
     // Parse
     let data = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "Ελληνικά /Κείμενο/");
 }
 
@@ -545,7 +545,7 @@ fn test_mixed_encoding_header() {
     let data = GedcomBuilder::new().build_from_bytes(bytes).unwrap();
 
     assert_eq!(data.individuals.len(), 1);
-    let name = data.individuals[0].name.as_ref().unwrap();
+    let name = data.individuals[0].primary_name().unwrap();
     assert_eq!(name.value.as_ref().unwrap(), "José /Martínez/");
 }
 
@@ -624,7 +624,7 @@ fn test_parse_complete_gedcom_with_iso8859_1() {
     // Check José's details
     let jose = &data.individuals[0];
     assert_eq!(
-        jose.name.as_ref().unwrap().value.as_ref().unwrap(),
+        jose.primary_name().unwrap().value.as_ref().unwrap(),
         "José /García/"
     );
 
@@ -638,7 +638,7 @@ fn test_parse_complete_gedcom_with_iso8859_1() {
     // Check María's details
     let maria = &data.individuals[1];
     assert_eq!(
-        maria.name.as_ref().unwrap().value.as_ref().unwrap(),
+        maria.primary_name().unwrap().value.as_ref().unwrap(),
         "María /López/"
     );
 
@@ -683,3 +683,143 @@ fn test_parse_washington_fixture_with_build_from_bytes() {
     assert_eq!(data.individuals.len(), 538);
     assert_eq!(data.families.len(), 278);
 }
+
+// ============================================================================
+// Encoded Writer Output Tests
+// ============================================================================
+
+#[test]
+fn test_writer_output_round_trips_in_every_encoding() {
+    let source = create_gedcom_with_name("Zoë /Müller/", "UTF-8");
+    let data = GedcomBuilder::new().build_from_str(&source).unwrap();
+
+    for encoding in [
+        GedcomEncoding::Utf8,
+        GedcomEncoding::Utf16Le,
+        GedcomEncoding::Utf16Be,
+        GedcomEncoding::Iso8859_1,
+        GedcomEncoding::Iso8859_15,
+        GedcomEncoding::Ansel,
+    ] {
+        let bytes = GedcomWriter::new()
+            .encoding(encoding)
+            .write_to_bytes(&data)
+            .unwrap();
+        let (text, detected) = decode_gedcom_bytes(&bytes).unwrap();
+        assert_eq!(detected, encoding, "{encoding}");
+        assert!(
+            text.contains(&format!("1 CHAR {}\n", encoding.char_value())),
+            "{encoding}"
+        );
+
+        // ANSEL stores diacritics as combining marks, so it decodes to the decomposed form
+        let expected = if encoding == GedcomEncoding::Ansel {
+            "Zoe\u{308} Mu\u{308}ller"
+        } else {
+            "Zoë Müller"
+        };
+        let reparsed = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            reparsed.individuals[0].full_name().as_deref(),
+            Some(expected),
+            "{encoding}"
+        );
+    }
+}
+
+#[test]
+fn test_writer_byte_order_mark() {
+    let data = GedcomBuilder::new()
+        .build_from_str(&create_gedcom_with_name("John /Doe/", "UTF-8"))
+        .unwrap();
+
+    let bytes = GedcomWriter::new()
+        .encoding(GedcomEncoding::Utf16Le)
+        .write_to_bytes(&data)
+        .unwrap();
+    assert!(bytes.starts_with(&[0xFF, 0xFE, b'0', 0x00]));
+
+    let bytes = GedcomWriter::new()
+        .encoding(GedcomEncoding::Utf8)
+        .byte_order_mark(true)
+        .write_to_bytes(&data)
+        .unwrap();
+    assert!(bytes.starts_with(&[0xEF, 0xBB, 0xBF, b'0']));
+
+    let bytes = GedcomWriter::new()
+        .encoding(GedcomEncoding::Ansel)
+        .byte_order_mark(true)
+        .write_to_bytes(&data)
+        .unwrap();
+    assert!(bytes.starts_with(b"0 HEAD"));
+}
+
+#[test]
+fn test_writer_rewrites_declared_char() {
+    let data = GedcomBuilder::new()
+        .build_from_str(&create_gedcom_with_name("John /Doe/", "ANSEL"))
+        .unwrap();
+
+    let bytes = GedcomWriter::new()
+        .encoding(GedcomEncoding::Utf8)
+        .write_to_bytes(&data)
+        .unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+    assert!(text.contains("1 CHAR UTF-8\n"));
+    assert!(!text.contains("ANSEL"));
+
+    // Without an encoding the output is UTF-8, whatever the parsed file declared
+    let text = GedcomWriter::new().write_to_string(&data).unwrap();
+    assert!(text.contains("1 CHAR UTF-8\n"));
+    assert!(!text.contains("ANSEL"));
+    let bytes = GedcomWriter::new().write_to_bytes(&data).unwrap();
+    assert!(String::from_utf8(bytes).unwrap().contains("1 CHAR UTF-8\n"));
+}
+
+#[test]
+fn test_writer_string_output_does_not_declare_byte_encoding() {
+    let data = GedcomBuilder::new()
+        .build_from_str(&create_gedcom_with_name("José /Pérez/", "UTF-8"))
+        .unwrap();
+    let writer = GedcomWriter::new().encoding(GedcomEncoding::Ansel);
+
+    // String output is UTF-8, whatever encoding byte output uses
+    let text = writer.write_to_string(&data).unwrap();
+    assert!(text.contains("1 CHAR UTF-8\n"));
+    assert!(text.contains("1 NAME José /Pérez/\n"));
+
+    let bytes = writer.write_to_bytes(&data).unwrap();
+    assert!(String::from_utf8_lossy(&bytes).contains("1 CHAR ANSEL\n"));
+
+    let mut output = Vec::new();
+    writer
+        .write_records(
+            &mut output,
+            [GedcomRecord::Header(data.header.clone().unwrap())],
+        )
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output).contains("1 CHAR ANSEL\n"));
+}
+
+#[test]
+fn test_writer_reports_unrepresentable_characters() {
+    let data = GedcomBuilder::new()
+        .build_from_str(&create_gedcom_with_name("Wang /王/", "UTF-8"))
+        .unwrap();
+
+    let error = GedcomWriter::new()
+        .encoding(GedcomEncoding::Iso8859_1)
+        .write_to_bytes(&data)
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    let message = error.to_string();
+    assert!(message.contains("U+738B"), "{message}");
+    assert!(message.contains("output line 7"), "{message}");
+
+    let bytes = GedcomWriter::new()
+        .encoding(GedcomEncoding::Iso8859_1)
+        .unmappable_characters(UnmappableCharacters::Replace('?'))
+        .write_to_bytes(&data)
+        .unwrap();
+    assert!(bytes.windows(15).any(|w| w == b"1 NAME Wang /?/"));
+}
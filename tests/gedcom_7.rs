@@ -3,7 +3,14 @@
 //! These tests verify that the library correctly parses GEDCOM 7.0 files
 //! and handles the differences between 5.5.1 and 7.0 specifications.
 
-use ged_io::{detect_version, Gedcom, GedcomBuilder, GedcomVersion, GedcomWriter};
+use ged_io::{
+    detect_version,
+    types::{
+        age::Age,
+        event::{spouse::Spouse, Event},
+    },
+    Gedcom, GedcomBuilder, GedcomVersion, GedcomWriter,
+};
 
 /// Test parsing a minimal GEDCOM 7.0 file.
 #[test]
@@ -92,11 +99,12 @@ fn test_parse_shared_notes() {
     assert_eq!(data.shared_notes.len(), 2);
 
     let note1 = data.find_shared_note("@N1@").unwrap();
-    assert_eq!(note1.xref, Some("@N1@".to_string()));
+    assert_eq!(note1.xref, Some("@N1@".into()));
+    assert_eq!(note1.version, Some(GedcomVersion::V7_0));
     assert!(note1.text.contains("Gordon surname"));
 
     let note2 = data.find_shared_note("@N2@").unwrap();
-    assert_eq!(note2.xref, Some("@N2@".to_string()));
+    assert_eq!(note2.xref, Some("@N2@".into()));
     assert_eq!(note2.text, "Another shared note.");
 }
 
@@ -243,6 +251,12 @@ fn test_version_features() {
     assert!(v5_features.submission_supported);
     assert!(v5_features.char_encoding_supported);
     assert!(v5_features.double_all_at_signs);
+    assert!(v5_features.automated_record_id_supported);
+    assert!(!v5_features.external_ids_supported);
+    assert!(!v5_features.uid_supported);
+    assert!(!v5_features.creation_date_supported);
+    assert!(!v5_features.phrase_supported);
+    assert!(!v5_features.multimedia_restriction_supported);
 
     let v7_features = VersionFeatures::v7_0();
     assert!(!v7_features.conc_supported);
@@ -252,6 +266,12 @@ fn test_version_features() {
     assert!(!v7_features.submission_supported);
     assert!(!v7_features.char_encoding_supported);
     assert!(!v7_features.double_all_at_signs);
+    assert!(!v7_features.automated_record_id_supported);
+    assert!(v7_features.external_ids_supported);
+    assert!(v7_features.uid_supported);
+    assert!(v7_features.creation_date_supported);
+    assert!(v7_features.phrase_supported);
+    assert!(v7_features.multimedia_restriction_supported);
 }
 
 /// Test version comparison and methods.
@@ -435,7 +455,7 @@ fn test_parse_individual_non_event() {
         non_event.date.as_ref().unwrap().value,
         Some("BEF 1900".to_string())
     );
-    assert!(non_event.note.is_some());
+    assert!(!non_event.notes.is_empty());
 }
 
 /// Test parsing GEDCOM 7.0 with NO (non-event) structure for families.
@@ -464,7 +484,7 @@ fn test_parse_family_non_event() {
 
     let non_event = &data.families[0].non_events[0];
     assert_eq!(non_event.event_type, "CHIL");
-    assert!(non_event.note.is_some());
+    assert!(!non_event.notes.is_empty());
 }
 
 /// Test parsing GEDCOM 7.0 with CROP structure for multimedia.
@@ -487,7 +507,7 @@ fn test_parse_multimedia_crop() {
     let data = gedcom.parse_data().unwrap();
 
     assert_eq!(data.multimedia.len(), 1);
-    let file = data.multimedia[0].file.as_ref().unwrap();
+    let file = data.multimedia[0].primary_file().unwrap();
     assert!(file.crop.is_some());
 
     let crop = file.crop.as_ref().unwrap();
@@ -537,7 +557,7 @@ fn test_round_trip_schema() {
     let data = GedcomBuilder::new().build_from_str(sample).unwrap();
     assert!(data.header.as_ref().unwrap().schema.is_some());
 
-    let writer = GedcomWriter::new();
+    let writer = GedcomWriter::new().gedcom_version("7.0");
     let output = writer.write_to_string(&data).unwrap();
 
     assert!(output.contains("SCHMA"));
@@ -559,7 +579,7 @@ fn test_round_trip_non_events() {
     let data = GedcomBuilder::new().build_from_str(sample).unwrap();
     assert_eq!(data.individuals[0].non_events.len(), 1);
 
-    let writer = GedcomWriter::new();
+    let writer = GedcomWriter::new().gedcom_version("7.0");
     let output = writer.write_to_string(&data).unwrap();
 
     assert!(output.contains("NO MARR"));
@@ -582,7 +602,7 @@ fn test_round_trip_sort_date() {
     let data = GedcomBuilder::new().build_from_str(sample).unwrap();
     assert!(data.individuals[0].events[0].sort_date.is_some());
 
-    let writer = GedcomWriter::new();
+    let writer = GedcomWriter::new().gedcom_version("7.0");
     let output = writer.write_to_string(&data).unwrap();
 
     assert!(output.contains("SDATE 1818"));
@@ -606,7 +626,7 @@ fn test_round_trip_date_phrase() {
     let date = data.individuals[0].events[0].date.as_ref().unwrap();
     assert!(date.phrase.is_some());
 
-    let writer = GedcomWriter::new();
+    let writer = GedcomWriter::new().gedcom_version("7.0");
     let output = writer.write_to_string(&data).unwrap();
 
     assert!(output.contains("PHRASE The Ides of March"));
@@ -796,7 +816,7 @@ fn test_parse_slgc_ordinance() {
         slgc.ordinance_type,
         Some(ged_io::types::lds::LdsOrdinanceType::SealingChild)
     );
-    assert_eq!(slgc.family_xref, Some("@F1@".to_string()));
+    assert_eq!(slgc.family_xref, Some("@F1@".into()));
     assert!(slgc.is_completed());
 }
 
@@ -855,7 +875,7 @@ fn test_round_trip_lds_ordinances() {
         0 TRLR";
 
     let data = GedcomBuilder::new().build_from_str(sample).unwrap();
-    let writer = GedcomWriter::new();
+    let writer = GedcomWriter::new().gedcom_version("7.0");
     let output = writer.write_to_string(&data).unwrap();
 
     // Verify output contains LDS ordinance tags
@@ -922,3 +942,95 @@ fn test_at_sign_escaping() {
     assert!(!needs_at_escaping("test@email.com", true)); // v7: only leading
     assert!(needs_at_escaping("@ref", true)); // v7: leading @ needs escaping
 }
+
+/// Test parsing phrases on family member pointers and spouse ages in family events.
+#[test]
+fn test_parse_family_member_phrases_and_spouse_ages() {
+    let sample = "\
+        0 HEAD\n\
+        1 GEDC\n\
+        2 VERS 7.0\n\
+        0 @F1@ FAM\n\
+        1 HUSB @I1@\n\
+        2 PHRASE Mr Smith\n\
+        1 WIFE @VOID@\n\
+        2 PHRASE His first wife\n\
+        1 CHIL @I3@\n\
+        1 CHIL @VOID@\n\
+        2 PHRASE A stillborn daughter\n\
+        1 MARR\n\
+        2 DATE 1 JAN 1900\n\
+        2 HUSB\n\
+        3 AGE 25y\n\
+        4 PHRASE Twenty-five\n\
+        2 WIFE\n\
+        3 AGE > 20y\n\
+        0 @I1@ INDI\n\
+        0 @I3@ INDI\n\
+        0 TRLR";
+
+    let mut gedcom = Gedcom::new(sample.chars()).unwrap();
+    let data = gedcom.parse_data().unwrap();
+
+    let family = &data.families[0];
+    let husband = family.individual1.as_ref().unwrap();
+    assert_eq!(husband.phrase.as_deref(), Some("Mr Smith"));
+    let wife = family.individual2.as_ref().unwrap();
+    assert_eq!(wife.phrase.as_deref(), Some("His first wife"));
+    assert_eq!(family.children.len(), 2);
+    assert_eq!(family.children[0].phrase, None);
+    assert_eq!(family.children[1], "@VOID@");
+    assert_eq!(
+        family.children[1].phrase.as_deref(),
+        Some("A stillborn daughter")
+    );
+    assert!(family.custom_data.is_empty());
+
+    let husband_age = family
+        .spouse_age(&Event::Marriage, &Spouse::Spouse1)
+        .unwrap();
+    assert_eq!(husband_age.to_string(), "25y");
+    assert!(matches!(
+        husband_age,
+        Age::Numeric { phrase: Some(phrase), .. } if phrase == "Twenty-five"
+    ));
+    let wife_age = family
+        .spouse_age(&Event::Marriage, &Spouse::Spouse2)
+        .unwrap();
+    assert_eq!(wife_age.to_string(), "> 20y");
+}
+
+#[test]
+fn test_family_keeps_duplicate_spouse_pointers() {
+    let sample = "\
+        0 HEAD\n\
+        1 GEDC\n\
+        2 VERS 7.0\n\
+        0 @F1@ FAM\n\
+        1 HUSB @I1@\n\
+        1 HUSB @VOID@\n\
+        2 PHRASE Possibly her cousin\n\
+        1 WIFE @I2@\n\
+        1 WIFE @I3@\n\
+        0 TRLR";
+
+    let data = GedcomBuilder::new().build_from_str(sample).unwrap();
+    let family = &data.families[0];
+    assert_eq!(family.individual1().unwrap(), "@I1@");
+    assert_eq!(family.additional_individual1.len(), 1);
+    assert_eq!(family.additional_individual1[0], "@VOID@");
+    assert_eq!(
+        family.additional_individual1[0].phrase.as_deref(),
+        Some("Possibly her cousin")
+    );
+    assert_eq!(family.individual2().unwrap(), "@I2@");
+    assert_eq!(family.additional_individual2[0], "@I3@");
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(written.contains(
+        "1 HUSB @I1@\n1 HUSB @VOID@\n2 PHRASE Possibly her cousin\n1 WIFE @I2@\n1 WIFE @I3@\n"
+    ));
+}
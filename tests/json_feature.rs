@@ -25,12 +25,7 @@ mod json_feature_tests {
 
         // Check individual names are preserved
         if !data.individuals.is_empty() {
-            let original_name = &data.individuals[0].name;
-            let deser_name = &deserialized.individuals[0].name;
-            assert_eq!(
-                original_name.as_ref().map(|n| n.value.clone()),
-                deser_name.as_ref().map(|n| n.value.clone())
-            );
+            assert_eq!(data.individuals[0].names, deserialized.individuals[0].names);
         }
     }
 
@@ -70,4 +65,24 @@ mod json_feature_tests {
         assert_eq!(data.individuals.len(), deserialized.individuals.len());
         assert_eq!(data.families.len(), deserialized.families.len());
     }
+
+    #[test]
+    fn serde_individual_with_multiple_names() {
+        let gedcom_content = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME Mary /Smith/\n\
+            1 NAME Mary /Jones/\n2 TYPE married\n0 TRLR";
+        let data = Gedcom::new(gedcom_content.chars())
+            .unwrap()
+            .parse_data()
+            .unwrap();
+
+        let json = serde_json::to_value(&data.individuals[0]).unwrap();
+        let names = json["names"].as_array().unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0]["value"], "Mary /Smith/");
+        assert_eq!(names[1]["value"], "Mary /Jones/");
+
+        let deserialized: ged_io::types::individual::Individual =
+            serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.names, data.individuals[0].names);
+    }
 }
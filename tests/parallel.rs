@@ -0,0 +1,88 @@
+//! Tests for parsing on a thread pool with the `rayon` feature.
+
+#![cfg(feature = "rayon")]
+
+use ged_io::{Diagnostic, ErrorMode, GedcomBuilder, GedcomError};
+use std::fs;
+
+#[test]
+fn parallel_parse_matches_sequential_parse_on_fixtures() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/washington.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let content = fs::read_to_string(path).unwrap();
+        let sequential = GedcomBuilder::new().build_from_str(&content).unwrap();
+        let parallel = GedcomBuilder::new().build_parallel(&content).unwrap();
+
+        assert_eq!(parallel, sequential, "{path}");
+    }
+}
+
+#[test]
+fn parallel_parse_keeps_spans_relative_to_the_file() {
+    let content = fs::read_to_string("tests/fixtures/washington.ged").unwrap();
+    let sequential = GedcomBuilder::new()
+        .record_spans(true)
+        .build_from_str(&content)
+        .unwrap();
+    let parallel = GedcomBuilder::new()
+        .record_spans(true)
+        .build_parallel(&content)
+        .unwrap();
+
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn parallel_parse_reports_first_error_in_file_order() {
+    let content = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @X1@ BOGUS\n0 @X2@ OTHER\n0 TRLR";
+    match GedcomBuilder::new().build_parallel(content) {
+        Err(GedcomError::ParseError { line, message }) => {
+            assert_eq!(line, 4);
+            assert!(message.contains("BOGUS"));
+        }
+        other => panic!("Expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn parallel_parse_validates_references() {
+    let content = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @F1@ FAM\n1 HUSB @I9@\n0 TRLR";
+    assert!(GedcomBuilder::new()
+        .validate_references(true)
+        .build_parallel(content)
+        .is_err());
+}
+
+#[test]
+fn parallel_parse_defers_errors_like_sequential_parse() {
+    let content = "0 HEAD\n1 GEDC\n2 VERS 5.5\n0 @I1@ INDI\n1 NAME John /Doe/\n\
+        0 @X1@ BOGUS\n0 @F1@ FAM\n1 HUSB @I9@\n0 @I2@ INDI\n1 NAME Jane /Doe/";
+    let builder = || {
+        GedcomBuilder::new()
+            .error_mode(ErrorMode::Deferred)
+            .validate_references(true)
+    };
+    let summary = |diagnostics: &[Diagnostic]| {
+        diagnostics
+            .iter()
+            .map(|d| (d.severity, d.line, d.xref.clone(), d.error.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    let (sequential, expected) = builder().build_from_str_with_diagnostics(content).unwrap();
+    let (parallel, diagnostics) = builder().build_parallel_with_diagnostics(content).unwrap();
+
+    assert_eq!(parallel, sequential);
+    assert_eq!(parallel.individuals.len(), 2);
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(summary(&diagnostics), summary(&expected));
+
+    assert!(matches!(
+        builder().build_parallel(content),
+        Err(GedcomError::ParseError { line: 6, .. })
+    ));
+}
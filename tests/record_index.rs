@@ -0,0 +1,79 @@
+//! Tests for the seekable record index.
+
+use ged_io::{
+    record_index::{RecordIndex, RecordKind},
+    GedcomStreamParser,
+};
+use std::{
+    fs::{self, File},
+    io::{BufReader, Cursor},
+};
+
+#[test]
+fn indexed_records_match_streamed_records() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/washington.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let index = RecordIndex::build(BufReader::new(File::open(path).unwrap())).unwrap();
+        assert_eq!(
+            index.source_len(),
+            fs::metadata(path).unwrap().len(),
+            "{path}"
+        );
+
+        let mut file = BufReader::new(File::open(path).unwrap());
+        let streamed = GedcomStreamParser::new(BufReader::new(File::open(path).unwrap())).unwrap();
+        let mut indexed_count = 0;
+        for record in streamed {
+            let record = record.unwrap();
+            let Some(xref) = record.xref() else {
+                continue;
+            };
+            let read = index.read_record(&mut file, xref).unwrap().unwrap();
+            assert_eq!(read, record, "{path} {xref}");
+            indexed_count += 1;
+        }
+        assert_eq!(index.len(), indexed_count, "{path}");
+
+        let header = index.read_location(&mut file, index.header().unwrap());
+        assert!(header.unwrap().is_header(), "{path}");
+    }
+}
+
+#[test]
+fn index_survives_sidecar_round_trip() {
+    let path = "tests/fixtures/washington.ged";
+    let index = RecordIndex::build(BufReader::new(File::open(path).unwrap())).unwrap();
+
+    let sidecar = std::env::temp_dir().join(format!("ged_io_index_{}.idx", std::process::id()));
+    index.save(&sidecar).unwrap();
+    let loaded = RecordIndex::load(&sidecar).unwrap();
+    fs::remove_file(&sidecar).unwrap();
+
+    assert_eq!(loaded, index);
+    let location = loaded.get("@I1@").unwrap();
+    assert_eq!(location.kind, RecordKind::Individual);
+}
+
+#[test]
+fn index_decodes_records_in_legacy_encodings() {
+    let content: &[u8] =
+        b"0 HEAD\n1 CHAR ANSEL\n0 @I1@ INDI\n1 NAME Anna /M\xB2ller/\n0 @I2@ INDI\n1 NAME Jos\xE2e /Ruiz/\n0 TRLR\n";
+    let index = RecordIndex::build(content).unwrap();
+    assert_eq!(index.encoding(), ged_io::GedcomEncoding::Ansel);
+
+    let mut reader = Cursor::new(content);
+    let record = index.read_record(&mut reader, "@I2@").unwrap().unwrap();
+    assert_eq!(
+        record.as_individual().unwrap().full_name().as_deref(),
+        Some("Jose\u{301} Ruiz")
+    );
+    let record = index.read_record(&mut reader, "@I1@").unwrap().unwrap();
+    assert_eq!(
+        record.as_individual().unwrap().full_name().as_deref(),
+        Some("Anna Møller")
+    );
+}
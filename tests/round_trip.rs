@@ -3,7 +3,7 @@
 //! These tests verify that parsing a GEDCOM file, writing it back, and parsing again
 //! produces equivalent data structures.
 
-use ged_io::{GedcomBuilder, GedcomWriter};
+use ged_io::{cst::GedcomDocument, GedcomBuilder, GedcomRecord, GedcomStreamParser, GedcomWriter};
 
 // =============================================================================
 // Basic Round-Trip Tests
@@ -43,10 +43,26 @@ fn test_round_trip_individual() {
 
     assert_eq!(data1.individuals.len(), data2.individuals.len());
     assert_eq!(data1.individuals[0].xref, data2.individuals[0].xref);
-    assert_eq!(data1.individuals[0].name, data2.individuals[0].name);
+    assert_eq!(data1.individuals[0].names, data2.individuals[0].names);
     assert_eq!(data1.individuals[0].sex, data2.individuals[0].sex);
 }
 
+#[test]
+fn test_round_trip_individual_with_multiple_names() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME Mary /Smith/\n\
+        1 NAME Mary /Jones/\n2 TYPE married\n1 NAME Molly\n2 TYPE aka\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new().write_to_string(&data1).unwrap();
+    assert!(written.contains("1 NAME Mary /Smith/\n"));
+    assert!(written.contains("1 NAME Mary /Jones/\n"));
+    assert!(written.contains("1 NAME Molly\n"));
+
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.individuals[0].names.len(), 3);
+    assert_eq!(data1.individuals[0].names, data2.individuals[0].names);
+}
+
 #[test]
 fn test_round_trip_individual_with_events() {
     let original = r#"0 HEAD
@@ -244,6 +260,139 @@ fn test_round_trip_multimedia() {
     assert_eq!(data1.multimedia[0].title, data2.multimedia[0].title);
 }
 
+#[test]
+fn test_round_trip_gedcom_5_file_format_medium() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @M1@ OBJE\n1 FILE photo.jpg\n\
+        2 FORM jpg\n3 TYPE photo\n2 TITL Family Photo\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new().write_to_string(&data1).unwrap();
+    assert!(written.contains("1 FILE photo.jpg\n2 FORM jpg\n3 TYPE photo\n"));
+    assert!(!written.contains("MEDI"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.multimedia[0].files, data1.multimedia[0].files);
+
+    let (converted, _) = data1.convert_to(ged_io::GedcomVersion::V7_0);
+    let (converted, _) = converted.convert_to(ged_io::GedcomVersion::V5_5_1);
+    let written = GedcomWriter::new().write_to_string(&converted).unwrap();
+    assert!(written.contains("2 FORM jpg\n3 TYPE photo\n"));
+}
+
+#[test]
+fn test_round_trip_gedcom_7_multimedia_record() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @M1@ OBJE\n1 RESN CONFIDENTIAL\n\
+        1 FILE scans/letter.tif\n2 FORM image/tiff\n3 MEDI PHOTO\n2 TITL Letter, front\n\
+        2 TRAN scans/letter.jpg\n3 FORM image/jpeg\n2 TRAN scans/letter.png\n3 FORM image/png\n\
+        1 FILE scans/letter-back.tif\n2 FORM image/tiff\n3 MEDI OTHER\n4 PHRASE Envelope\n\
+        1 REFN 12\n1 UID 1d1c5d5e-2a84-4b4f-9d7a-0a2b6c1f7e11\n1 UID 6f1e3b62-54d1-4a0e-8c36-3f6a2b4a9c01\n\
+        1 EXID 123\n2 TYPE https://example.com/media/\n1 EXID 456\n\
+        1 NOTE Found in the attic\n1 SNOTE @N1@\n1 SOUR @S1@\n1 SOUR @S2@\n\
+        0 @N1@ SNOTE Family papers\n0 @S1@ SOUR\n1 TITL One\n0 @S2@ SOUR\n1 TITL Two\n0 TRLR";
+
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let media = &data1.multimedia[0];
+    assert_eq!(media.files.len(), 2);
+    assert_eq!(media.files[0].translations.len(), 2);
+    assert_eq!(
+        media.files[1]
+            .form
+            .as_ref()
+            .unwrap()
+            .source_media_phrase
+            .as_deref(),
+        Some("Envelope")
+    );
+    assert_eq!(media.uids.len(), 2);
+    assert_eq!(
+        media.external_ids[0].full_url().as_deref(),
+        Some("https://example.com/media/123")
+    );
+    assert_eq!(media.notes.len(), 2);
+    assert_eq!(media.source_citations.len(), 2);
+    assert_eq!(
+        media.file_paths().collect::<Vec<_>>(),
+        [
+            "scans/letter.tif",
+            "scans/letter.jpg",
+            "scans/letter.png",
+            "scans/letter-back.tif"
+        ]
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.multimedia[0].files, media.files);
+    assert_eq!(data2.multimedia[0].uids, media.uids);
+    assert_eq!(data2.multimedia[0].external_ids, media.external_ids);
+    assert_eq!(data2.multimedia[0].notes, media.notes);
+    assert_eq!(data2.multimedia[0].source_citations, media.source_citations);
+    assert_eq!(data2.multimedia[0].restriction, media.restriction);
+}
+
+#[test]
+fn test_round_trip_external_ids_on_every_record() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n\
+        0 @I1@ INDI\n1 NAME John /Doe/\n1 EXID LZDP-V3V\n2 TYPE https://www.familysearch.org/tree/person/\n\
+        1 EXID Doe-42\n2 TYPE https://www.wikitree.com/wiki/\n\
+        1 RESI\n2 PLAC Springfield\n3 EXID 4951\n4 TYPE https://example.com/places/\n\
+        0 @F1@ FAM\n1 HUSB @I1@\n1 EXID F-1\n2 TYPE https://example.com/families/\n\
+        0 @S1@ SOUR\n1 TITL Census\n1 EXID S-1\n2 TYPE https://example.com/sources/\n\
+        0 @R1@ REPO\n1 NAME Archive\n1 EXID R-1\n2 TYPE https://example.com/repositories/\n\
+        0 @N1@ SNOTE Shared\n1 EXID N-1\n2 TYPE https://example.com/notes/\n\
+        0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let individual = &data1.individuals[0];
+    assert_eq!(individual.external_ids.len(), 2);
+    assert_eq!(
+        individual.external_ids[1].full_url().as_deref(),
+        Some("https://www.wikitree.com/wiki/Doe-42")
+    );
+    assert_eq!(data1.records_with_external_ids().count(), 5);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.individuals[0].external_ids, individual.external_ids);
+    assert_eq!(
+        data2.individuals[0].attributes[0].place,
+        individual.attributes[0].place
+    );
+    assert_eq!(
+        data2.families[0].external_ids,
+        data1.families[0].external_ids
+    );
+    assert_eq!(data2.sources[0].external_ids, data1.sources[0].external_ids);
+    assert_eq!(
+        data2.repositories[0].external_ids,
+        data1.repositories[0].external_ids
+    );
+    assert_eq!(
+        data2.shared_notes[0].external_ids,
+        data1.shared_notes[0].external_ids
+    );
+    for (type_uri, id, xref) in [
+        ("https://www.wikitree.com/wiki/", "Doe-42", "@I1@"),
+        ("https://example.com/families/", "F-1", "@F1@"),
+        ("https://example.com/sources/", "S-1", "@S1@"),
+        ("https://example.com/repositories/", "R-1", "@R1@"),
+        ("https://example.com/notes/", "N-1", "@N1@"),
+    ] {
+        let record = data2.find_by_external_id(type_uri, id).unwrap();
+        assert_eq!(record.xref(), Some(xref));
+    }
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(!written.contains("EXID"));
+}
+
 // =============================================================================
 // Complex Round-Trip Tests
 // =============================================================================
@@ -304,7 +453,7 @@ fn test_round_trip_complete_gedcom() {
         .enumerate()
     {
         assert_eq!(ind1.xref, ind2.xref, "Individual {i} xref mismatch");
-        assert_eq!(ind1.name, ind2.name, "Individual {i} name mismatch");
+        assert_eq!(ind1.names, ind2.names, "Individual {i} name mismatch");
         assert_eq!(ind1.sex, ind2.sex, "Individual {i} sex mismatch");
     }
 }
@@ -395,8 +544,8 @@ fn test_round_trip_individual_no_name() {
     let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
 
     assert_eq!(data1.individuals.len(), data2.individuals.len());
-    assert!(data1.individuals[0].name.is_none());
-    assert!(data2.individuals[0].name.is_none());
+    assert!(data1.individuals[0].names.is_empty());
+    assert!(data2.individuals[0].names.is_empty());
 }
 
 #[test]
@@ -478,3 +627,936 @@ fn test_written_output_contains_expected_tags() {
     assert!(written.contains("1 HUSB @I1@"), "Missing HUSB tag");
     assert!(written.contains("0 TRLR"), "Missing TRLR tag");
 }
+
+// =============================================================================
+// Lossless Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_lossless_round_trip_of_fixtures() {
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/washington.ged",
+        "tests/fixtures/allged.ged",
+    ] {
+        let content = std::fs::read_to_string(path).unwrap();
+        let mut document = GedcomDocument::parse(&content).unwrap();
+        assert_eq!(document.to_string(), content, "{path}");
+
+        // Applying the unmodified typed model must not change a byte
+        let data = document.to_gedcom_data().unwrap();
+        document.apply(&data, &GedcomWriter::new()).unwrap();
+        assert_eq!(document.to_string(), content, "{path}");
+    }
+}
+
+#[test]
+fn test_lossless_edit_touches_only_the_edited_record() {
+    let content = std::fs::read_to_string("tests/fixtures/washington.ged").unwrap();
+    let mut document = GedcomDocument::parse(&content).unwrap();
+    let mut data = document.to_gedcom_data().unwrap();
+    let xref = data.individuals[0].xref.clone().unwrap();
+    data.individuals[0].primary_name_mut().unwrap().value = Some("Edited /Name/".to_string());
+
+    document.apply(&data, &GedcomWriter::new()).unwrap();
+    let output = document.to_string();
+
+    for node in document.records() {
+        if node.line.xref() != Some(&xref) {
+            assert!(content.contains(&node.to_string()));
+        }
+    }
+    let edited = document.find_record(&xref).unwrap();
+    assert!(edited.to_string().contains("1 NAME Edited /Name/"));
+    let reparsed = GedcomBuilder::new().build_from_str(&output).unwrap();
+    assert_eq!(reparsed.individuals.len(), data.individuals.len());
+    assert_eq!(
+        reparsed.individuals[0]
+            .primary_name()
+            .unwrap()
+            .value
+            .as_deref(),
+        Some("Edited /Name/")
+    );
+}
+
+// =============================================================================
+// Unknown Structure Preservation Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_preserves_unknown_standard_structures() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n1 FUTR header value\n\
+        0 @I1@ INDI\n1 NAME John /Doe/\n1 FUTR Some value\n2 PHRASE In a later version\n\
+        0 @F1@ FAM\n1 HUSB @I1@\n1 FUTR\n2 DATE 1 JAN 2030\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let custom = &data.individuals[0].custom_data;
+    assert_eq!(custom.len(), 1);
+    assert_eq!(custom[0].tag, "FUTR");
+    assert_eq!(custom[0].value.as_deref(), Some("Some value"));
+    assert_eq!(custom[0].children[0].tag, "PHRASE");
+    assert_eq!(data.families[0].custom_data[0].children[0].tag, "DATE");
+
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+    assert!(written.contains("1 FUTR header value\n"));
+    assert!(written.contains("1 FUTR Some value\n2 PHRASE In a later version\n"));
+    assert!(written.contains("1 FUTR\n2 DATE 1 JAN 2030\n"));
+
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(reparsed.individuals[0].custom_data, custom.clone());
+    assert_eq!(
+        reparsed.families[0].custom_data,
+        data.families[0].custom_data
+    );
+}
+
+#[test]
+fn test_round_trip_preserves_unknown_structures_in_events() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n\
+        0 @I1@ INDI\n1 BIRT\n2 FUTR x\n2 _CUST y\n2 DATE 1 JAN 1900\n3 FUTR later\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let birth = &data.individuals[0].events[0];
+    let tags: Vec<&str> = birth.custom_data.iter().map(|c| c.tag.as_str()).collect();
+    assert_eq!(tags, ["FUTR", "_CUST"]);
+    assert_eq!(birth.custom_data[1].value.as_deref(), Some("y"));
+    assert_eq!(birth.date.as_ref().unwrap().custom_data[0].tag, "FUTR");
+    assert!(data.individuals[0].custom_data.is_empty());
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(written.contains("1 BIRT\n2 DATE 1 JAN 1900\n3 FUTR later\n2 FUTR x\n2 _CUST y\n"));
+
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(reparsed.individuals[0].events[0], *birth);
+}
+
+#[test]
+fn test_round_trip_preserves_unknown_structures_below_level_two() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n\
+        0 @I1@ INDI\n1 NAME John /Doe/\n\
+        1 SOUR @S1@\n2 DATA\n3 _DATA z\n2 EVEN BIRT\n3 ROLE CHIL\n4 FUTR r\n\
+        1 NOTE Text\n2 _NOTE n\n\
+        1 ASSO @I2@\n2 ROLE GODP\n3 _ROLE q\n\
+        0 @F1@ FAM\n1 HUSB @I1@\n2 _HUSB h\n1 CHIL @I2@\n2 FUTR c\n\
+        1 MARR\n2 WIFE\n3 AGE 20y\n3 _WIFE w\n2 PLAC Town\n3 MAP\n4 LATI N1\n4 LONG E1\n4 _MAP m\n\
+        1 CHAN\n2 DATE 1 JAN 2000\n2 _CHAN t\n\
+        0 @M1@ OBJE\n1 FILE a.jpg\n2 FORM image/jpeg\n3 MEDI PHOTO\n4 _MEDI p\n3 _FORM f\n2 _FILE e\n\
+        0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    for expected in [
+        "2 DATA\n3 _DATA z\n",
+        "3 ROLE CHIL\n4 FUTR r\n",
+        "1 NOTE Text\n2 _NOTE n\n",
+        "2 ROLE GODP\n3 _ROLE q\n",
+        "1 HUSB @I1@\n2 _HUSB h\n",
+        "1 CHIL @I2@\n2 FUTR c\n",
+        "3 AGE 20y\n3 _WIFE w\n",
+        "4 LONG E1\n4 _MAP m\n",
+        "2 DATE 1 JAN 2000\n2 _CHAN t\n",
+        "3 MEDI PHOTO\n4 _MEDI p\n3 _FORM f\n2 _FILE e\n",
+    ] {
+        assert!(
+            written.contains(expected),
+            "{expected:?} missing from\n{written}"
+        );
+    }
+
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(reparsed.individuals, data.individuals);
+    assert_eq!(reparsed.families, data.families);
+    assert_eq!(reparsed.multimedia, data.multimedia);
+}
+
+#[test]
+fn test_ignore_unknown_tags_drops_unknown_standard_structures() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n1 FUTR Some value\n\
+        2 PHRASE In a later version\n1 SEX M\n0 TRLR";
+    let data = GedcomBuilder::new()
+        .ignore_unknown_tags(true)
+        .build_from_str(original)
+        .unwrap();
+
+    assert!(data.individuals[0].custom_data.is_empty());
+    assert!(data.individuals[0].sex.is_some());
+
+    let strict = GedcomBuilder::new()
+        .strict_mode(true)
+        .build_from_str(original);
+    assert!(strict.is_err());
+}
+
+// =============================================================================
+// User-Defined Tag Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_preserves_user_defined_tags_in_allged() {
+    let original = std::fs::read_to_string("tests/fixtures/allged.ged").unwrap();
+    let data1 = GedcomBuilder::new().build_from_str(&original).unwrap();
+
+    let written = GedcomWriter::new().write_to_string(&data1).unwrap();
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+
+    for line in original.lines().filter(|line| line.contains(" _MYOWNTAG ")) {
+        assert!(written.contains(line), "missing {line:?}");
+    }
+    assert_eq!(
+        written.matches("_MYOWNTAG").count(),
+        original.matches("_MYOWNTAG").count()
+    );
+
+    assert_eq!(data2.custom_data, data1.custom_data);
+    assert_eq!(
+        data2.header.as_ref().unwrap().custom_data,
+        data1.header.as_ref().unwrap().custom_data
+    );
+    for (a, b) in data1.individuals.iter().zip(&data2.individuals) {
+        assert_eq!(a.custom_data, b.custom_data, "{:?}", a.xref);
+        assert_eq!(
+            a.primary_name().map(|n| &n.custom_data),
+            b.primary_name().map(|n| &n.custom_data)
+        );
+    }
+    for (a, b) in data1.families.iter().zip(&data2.families) {
+        assert_eq!(a.custom_data, b.custom_data, "{:?}", a.xref);
+    }
+    for (a, b) in data1.sources.iter().zip(&data2.sources) {
+        assert_eq!(a.custom_data, b.custom_data, "{:?}", a.xref);
+    }
+    for (a, b) in data1.repositories.iter().zip(&data2.repositories) {
+        assert_eq!(a.custom_data, b.custom_data, "{:?}", a.xref);
+    }
+    for (a, b) in data1.submitters.iter().zip(&data2.submitters) {
+        assert_eq!(a.custom_data, b.custom_data, "{:?}", a.xref);
+    }
+    for (a, b) in data1.submissions.iter().zip(&data2.submissions) {
+        assert_eq!(a.custom, b.custom, "{:?}", a.xref);
+    }
+}
+
+#[test]
+fn test_round_trip_user_defined_tags_at_every_level() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n1 _HEAD header\n\
+        0 @I1@ INDI\n1 NAME John /Doe/\n2 _MARNM Smith\n1 SEX M\n2 _SEXX sex\n\
+        1 BIRT\n2 PLAC Boston\n3 _PLAC place\n2 SOUR @S1@\n3 _APID 1,7602::123\n\
+        1 FAMS @F1@\n2 _FAMS link\n1 _UID 0123ABCD\n2 _SUB nested\n3 _DEEP deeper\n\
+        0 @F1@ FAM\n1 HUSB @I1@\n1 _STAT married\n\
+        0 @S1@ SOUR\n1 TITL Census\n1 REPO @R1@\n2 _REPO call\n\
+        0 @R1@ REPO\n1 NAME Archive\n1 ADDR Main St\n2 _ADDR addr\n\
+        0 _PUBLISH top level\n1 _CHILD child\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+
+    for expected in [
+        "1 _HEAD header\n",
+        "1 NAME John /Doe/\n",
+        "2 _MARNM Smith\n",
+        "2 _SEXX sex\n",
+        "3 _PLAC place\n",
+        "3 _APID 1,7602::123\n",
+        "1 FAMS @F1@\n2 _FAMS link\n",
+        "1 _UID 0123ABCD\n2 _SUB nested\n3 _DEEP deeper\n",
+        "1 _STAT married\n",
+        "1 REPO @R1@\n2 _REPO call\n",
+        "2 _ADDR addr\n",
+        "0 _PUBLISH top level\n1 _CHILD child\n0 TRLR",
+    ] {
+        assert!(
+            written.contains(expected),
+            "missing {expected:?} in\n{written}"
+        );
+    }
+
+    let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(reparsed.custom_data, data.custom_data);
+    assert_eq!(
+        reparsed.individuals[0].custom_data,
+        data.individuals[0].custom_data
+    );
+}
+
+// =============================================================================
+// Note Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_multiple_notes_and_note_pointers() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME John /Doe/\n\
+        2 NOTE First name note\n2 NOTE Second name note\n1 BIRT\n2 NOTE @N1@\n\
+        2 NOTE Born at home\n1 NOTE @N1@\n1 NOTE Another note\n\
+        1 CHAN\n2 DATE 1 JAN 2000\n2 NOTE First change\n2 NOTE Second change\n\
+        0 @N1@ NOTE Shared text\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let individual = &data1.individuals[0];
+    assert_eq!(individual.notes.len(), 2);
+    assert_eq!(individual.names[0].notes.len(), 2);
+    assert_eq!(individual.events[0].notes.len(), 2);
+    assert_eq!(individual.change_date.as_ref().unwrap().notes.len(), 2);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("1 NOTE @N1@\n1 NOTE Another note\n"));
+    assert!(written.contains("2 NOTE @N1@\n2 NOTE Born at home\n"));
+    assert!(written.contains("2 NOTE First change\n2 NOTE Second change\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.individuals[0].notes, individual.notes);
+    assert_eq!(
+        data2.individuals[0].names[0].notes,
+        individual.names[0].notes
+    );
+    assert_eq!(
+        data2.individuals[0].events[0].notes,
+        individual.events[0].notes
+    );
+    assert_eq!(data2.individuals[0].change_date, individual.change_date);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("1 SNOTE @N1@\n"));
+    assert!(written.contains("2 SNOTE @N1@\n"));
+    let data3 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data3.individuals[0].notes, individual.notes);
+}
+
+#[test]
+fn test_round_trip_note_records_between_versions() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME John /Doe/\n1 NOTE @N1@\n\
+        0 @N1@ NOTE First line\n1 CONT Second line\n1 REFN 42\n2 TYPE ledger\n1 RIN 7\n\
+        1 SOUR @S1@\n0 @S1@ SOUR\n1 TITL Parish register\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let record = data1.resolve_note(&data1.individuals[0].notes[0]).unwrap();
+    assert_eq!(record.version, Some(ged_io::GedcomVersion::V5_5_1));
+    assert_eq!(record.text, "First line\nSecond line");
+    assert_eq!(
+        record.user_reference_numbers[0].value.as_deref(),
+        Some("42")
+    );
+    assert_eq!(record.automated_record_id.as_deref(), Some("7"));
+    assert_eq!(record.source_citations.len(), 1);
+    assert!(record.custom_data.is_empty());
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("0 @N1@ SNOTE First line\n1 CONT Second line\n"));
+    assert!(written.contains("1 SNOTE @N1@\n"));
+    assert!(!written.contains("RIN"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    let record = data2.resolve_note(&data2.individuals[0].notes[0]).unwrap();
+    assert_eq!(record.version, Some(ged_io::GedcomVersion::V7_0));
+    assert_eq!(record.text, "First line\nSecond line");
+    assert_eq!(
+        record.user_reference_numbers,
+        data1.shared_notes[0].user_reference_numbers
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data2)
+        .unwrap();
+    assert!(written.contains("0 @N1@ NOTE First line\n1 CONT Second line\n"));
+    assert!(written.contains("1 NOTE @N1@\n"));
+    let data3 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(
+        data3.note_text(&data3.individuals[0].notes[0]),
+        Some("First line\nSecond line")
+    );
+}
+
+#[test]
+fn test_round_trip_long_note_record_wraps_with_conc() {
+    let text = "word ".repeat(60);
+    let text = format!("{}\nSecond line", text.trim_end());
+    let original = format!(
+        "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @N1@ NOTE {}\n0 TRLR",
+        text.replace('\n', "\n1 CONT ")
+    );
+    let data1 = GedcomBuilder::new().build_from_str(&original).unwrap();
+    assert_eq!(data1.shared_notes[0].text, text);
+
+    let written = GedcomWriter::new()
+        .max_line_length(80)
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("0 @N1@ NOTE word word"));
+    assert!(written.contains("\n1 CONC "));
+    assert!(written.contains("\n1 CONT Second line\n"));
+    for line in written.lines() {
+        assert!(line.len() <= 80, "{line}");
+    }
+
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.shared_notes[0].text, text);
+}
+
+// =============================================================================
+// Source Citation Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_gedcom_7_source_citation() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n1 NAME John /Doe/\n\
+        1 SOUR @S1@\n2 PAGE Entry 42\n2 DATA\n3 DATE 2 JAN 1900\n\
+        3 TEXT <p>John, son of Thomas</p>\n4 MIME text/html\n4 LANG en\n\
+        3 TEXT Johann, Sohn des Thomas\n4 LANG de\n\
+        2 EVEN BAPM\n3 PHRASE Baptism at St Mary\n3 ROLE OTHER\n4 PHRASE Godson\n\
+        2 QUAY 3\n2 OBJE @O1@\n2 NOTE Transcribed by hand\n2 SNOTE @N1@\n\
+        0 @S1@ SOUR\n1 TITL Parish register\n0 @O1@ OBJE\n1 FILE register.jpg\n2 FORM image/jpeg\n\
+        0 @N1@ SNOTE Shared\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let citation = &data1.individuals[0].source[0];
+    let citation_data = citation.data.as_ref().unwrap();
+    assert_eq!(citation_data.texts.len(), 2);
+    assert_eq!(citation.event_phrase.as_deref(), Some("Baptism at St Mary"));
+    assert_eq!(citation.role_phrase.as_deref(), Some("Godson"));
+    assert_eq!(citation.notes.len(), 2);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("3 TEXT <p>John, son of Thomas</p>\n4 MIME text/html\n4 LANG en\n"));
+    assert!(written
+        .contains("2 EVEN BAPM\n3 PHRASE Baptism at St Mary\n3 ROLE OTHER\n4 PHRASE Godson\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    let reparsed = &data2.individuals[0].source[0];
+    assert_eq!(reparsed.page, citation.page);
+    assert_eq!(reparsed.data, citation.data);
+    assert_eq!(reparsed.event_type, citation.event_type);
+    assert_eq!(reparsed.event_phrase, citation.event_phrase);
+    assert_eq!(reparsed.role, citation.role);
+    assert_eq!(reparsed.role_phrase, citation.role_phrase);
+    assert_eq!(reparsed.certainty_assessment, citation.certainty_assessment);
+    assert_eq!(reparsed.multimedia[0].xref, citation.multimedia[0].xref);
+    assert_eq!(reparsed.notes, citation.notes);
+}
+
+#[test]
+fn test_round_trip_gedcom_5_source_citation() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME John /Doe/\n1 BIRT\n\
+        2 SOUR @S1@\n3 PAGE Folio 7\n3 EVEN BIRT\n4 ROLE CHIL\n3 DATA\n4 DATE 3 JAN 1900\n\
+        4 TEXT First excerpt\n5 CONT continued\n4 TEXT Second excerpt\n\
+        3 NOTE First note\n3 NOTE Second note\n0 @S1@ SOUR\n1 TITL Births\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    let citation = &data1.individuals[0].events[0].citations[0];
+    assert_eq!(citation.role.as_deref(), Some("CHIL"));
+    assert_eq!(
+        citation.data.as_ref().unwrap().texts[0].value.as_deref(),
+        Some("First excerpt\ncontinued")
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("3 EVEN BIRT\n4 ROLE CHIL\n"));
+    assert!(written.contains("4 TEXT Second excerpt\n"));
+    assert!(written.contains("3 NOTE First note\n3 NOTE Second note\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.individuals[0].events[0].citations[0], *citation);
+}
+
+#[test]
+fn test_gedcom_5_output_drops_citation_phrases_and_text_media_types() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n1 SOUR @S1@\n2 DATA\n\
+        3 TEXT Excerpt\n4 MIME text/plain\n4 LANG en\n2 EVEN BIRT\n3 PHRASE Birth\n\
+        3 ROLE CHIL\n4 PHRASE Infant\n0 @S1@ SOUR\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(written.contains("3 TEXT Excerpt\n2 EVEN BIRT\n3 ROLE CHIL\n"));
+    assert!(!written.contains("PHRASE"));
+    assert!(!written.contains("MIME"));
+}
+
+// =============================================================================
+// Association Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_associations_from_gedcom_5_to_7() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME John /Doe/\n\
+        1 ASSO @I2@\n2 RELA Godfather\n2 SOUR @S1@\n3 PAGE 3\n2 NOTE Named in the register\n\
+        1 ASSO @I3@\n2 RELA Landlord\n1 ASSO @I4@\n2 RELA Friend\n\
+        0 @I2@ INDI\n0 @I3@ INDI\n0 @I4@ INDI\n0 @S1@ SOUR\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+    assert_eq!(data1.individuals[0].associations.len(), 3);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("1 ASSO @I2@\n2 RELA Godfather\n2 SOUR @S1@\n3 PAGE 3\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(
+        data2.individuals[0].associations,
+        data1.individuals[0].associations
+    );
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("1 ASSO @I2@\n2 ROLE GODP\n3 PHRASE Godfather\n2 SOUR @S1@\n"));
+    assert!(written.contains("1 ASSO @I3@\n2 ROLE OTHER\n3 PHRASE Landlord\n"));
+    assert!(written.contains("1 ASSO @I4@\n2 ROLE FRIEND\n0"));
+    assert!(!written.contains("RELA"));
+    let data3 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    for (converted, original) in data3.individuals[0]
+        .associations
+        .iter()
+        .zip(&data1.individuals[0].associations)
+    {
+        assert_eq!(converted.resolved_role(), original.resolved_role());
+        assert_eq!(converted.source_citations, original.source_citations);
+        assert_eq!(converted.notes, original.notes);
+    }
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data3)
+        .unwrap();
+    assert!(written.contains("1 ASSO @I2@\n2 RELA Godfather\n"));
+    assert!(written.contains("1 ASSO @I3@\n2 RELA Landlord\n"));
+    assert!(written.contains("1 ASSO @I4@\n2 RELA Friend\n"));
+}
+
+#[test]
+fn test_round_trip_gedcom_7_associations() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @I1@ INDI\n\
+        1 ASSO @VOID@\n2 PHRASE Mr Stockdale\n2 ROLE OTHER\n3 PHRASE Landlord\n\
+        1 BAPM\n2 ASSO @I2@\n3 ROLE GODP\n3 SOUR @S1@\n\
+        0 @I2@ INDI\n0 @S1@ SOUR\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(
+        written.contains("1 ASSO @VOID@\n2 PHRASE Mr Stockdale\n2 ROLE OTHER\n3 PHRASE Landlord\n")
+    );
+    assert!(written.contains("2 ASSO @I2@\n3 ROLE GODP\n3 SOUR @S1@\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(
+        data2.individuals[0].associations,
+        data1.individuals[0].associations
+    );
+    assert_eq!(
+        data2.individuals[0].events[0].associations,
+        data1.individuals[0].events[0].associations
+    );
+}
+
+// =============================================================================
+// Family Member Round-Trip Tests
+// =============================================================================
+
+#[test]
+fn test_round_trip_family_member_phrases_and_spouse_ages() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n0 @F1@ FAM\n1 HUSB @I1@\n2 PHRASE Mr Smith\n\
+        1 WIFE @I2@\n1 CHIL @I3@\n1 CHIL @VOID@\n2 PHRASE Unnamed infant\n\
+        1 MARR\n2 HUSB\n3 AGE 25y\n4 PHRASE Twenty-five\n2 WIFE\n3 AGE > 20y\n\
+        0 @I1@ INDI\n0 @I2@ INDI\n0 @I3@ INDI\n0 TRLR";
+    let data1 = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains(
+        "1 HUSB @I1@\n2 PHRASE Mr Smith\n1 WIFE @I2@\n1 CHIL @I3@\n1 CHIL @VOID@\n2 PHRASE Unnamed infant\n"
+    ));
+    assert!(written.contains("2 HUSB\n3 AGE 25y\n4 PHRASE Twenty-five\n2 WIFE\n3 AGE > 20y\n"));
+    let data2 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(data2.families[0], data1.families[0]);
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data1)
+        .unwrap();
+    assert!(written.contains("1 HUSB @I1@\n1 WIFE @I2@\n1 CHIL @I3@\n1 CHIL @VOID@\n1 MARR\n"));
+    assert!(written.contains("2 HUSB\n3 AGE 25y\n"));
+    assert!(!written.contains("PHRASE"));
+    let data3 = GedcomBuilder::new().build_from_str(&written).unwrap();
+    let ages = |data: &ged_io::types::GedcomData| -> Vec<Option<String>> {
+        data.families[0].events[0]
+            .family_event_details
+            .iter()
+            .map(|detail| detail.age.as_ref().map(ToString::to_string))
+            .collect()
+    };
+    assert_eq!(ages(&data3), ages(&data2));
+}
+
+// =============================================================================
+// Streaming Output Tests
+// =============================================================================
+
+#[test]
+fn test_write_to_io_matches_write_to_string() {
+    for path in ["tests/fixtures/sample.ged", "tests/fixtures/washington.ged"] {
+        let content = std::fs::read_to_string(path).unwrap();
+        let data = GedcomBuilder::new().build_from_str(&content).unwrap();
+        let writer = GedcomWriter::new();
+
+        let mut output = Vec::new();
+        writer.write_to_io(&mut output, &data).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            writer.write_to_string(&data).unwrap(),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn test_write_records_from_stream_parser() {
+    let path = "tests/fixtures/washington.ged";
+    let file = std::fs::File::open(path).unwrap();
+    let records = GedcomStreamParser::new(std::io::BufReader::new(file))
+        .unwrap()
+        .map(Result::unwrap);
+
+    let mut output = Vec::new();
+    GedcomWriter::new()
+        .write_records(&mut output, records)
+        .unwrap();
+    let streamed = GedcomBuilder::new()
+        .build_from_str(std::str::from_utf8(&output).unwrap())
+        .unwrap();
+
+    let content = std::fs::read_to_string(path).unwrap();
+    let data = GedcomBuilder::new().build_from_str(&content).unwrap();
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+    let expected = GedcomBuilder::new().build_from_str(&written).unwrap();
+    assert_eq!(streamed.individuals, expected.individuals);
+    assert_eq!(streamed.families, expected.families);
+    assert_eq!(streamed.header, expected.header);
+}
+
+#[test]
+fn test_stream_writer_adds_header_and_trailer() {
+    let individual = ged_io::types::individual::Individual {
+        xref: Some("@I1@".into()),
+        ..Default::default()
+    };
+
+    let mut stream = GedcomWriter::new().stream_to(Vec::new());
+    stream
+        .write_record(&GedcomRecord::Individual(individual))
+        .unwrap();
+    let output = String::from_utf8(stream.finish().unwrap()).unwrap();
+    assert!(output.starts_with("0 HEAD\n"));
+    assert!(output.contains("0 @I1@ INDI\n"));
+    assert!(output.ends_with("0 TRLR"));
+
+    let empty = GedcomWriter::new().stream_to(Vec::new()).finish().unwrap();
+    let empty = String::from_utf8(empty).unwrap();
+    assert!(empty.starts_with("0 HEAD\n"));
+    assert!(empty.ends_with("0 TRLR"));
+}
+
+#[test]
+fn test_write_to_io_reports_output_errors() {
+    struct FullDisk;
+
+    impl std::io::Write for FullDisk {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::StorageFull,
+                "disk full",
+            ))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let content = std::fs::read_to_string("tests/fixtures/washington.ged").unwrap();
+    let data = GedcomBuilder::new().build_from_str(&content).unwrap();
+    let error = GedcomWriter::new()
+        .write_to_io(FullDisk, &data)
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::StorageFull);
+}
+
+// =============================================================================
+// Version-Correct Output Tests
+// =============================================================================
+
+/// Returns the tags written in `output`, one per line.
+fn written_tags(output: &str) -> Vec<&str> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split(' ').skip(1);
+            let tag = parts.next()?;
+            if tag.starts_with('@') {
+                parts.next()
+            } else {
+                Some(tag)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_gedcom_7_output_of_fixtures_is_strictly_valid() {
+    // washington.ged has malformed dates of its own, which strict mode rejects
+    for path in [
+        "tests/fixtures/simple.ged",
+        "tests/fixtures/sample.ged",
+        "tests/fixtures/allged.ged",
+        "tests/fixtures/long-url.ged",
+    ] {
+        let original = std::fs::read_to_string(path).unwrap();
+        let data = GedcomBuilder::new().build_from_str(&original).unwrap();
+
+        let written = GedcomWriter::new()
+            .gedcom_version("7.0")
+            .write_to_string(&data)
+            .unwrap();
+        assert!(
+            written.starts_with("0 HEAD\n1 GEDC\n2 VERS 7.0\n1 "),
+            "{path}"
+        );
+        let tags = written_tags(&written);
+        for removed in ["CONC", "CHAR", "SUBN"] {
+            assert!(!tags.contains(&removed), "{path} writes {removed}");
+        }
+        let header = &written[..written.find("\n0 ").unwrap()];
+        assert!(!header.contains("\n1 FILE "), "{path}");
+
+        let reparsed = GedcomBuilder::new()
+            .strict_mode(true)
+            .build_from_str(&written)
+            .unwrap_or_else(|e| panic!("{path}: {e}"));
+        let header = reparsed.header.as_ref().unwrap();
+        assert!(header.is_gedcom_7(), "{path}");
+        assert_eq!(reparsed.individuals.len(), data.individuals.len(), "{path}");
+        for (reparsed, individual) in reparsed.individuals.iter().zip(&data.individuals) {
+            assert_eq!(reparsed.names, individual.names, "{path}");
+        }
+        assert_eq!(reparsed.families.len(), data.families.len(), "{path}");
+        assert!(reparsed.submissions.is_empty(), "{path}");
+    }
+}
+
+#[test]
+fn test_gedcom_5_output_leaves_out_gedcom_7_structures() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 7.0\n1 SCHMA\n2 TAG _SKYPE http://example.com/skype\n\
+        0 @I1@ INDI\n1 NAME Jane /Doe/\n1 BIRT\n2 DATE 15 MAR 1900\n3 PHRASE The Ides of March\n\
+        2 SDATE 15 MAR 1900\n2 AGE 0y\n3 PHRASE Newborn\n1 NO MARR\n1 INIL\n2 TEMP SLAKE\n\
+        1 EXID 123\n2 TYPE http://example.com\n1 SNOTE @N1@\n\
+        0 @N1@ SNOTE Shared text\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    let written = GedcomWriter::new()
+        .gedcom_version("5.5.1")
+        .write_to_string(&data)
+        .unwrap();
+    let tags = written_tags(&written);
+    for removed in ["SCHMA", "PHRASE", "SDATE", "NO", "INIL", "EXID", "SNOTE"] {
+        assert!(!tags.contains(&removed), "writes {removed}");
+    }
+    assert!(written.starts_with("0 HEAD\n1 GEDC\n2 VERS 5.5.1\n2 FORM LINEAGE-LINKED\n"));
+    assert!(written.contains("1 NOTE @N1@\n"));
+    assert!(written.contains("0 @N1@ NOTE Shared text\n"));
+
+    let reparsed = GedcomBuilder::new()
+        .strict_mode(true)
+        .build_from_str(&written)
+        .unwrap();
+    assert!(!reparsed.header.as_ref().unwrap().is_gedcom_7());
+    assert_eq!(reparsed.individuals[0].names, data.individuals[0].names);
+}
+
+#[test]
+fn test_long_text_continuation_follows_version() {
+    let note = format!("{}\nSecond line", "x".repeat(300));
+    let original = format!(
+        "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NOTE {}\n2 CONT Second line\n0 TRLR",
+        "x".repeat(300)
+    );
+    let data = GedcomBuilder::new().build_from_str(&original).unwrap();
+
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+    // The limit covers the whole line, including "1 NOTE "
+    assert!(written.contains(&format!("1 NOTE {}\n2 CONC xxx", "x".repeat(248))));
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(!written.contains("CONC"));
+    assert!(written.contains(&format!("1 NOTE {}\n2 CONT Second line\n", "x".repeat(300))));
+
+    let reparsed = GedcomBuilder::new()
+        .strict_mode(true)
+        .build_from_str(&written)
+        .unwrap();
+    assert_eq!(
+        reparsed.individuals[0].notes[0].value.as_deref(),
+        Some(note.as_str())
+    );
+}
+
+#[test]
+fn test_at_signs_are_escaped_for_each_version() {
+    let original = "0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n1 NAME Jane /Doe/\n\
+        1 NOTE Write to jane@@example.com\n1 NOTE @@home is where the heart is\n\
+        1 BIRT\n2 DATE @#DJULIAN@ 1 JAN 1700\n0 TRLR";
+    let data = GedcomBuilder::new().build_from_str(original).unwrap();
+
+    // Writing the same version keeps every payload as it was
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+    assert!(written.contains("1 NOTE Write to jane@@example.com\n"));
+    assert!(written.contains("1 NOTE @@home is where the heart is\n"));
+    assert!(written.contains("2 DATE @#DJULIAN@ 1 JAN 1700\n"));
+
+    // GEDCOM 7.0 only doubles a leading at-sign
+    let written_7 = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(written_7.contains("1 NOTE Write to jane@example.com\n"));
+    assert!(written_7.contains("1 NOTE @@home is where the heart is\n"));
+    let data_7 = GedcomBuilder::new()
+        .strict_mode(true)
+        .build_from_str(&written_7)
+        .unwrap();
+
+    // And converting back doubles them all again
+    let written_5 = GedcomWriter::new().write_to_string(&data_7).unwrap();
+    assert!(written_5.contains("1 NOTE Write to jane@@example.com\n"));
+    assert!(written_5.contains("1 NOTE @@home is where the heart is\n"));
+}
+
+#[test]
+fn test_at_signs_in_new_text_are_escaped() {
+    let mut data = GedcomBuilder::new()
+        .build_from_str("0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n0 TRLR")
+        .unwrap();
+    data.individuals[0].notes.push(ged_io::types::note::Note {
+        value: Some("@home or jane@example.com".to_string()),
+        ..Default::default()
+    });
+
+    let written = GedcomWriter::new().write_to_string(&data).unwrap();
+    assert!(written.contains("1 NOTE @@home or jane@@example.com\n"));
+
+    let written = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .write_to_string(&data)
+        .unwrap();
+    assert!(written.contains("1 NOTE @@home or jane@example.com\n"));
+
+    // A CONC split never separates an escaped pair, with room for 2 to 12 value characters
+    // after "2 CONC "
+    for max_line_length in 9..=19 {
+        let written = GedcomWriter::new()
+            .max_line_length(max_line_length)
+            .write_to_string(&data)
+            .unwrap();
+        for line in written.lines().filter(|line| line.contains('@')) {
+            let value = line.splitn(3, ' ').nth(2).unwrap_or_default();
+            let odd_run = value.split(|c| c != '@').any(|run| run.len() % 2 == 1);
+            assert!(!odd_run, "{line:?} with {max_line_length}");
+        }
+        let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+        assert_eq!(
+            reparsed.individuals[0].notes[0].value.as_deref(),
+            Some("@@home or jane@@example.com")
+        );
+    }
+}
+
+#[test]
+fn test_gedcom_7_output_must_be_utf8() {
+    let data = GedcomBuilder::new()
+        .build_from_str("0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 TRLR")
+        .unwrap();
+    let error = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .encoding(ged_io::GedcomEncoding::Ansel)
+        .write_to_bytes(&data)
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+    let bytes = GedcomWriter::new()
+        .gedcom_version("7.0")
+        .encoding(ged_io::GedcomEncoding::Utf8)
+        .write_to_bytes(&data)
+        .unwrap();
+    assert!(!String::from_utf8(bytes).unwrap().contains("CHAR"));
+}
+
+#[test]
+fn test_long_multibyte_text_wraps_on_character_boundaries() {
+    let cyrillic = "Иван Петрович жил в селе у реки, где родились все его дети. ".repeat(8);
+    let cjk = "家族の歴史は村の記録に残されている。".repeat(30);
+    let emoji = "👪🏡".repeat(100);
+    for text in [cyrillic.trim_end(), cjk.as_str(), emoji.as_str()] {
+        let mut data = GedcomBuilder::new()
+            .build_from_str("0 HEAD\n1 GEDC\n2 VERS 5.5.1\n0 @I1@ INDI\n0 TRLR")
+            .unwrap();
+        data.individuals[0].notes.push(ged_io::types::note::Note {
+            value: Some(text.to_string()),
+            ..Default::default()
+        });
+
+        let written = GedcomWriter::new().write_to_string(&data).unwrap();
+        for line in written.lines().filter(|line| line.contains(" CONC ")) {
+            let value = line.strip_prefix("2 CONC ").unwrap();
+            assert!(!value.starts_with(' ') && !value.ends_with(' '), "{line:?}");
+        }
+        for line in written.lines() {
+            assert!(line.chars().count() <= 255, "{line:?}");
+        }
+
+        let reparsed = GedcomBuilder::new().build_from_str(&written).unwrap();
+        assert_eq!(
+            reparsed.individuals[0].notes[0].value.as_deref(),
+            Some(text)
+        );
+
+        // UTF-16 output measures lines in code units
+        let bytes = GedcomWriter::new()
+            .encoding(ged_io::GedcomEncoding::Utf16Le)
+            .write_to_bytes(&data)
+            .unwrap();
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        let decoded = String::from_utf16(&units).unwrap();
+        for line in decoded.trim_start_matches('\u{FEFF}').lines() {
+            assert!(line.encode_utf16().count() <= 255, "{line:?}");
+        }
+        let reparsed = GedcomBuilder::new().build_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            reparsed.individuals[0].notes[0].value.as_deref(),
+            Some(text)
+        );
+    }
+}